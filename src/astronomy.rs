@@ -0,0 +1,422 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::month::Month;
+use crate::day::Day;
+use crate::free::days_from_civil;
+
+//---------------------------------------------------------------------------------------------------- Constants
+// Moments are fractional Rata Die in Universal Time,
+// i.e. `1.0` is midnight of January 1st, 1 (Gregorian).
+//
+// The algorithms are from "Calendrical Calculations" (Reingold & Dershowitz),
+// which take them from "Astronomical Algorithms" (Meeus).
+
+/// Noon, January 1st, 2000
+const J2000: f64 = 730120.5;
+
+/// Rata Die of the Unix epoch
+const RATA_DIE_UNIX_EPOCH: f64 = 719163.0;
+
+/// Mean tropical year in days
+pub(crate) const MEAN_TROPICAL_YEAR: f64 = 365.242189;
+
+/// `(amplitude, phase, rate)` of the periodic terms of the solar longitude,
+/// from "Planetary Programs and Tables from -4000 to +2800" (Bretagnon & Simon)
+const SOLAR_LONGITUDE_TERMS: [(f64, f64, f64); 49] = [
+	(403406.0, 270.54861, 0.9287892),
+	(195207.0, 340.19128, 35999.1376958),
+	(119433.0, 63.91854, 35999.4089666),
+	(112392.0, 331.26220, 35998.7287385),
+	(3891.0, 317.843, 71998.20261),
+	(2819.0, 86.631, 71998.4403),
+	(1721.0, 240.052, 36000.35726),
+	(660.0, 310.26, 71997.4812),
+	(350.0, 247.23, 32964.4678),
+	(334.0, 260.87, -19.4410),
+	(314.0, 297.82, 445267.1117),
+	(268.0, 343.14, 45036.8840),
+	(242.0, 166.79, 3.1008),
+	(234.0, 81.53, 22518.4434),
+	(158.0, 3.50, -19.9739),
+	(132.0, 132.75, 65928.9345),
+	(129.0, 182.95, 9038.0293),
+	(114.0, 162.03, 3034.7684),
+	(99.0, 29.8, 33718.148),
+	(93.0, 266.4, 3034.448),
+	(86.0, 249.2, -2280.773),
+	(78.0, 157.6, 29929.992),
+	(72.0, 257.8, 31556.493),
+	(68.0, 185.1, 149.588),
+	(64.0, 69.9, 9037.750),
+	(46.0, 8.0, 107997.405),
+	(38.0, 197.1, -4444.176),
+	(37.0, 250.4, 151.771),
+	(32.0, 65.3, 67555.316),
+	(29.0, 162.7, 31556.080),
+	(28.0, 341.5, -4561.540),
+	(27.0, 291.6, 107996.706),
+	(27.0, 98.5, 1221.655),
+	(25.0, 146.7, 62894.167),
+	(24.0, 110.0, 31437.369),
+	(21.0, 5.2, 14578.298),
+	(21.0, 342.6, -31931.757),
+	(20.0, 230.9, 34777.243),
+	(18.0, 256.1, 1221.999),
+	(17.0, 45.3, 62894.511),
+	(14.0, 242.9, -4442.039),
+	(13.0, 115.2, 107997.909),
+	(13.0, 151.8, 119.066),
+	(13.0, 285.3, 16859.071),
+	(12.0, 53.3, -4.578),
+	(10.0, 126.6, 26895.292),
+	(10.0, 205.7, -39.127),
+	(10.0, 85.9, 12297.536),
+	(10.0, 146.1, 90073.778),
+];
+
+//---------------------------------------------------------------------------------------------------- Free
+#[inline]
+/// Convert a moment into seconds relative to the Unix epoch
+pub(crate) fn moment_to_unix(moment: f64) -> i128 {
+	((moment - RATA_DIE_UNIX_EPOCH) * 86400.0).round() as i128
+}
+
+#[inline]
+/// The Rata Die of the day `moment` falls on, `utc_offset` seconds away from Universal Time
+pub(crate) fn moment_to_rata_die(moment: f64, utc_offset: i32) -> i128 {
+	(moment + utc_offset as f64 / 86400.0).floor() as i128
+}
+
+/// Difference between Dynamical Time and Universal Time (ΔT) in days
+fn ephemeris_correction(moment: f64) -> f64 {
+	let year = moment / 365.2425;
+	let year = (if year > 0.0 { year + 1.0 } else { year }) as i32;
+
+	let mid_year = days_from_civil(year as i128, Month::July, Day::First) as f64 + RATA_DIE_UNIX_EPOCH;
+	let c = (mid_year - 693596.0) / 36525.0;
+	let y2000 = (year - 2000) as f64;
+	let y1700 = (year - 1700) as f64;
+	let y1600 = (year - 1600) as f64;
+	let y1000 = (year - 1000) as f64 / 100.0;
+	let y0    = year as f64 / 100.0;
+	let y1820 = (year - 1820) as f64 / 100.0;
+
+	match year {
+		2051..=2150 => {
+			(-20.0 + 32.0 * y1820 * y1820 + 0.5628 * (2150 - year) as f64) / 86400.0
+		},
+		2006..=2050 => {
+			(62.92 + 0.32217 * y2000 + 0.005589 * y2000 * y2000) / 86400.0
+		},
+		1987..=2005 => {
+			poly(y2000, &[63.86, 0.3345, -0.060374, 0.0017275, 0.000651814, 0.00002373599]) / 86400.0
+		},
+		1900..=1986 => {
+			poly(c, &[-0.00002, 0.000297, 0.025184, -0.181133, 0.553040, -0.861938, 0.677066, -0.212591])
+		},
+		1800..=1899 => {
+			poly(c, &[
+				-0.000009, 0.003844, 0.083563, 0.865736, 4.867575, 15.845535,
+				31.332267, 38.291999, 28.316289, 11.636204, 2.043794,
+			])
+		},
+		1700..=1799 => {
+			poly(y1700, &[8.118780842, -0.005092142, 0.003336121, -0.0000266484]) / 86400.0
+		},
+		1600..=1699 => {
+			poly(y1600, &[120.0, -0.9808, -0.01532, 0.000140272128]) / 86400.0
+		},
+		500..=1599 => {
+			poly(y1000, &[1574.2, -556.01, 71.23472, 0.319781, -0.8503463, -0.005050998, 0.0083572073]) / 86400.0
+		},
+		-499..=499 => {
+			poly(y0, &[10583.6, -1014.41, 33.78311, -5.952053, -0.1798452, 0.022174192, 0.0090316521]) / 86400.0
+		},
+		_ => (-20.0 + 32.0 * y1820 * y1820) / 86400.0,
+	}
+}
+
+#[inline]
+/// Evaluate the polynomial `coefficients[0] + coefficients[1] * x + ...`
+fn poly(x: f64, coefficients: &[f64]) -> f64 {
+	coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+#[inline]
+/// Julian centuries of Dynamical Time since [`J2000`]
+pub(crate) fn julian_centuries(moment: f64) -> f64 {
+	(moment + ephemeris_correction(moment) - J2000) / 36525.0
+}
+
+#[inline]
+/// `sin()` of degrees
+pub(crate) fn sin_degrees(degrees: f64) -> f64 {
+	degrees.to_radians().sin()
+}
+
+#[inline]
+/// `cos()` of degrees
+pub(crate) fn cos_degrees(degrees: f64) -> f64 {
+	degrees.to_radians().cos()
+}
+
+#[inline]
+/// Longitudinal nutation in degrees
+pub(crate) fn nutation(c: f64) -> f64 {
+	let a = 124.90 - 1934.134 * c + 0.002063 * c * c;
+	let b = 201.11 + 72001.5377 * c + 0.00057 * c * c;
+	-0.004778 * sin_degrees(a) - 0.0003667 * sin_degrees(b)
+}
+
+#[inline]
+/// Aberration of the Sun in degrees
+fn aberration(c: f64) -> f64 {
+	0.0000974 * cos_degrees(177.63 + 35999.01848 * c) - 0.005575
+}
+
+/// Apparent longitude of the Sun at `moment` in degrees, `0.0..360.0`
+pub(crate) fn solar_longitude(moment: f64) -> f64 {
+	let c = julian_centuries(moment);
+
+	let lambda: f64 = SOLAR_LONGITUDE_TERMS
+		.iter()
+		.map(|(x, y, z)| x * sin_degrees(y + z * c))
+		.sum();
+
+	let lambda = 282.7771834 + 36000.76953744 * c + 0.000005729577951308232 * lambda;
+	(lambda + aberration(c) + nutation(c)).rem_euclid(360.0)
+}
+
+/// Find the moment in `(low, high)` where the angle returned by `f` crosses `angle`
+///
+/// `f` must increase by less than `180°` over the range.
+pub(crate) fn invert_angular(f: impl Fn(f64) -> f64, angle: f64, mut low: f64, mut high: f64) -> f64 {
+	// About 1 second.
+	while high - low > 0.00001 {
+		let mid = (low + high) / 2.0;
+		if (f(mid) - angle).rem_euclid(360.0) < 180.0 {
+			high = mid;
+		} else {
+			low = mid;
+		}
+	}
+	(low + high) / 2.0
+}
+
+/// The first moment at or after `moment` when the Sun reaches `longitude`
+pub(crate) fn solar_longitude_after(longitude: f64, moment: f64) -> f64 {
+	let rate = MEAN_TROPICAL_YEAR / 360.0;
+	let tau  = moment + rate * (longitude - solar_longitude(moment)).rem_euclid(360.0);
+	invert_angular(solar_longitude, longitude, moment.max(tau - 5.0), tau + 5.0)
+}
+
+//---------------------------------------------------------------------------------------------------- Moon
+// The lunar algorithms are from "Astronomical Algorithms" (Meeus), chapters 48 and 49.
+
+/// Julian Day of Rata Die `0.0`
+const JULIAN_DAY_RATA_DIE_EPOCH: f64 = 1721424.5;
+
+/// Mean synodic month in days
+pub(crate) const MEAN_SYNODIC_MONTH: f64 = 29.530588861;
+
+/// Moment of the mean new moon `0`, `January 6th, 2000`
+const MEAN_NEW_MOON_ZERO: f64 = 2451550.09766 - JULIAN_DAY_RATA_DIE_EPOCH;
+
+/// `(coefficient, power of E, M, M', F)` of the new and full moon corrections
+type Term = (f64, i32, f64, f64, f64);
+
+const NEW_MOON_TERMS: [Term; 24] = [
+	(-0.40720, 0, 0.0, 1.0, 0.0),
+	( 0.17241, 1, 1.0, 0.0, 0.0),
+	( 0.01608, 0, 0.0, 2.0, 0.0),
+	( 0.01039, 0, 0.0, 0.0, 2.0),
+	( 0.00739, 1, -1.0, 1.0, 0.0),
+	(-0.00514, 1, 1.0, 1.0, 0.0),
+	( 0.00208, 2, 2.0, 0.0, 0.0),
+	(-0.00111, 0, 0.0, 1.0, -2.0),
+	(-0.00057, 0, 0.0, 1.0, 2.0),
+	( 0.00056, 1, 1.0, 2.0, 0.0),
+	(-0.00042, 0, 0.0, 3.0, 0.0),
+	( 0.00042, 1, 1.0, 0.0, 2.0),
+	( 0.00038, 1, 1.0, 0.0, -2.0),
+	(-0.00024, 1, -1.0, 2.0, 0.0),
+	(-0.00007, 0, 2.0, 1.0, 0.0),
+	( 0.00004, 0, 0.0, 2.0, -2.0),
+	( 0.00004, 0, 3.0, 0.0, 0.0),
+	( 0.00003, 0, 1.0, 1.0, -2.0),
+	( 0.00003, 0, 0.0, 2.0, 2.0),
+	(-0.00003, 0, 1.0, 1.0, 2.0),
+	( 0.00003, 0, -1.0, 1.0, 2.0),
+	(-0.00002, 0, -1.0, 1.0, -2.0),
+	(-0.00002, 0, 1.0, 3.0, 0.0),
+	( 0.00002, 0, 0.0, 4.0, 0.0),
+];
+
+const FULL_MOON_TERMS: [Term; 24] = [
+	(-0.40614, 0, 0.0, 1.0, 0.0),
+	( 0.17302, 1, 1.0, 0.0, 0.0),
+	( 0.01614, 0, 0.0, 2.0, 0.0),
+	( 0.01043, 0, 0.0, 0.0, 2.0),
+	( 0.00734, 1, -1.0, 1.0, 0.0),
+	(-0.00515, 1, 1.0, 1.0, 0.0),
+	( 0.00209, 2, 2.0, 0.0, 0.0),
+	(-0.00111, 0, 0.0, 1.0, -2.0),
+	(-0.00057, 0, 0.0, 1.0, 2.0),
+	( 0.00056, 1, 1.0, 2.0, 0.0),
+	(-0.00042, 0, 0.0, 3.0, 0.0),
+	( 0.00042, 1, 1.0, 0.0, 2.0),
+	( 0.00038, 1, 1.0, 0.0, -2.0),
+	(-0.00024, 1, -1.0, 2.0, 0.0),
+	(-0.00007, 0, 2.0, 1.0, 0.0),
+	( 0.00004, 0, 0.0, 2.0, -2.0),
+	( 0.00004, 0, 3.0, 0.0, 0.0),
+	( 0.00003, 0, 1.0, 1.0, -2.0),
+	( 0.00003, 0, 0.0, 2.0, 2.0),
+	(-0.00003, 0, 1.0, 1.0, 2.0),
+	( 0.00003, 0, -1.0, 1.0, 2.0),
+	(-0.00002, 0, -1.0, 1.0, -2.0),
+	(-0.00002, 0, 1.0, 3.0, 0.0),
+	( 0.00002, 0, 0.0, 4.0, 0.0),
+];
+
+const QUARTER_TERMS: [Term; 24] = [
+	(-0.62801, 0, 0.0, 1.0, 0.0),
+	( 0.17172, 1, 1.0, 0.0, 0.0),
+	(-0.01183, 1, 1.0, 1.0, 0.0),
+	( 0.00862, 0, 0.0, 2.0, 0.0),
+	( 0.00804, 0, 0.0, 0.0, 2.0),
+	( 0.00454, 1, -1.0, 1.0, 0.0),
+	( 0.00204, 2, 2.0, 0.0, 0.0),
+	(-0.00180, 0, 0.0, 1.0, -2.0),
+	(-0.00070, 0, 0.0, 1.0, 2.0),
+	(-0.00040, 0, 0.0, 3.0, 0.0),
+	(-0.00034, 1, -1.0, 2.0, 0.0),
+	( 0.00032, 1, 1.0, 0.0, 2.0),
+	( 0.00032, 1, 1.0, 0.0, -2.0),
+	(-0.00028, 2, 2.0, 1.0, 0.0),
+	( 0.00027, 1, 1.0, 2.0, 0.0),
+	(-0.00005, 0, -1.0, 1.0, -2.0),
+	( 0.00004, 0, 0.0, 2.0, 2.0),
+	(-0.00004, 0, 1.0, 1.0, 2.0),
+	( 0.00004, 0, -2.0, 1.0, 0.0),
+	( 0.00003, 0, 1.0, 1.0, -2.0),
+	( 0.00003, 0, 3.0, 0.0, 0.0),
+	( 0.00002, 0, 0.0, 2.0, -2.0),
+	( 0.00002, 0, -1.0, 1.0, 2.0),
+	(-0.00002, 0, 1.0, 3.0, 0.0),
+];
+
+/// `(coefficient, phase, rate)` of the planetary arguments
+const PLANETARY_TERMS: [(f64, f64, f64); 14] = [
+	(0.000325, 299.77, 0.107408),
+	(0.000165, 251.88, 0.016321),
+	(0.000164, 251.83, 26.651886),
+	(0.000126, 349.42, 36.412478),
+	(0.000110,  84.66, 18.206239),
+	(0.000062, 141.74, 53.303771),
+	(0.000060, 207.14, 2.453732),
+	(0.000056, 154.84, 7.306860),
+	(0.000047,  34.52, 27.261239),
+	(0.000042, 207.19, 0.121824),
+	(0.000040, 291.34, 1.844379),
+	(0.000037, 161.72, 24.198154),
+	(0.000035, 239.56, 25.513099),
+	(0.000023, 331.55, 3.592518),
+];
+
+/// The moment of the `quarter`th principal moon phase after the new moon of January 6th, 2000
+///
+/// `quarter % 4` is `0` for new moons, `1` for first quarters, `2` for full moons and `3` for last quarters.
+pub(crate) fn moon_phase_moment(quarter: i64) -> f64 {
+	let k = quarter as f64 / 4.0;
+	let t = k / 1236.85;
+	let t2 = t * t;
+
+	let mean = 2451550.09766
+		+ MEAN_SYNODIC_MONTH * k
+		+ 0.00015437 * t2
+		- 0.000000150 * t2 * t
+		+ 0.00000000073 * t2 * t2;
+
+	let e  = 1.0 - 0.002516 * t - 0.0000074 * t2;
+	let m  = 2.5534 + 29.10535670 * k - 0.0000014 * t2 - 0.00000011 * t2 * t;
+	let m1 = 201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t2 * t - 0.000000058 * t2 * t2;
+	let f  = 160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t2 * t + 0.000000011 * t2 * t2;
+	let omega = 124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t2 * t;
+
+	let terms = match quarter.rem_euclid(4) {
+		0 => &NEW_MOON_TERMS,
+		2 => &FULL_MOON_TERMS,
+		_ => &QUARTER_TERMS,
+	};
+
+	let mut correction: f64 = terms
+		.iter()
+		.map(|&(c, e_power, a, b, d)| c * e.powi(e_power) * sin_degrees(a * m + b * m1 + d * f))
+		.sum();
+
+	correction -= 0.00017 * sin_degrees(omega);
+
+	let w = 0.00306
+		- 0.00038 * e * cos_degrees(m)
+		+ 0.00026 * cos_degrees(m1)
+		- 0.00002 * cos_degrees(m1 - m)
+		+ 0.00002 * cos_degrees(m1 + m)
+		+ 0.00002 * cos_degrees(2.0 * f);
+
+	match quarter.rem_euclid(4) {
+		1 => correction += w,
+		3 => correction -= w,
+		_ => (),
+	}
+
+	correction += PLANETARY_TERMS
+		.iter()
+		.enumerate()
+		.map(|(i, &(c, phase, rate))| {
+			// Only the first argument has a `T²` term.
+			let extra = if i == 0 { -0.009173 * t2 } else { 0.0 };
+			c * sin_degrees(phase + rate * k + extra)
+		})
+		.sum::<f64>();
+
+	let dynamical = mean + correction - JULIAN_DAY_RATA_DIE_EPOCH;
+	dynamical - ephemeris_correction(dynamical)
+}
+
+#[inline]
+/// The `quarter` (see [`moon_phase_moment`]) of the principal moon phase at or before `moment`
+pub(crate) fn moon_phase_quarter_before(moment: f64) -> i64 {
+	let mut quarter = ((moment - MEAN_NEW_MOON_ZERO) / MEAN_SYNODIC_MONTH * 4.0).floor() as i64 + 1;
+	while moon_phase_moment(quarter) > moment {
+		quarter -= 1;
+	}
+	quarter
+}
+
+/// Elongation of the Moon from the Sun at `moment` in degrees, `0.0..360.0`
+///
+/// `0°` is a new moon, `180°` is a full moon.
+pub(crate) fn moon_elongation(moment: f64) -> f64 {
+	let t  = julian_centuries(moment);
+	let t2 = t * t;
+
+	let d  = 297.8501921 + 445267.1114034 * t - 0.0018819 * t2 + t2 * t / 545868.0 - t2 * t2 / 113065000.0;
+	let m  = 357.5291092 + 35999.0502909 * t - 0.0001536 * t2 + t2 * t / 24490000.0;
+	let m1 = 134.9633964 + 477198.8675055 * t + 0.0087414 * t2 + t2 * t / 69699.0 - t2 * t2 / 14712000.0;
+
+	// This is `180° - i`, where `i` is the phase angle.
+	(d
+		+ 6.289 * sin_degrees(m1)
+		- 2.100 * sin_degrees(m)
+		+ 1.274 * sin_degrees(2.0 * d - m1)
+		+ 0.658 * sin_degrees(2.0 * d)
+		+ 0.214 * sin_degrees(2.0 * m1)
+		+ 0.110 * sin_degrees(d)
+	).rem_euclid(360.0)
+}
+
+#[inline]
+/// Illuminated fraction of the Moon's disk at `moment`, `0.0..=1.0`
+pub(crate) fn moon_illumination(moment: f64) -> f64 {
+	(1.0 - cos_degrees(moon_elongation(moment))) / 2.0
+}
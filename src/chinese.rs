@@ -0,0 +1,1121 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Rata Die of a `甲子` day, `October 1st, 1949` minus a multiple of `60`
+const SEXAGENARY_DAY_EPOCH: i128 = 46;
+
+// Year data for the lunar years starting in the Gregorian years `1900..=2100`.
+//
+// Computed with the astronomical new moon and solar term
+// algorithms of "Calendrical Calculations" (Reingold & Dershowitz).
+//
+// Bit layout of each entry:
+// - `0..=12`  month lengths in order, including the leap month (`1` = 30 days, `0` = 29 days)
+// - `13..=16` number of the month that is followed by a leap month (`0` = no leap month)
+// - `17..=22` days from January 1st to the new year
+const CHINA: [u32; 201] = [
+	0x3d16d2, 0x620752, 0x4c0ea5, 0x38b64a, 0x5c064b, 0x440a9b, 0x30955a, 0x56056a, 0x400b59, 0x2a5752,
+	0x500752, 0x3adb25, 0x600b25, 0x480a4b, 0x32b4ab, 0x5802ad, 0x42056b, 0x2c4b69, 0x520da9, 0x3efd92,
+	0x640e92, 0x4c0d25, 0x36ba4d, 0x5c0a56, 0x4602b6, 0x2e95b5, 0x5606d4, 0x400ea9, 0x2c5e92, 0x500e92,
+	0x3acd26, 0x5e052b, 0x480a57, 0x32b2b6, 0x580b5a, 0x4406d4, 0x2e6ec9, 0x520749, 0x3cf693, 0x620a93,
+	0x4c052b, 0x34ca5b, 0x5a0aad, 0x46056a, 0x309b55, 0x560ba4, 0x400b49, 0x2a5a93, 0x500a95, 0x38f52d,
+	0x5e0536, 0x480aad, 0x34b5aa, 0x5805b2, 0x420da5, 0x2e7d4a, 0x540d4a, 0x3d0a95, 0x600a97, 0x4c0556,
+	0x36cab5, 0x5a0ad5, 0x4606d2, 0x308ea5, 0x560ea5, 0x40064a, 0x286c97, 0x4e0a9b, 0x3af55a, 0x5e056a,
+	0x480b69, 0x34b752, 0x5a0b52, 0x420b25, 0x2c964b, 0x520a4b, 0x3d14ab, 0x6002ad, 0x4a056d, 0x36cb69,
+	0x5c0da9, 0x460d92, 0x309d25, 0x560d25, 0x415a4d, 0x640a56, 0x4e02b6, 0x38c5b5, 0x5e06d5, 0x480ea9,
+	0x34be92, 0x5a0e92, 0x440d26, 0x2c6a56, 0x500a57, 0x3d14d6, 0x62035a, 0x4a06d5, 0x36b6c9, 0x5c0749,
+	0x460693, 0x2e952b, 0x54052b, 0x3e0a5b, 0x2a555a, 0x4e056a, 0x38fb55, 0x600ba4, 0x4a0b49, 0x32ba93,
+	0x580a95, 0x42052d, 0x2c8aad, 0x500ab5, 0x3d35aa, 0x6205d2, 0x4c0da5, 0x36dd4a, 0x5c0d4a, 0x460c95,
+	0x30952e, 0x540556, 0x3e0ab5, 0x2a55b2, 0x5006d2, 0x38cea5, 0x5e0725, 0x48064b, 0x32ac97, 0x560cab,
+	0x42055a, 0x2c6ad6, 0x520b69, 0x3d7752, 0x620b52, 0x4c0b25, 0x36da4b, 0x5a0a4b, 0x4404ab, 0x2ea55b,
+	0x5405ad, 0x3e0b6a, 0x2a5b52, 0x500d92, 0x3afd25, 0x5e0d25, 0x480a55, 0x32b4ad, 0x5804b6, 0x4005b5,
+	0x2c6daa, 0x520ec9, 0x3f1e92, 0x620e92, 0x4c0d26, 0x36ca56, 0x5a0a57, 0x440556, 0x2e86d5, 0x540755,
+	0x400749, 0x286e93, 0x4e0693, 0x38f52b, 0x5e052b, 0x460a5b, 0x32b55a, 0x58056a, 0x420b65, 0x2c974a,
+	0x520b4a, 0x3d1a95, 0x620a95, 0x4a052d, 0x34caad, 0x5a0ab5, 0x4605aa, 0x2e8ba5, 0x540da5, 0x400d4a,
+	0x2a7c95, 0x4e0c96, 0x38f94e, 0x5e0556, 0x480ab5, 0x32b5b2, 0x5806d2, 0x420ea5, 0x2e8e4a, 0x50068b,
+	0x3b0c97, 0x6004ab, 0x4a055b, 0x34cad6, 0x5a0b6a, 0x460752, 0x309725, 0x540b45, 0x3e0a8b, 0x28549b,
+	0x4e04ab,
+];
+
+/// Same as [`CHINA`], observed in Japan Standard Time (UTC+9)
+const JAPAN: [u32; 201] = [
+	0x3d16d2, 0x620752, 0x4c0ea5, 0x38ad4a, 0x5c054b, 0x440a97, 0x309556, 0x56055a, 0x400b55, 0x2a56d2,
+	0x500752, 0x3ad725, 0x600b25, 0x480a4b, 0x32b29b, 0x580aad, 0x44056a, 0x2c4b69, 0x520ba9, 0x3efb52,
+	0x640d92, 0x4c0d25, 0x36ba4d, 0x5c0956, 0x4602b5, 0x2e95ad, 0x5606d4, 0x400da9, 0x2c5d92, 0x500e92,
+	0x3acd26, 0x5e0527, 0x480a57, 0x32b2b6, 0x580ada, 0x4406d4, 0x2e6ea9, 0x520749, 0x3cf693, 0x620a93,
+	0x4c052b, 0x34ca5b, 0x5a096d, 0x460b6a, 0x329b54, 0x560ba4, 0x400b49, 0x2a5a93, 0x500a95, 0x38f52b,
+	0x5e052d, 0x480aad, 0x34b56a, 0x580db2, 0x440da4, 0x2e7d49, 0x540d4a, 0x3d1a95, 0x620a96, 0x4c0556,
+	0x36cab5, 0x5a0ad5, 0x4606d2, 0x308ea5, 0x560ea5, 0x400e4a, 0x2a6c96, 0x4e0a9b, 0x3af556, 0x5e056a,
+	0x480b59, 0x34b752, 0x5a0752, 0x420725, 0x2c964b, 0x520a4b, 0x3d12ab, 0x6002ad, 0x4a056b, 0x36cb69,
+	0x5c0da9, 0x460d92, 0x309b25, 0x560d25, 0x415a4d, 0x640a56, 0x4e02b6, 0x38d5ad, 0x6006d4, 0x480da9,
+	0x34bd92, 0x5a0e92, 0x440d26, 0x2c6a56, 0x500a57, 0x3d12b6, 0x620b5a, 0x4c06d4, 0x36aec9, 0x5c0749,
+	0x460693, 0x2e9527, 0x54052b, 0x3e0a5b, 0x2a555a, 0x4e036a, 0x38fb55, 0x600ba4, 0x4a0b49, 0x32ba93,
+	0x580a95, 0x42052d, 0x2c6a5d, 0x500aad, 0x3d35aa, 0x6205d2, 0x4c0da5, 0x36bd4a, 0x5c0d4a, 0x460a95,
+	0x30952d, 0x540556, 0x3e0ab5, 0x2a55aa, 0x5006d2, 0x38cea5, 0x5e0ea5, 0x4a0e4a, 0x34ac96, 0x560c9b,
+	0x42055a, 0x2c6ad5, 0x520b69, 0x3d7752, 0x620752, 0x4c0b25, 0x36d64b, 0x5a0a4b, 0x4404ab, 0x2ea55b,
+	0x54056d, 0x3e0b69, 0x2a5b52, 0x500d92, 0x3afd25, 0x5e0d25, 0x480a4d, 0x32b4ad, 0x5802b6, 0x4005b5,
+	0x2c6da9, 0x520ea9, 0x3f1d92, 0x620e92, 0x4c0d26, 0x36ca56, 0x5a0a57, 0x4404d6, 0x2e86b5, 0x5406d5,
+	0x400ec9, 0x2a6e92, 0x4e0693, 0x38f52b, 0x5e052b, 0x460a5b, 0x32b55a, 0x58056a, 0x420b55, 0x2c9749,
+	0x520b49, 0x3d1a93, 0x620a95, 0x4a052d, 0x34caad, 0x5a0ab5, 0x4605aa, 0x2e8ba5, 0x540da5, 0x400d4a,
+	0x2a7a95, 0x4e0c95, 0x38f52e, 0x5e0556, 0x480ab5, 0x32b5b2, 0x5806d2, 0x420ea5, 0x2e9e4a, 0x52064a,
+	0x3b0c97, 0x600cab, 0x4c055a, 0x34cad5, 0x5a0b69, 0x460752, 0x3096a5, 0x540b25, 0x3e064b, 0x287497,
+	0x4e04ab,
+];
+
+//---------------------------------------------------------------------------------------------------- ChineseCalendar
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Where the new moons and solar terms of the Chinese lunisolar calendar are observed
+///
+/// Months start on the day of the new moon, so the calendar
+/// depends on the time zone; Japan's old calendar (旧暦), which
+/// [`Rokuyo`] is based on, sometimes starts a month a day later than China's.
+///
+/// Both are embedded for the lunar years starting in
+/// [`ChineseCalendar::FIRST_YEAR`] up to [`ChineseCalendar::LAST_YEAR`],
+/// all functions return [`None`] outside of that range.
+///
+/// ```rust
+/// # use nichi::*;
+/// // The new moon was at 23:53 in China, 00:53 the next day in Japan.
+/// let date = Date::new(2023, 5, 19);
+/// assert_eq!(ChineseCalendar::China.from_date(date), Some(ChineseDate::new(2023, 4, false, 1)));
+/// assert_eq!(ChineseCalendar::Japan.from_date(date), Some(ChineseDate::new(2023, 3, false, 30)));
+/// ```
+pub enum ChineseCalendar {
+	#[default]
+	/// China Standard Time (UTC+8)
+	China,
+	/// Japan Standard Time (UTC+9)
+	Japan,
+}
+
+impl ChineseCalendar {
+	/// The first lunar year with data
+	pub const FIRST_YEAR: i32 = 1900;
+
+	/// The last lunar year with data
+	pub const LAST_YEAR: i32 = 2100;
+
+	#[inline]
+	/// Table entry for `year`
+	const fn entry(self, year: i32) -> Option<u32> {
+		if year < Self::FIRST_YEAR || year > Self::LAST_YEAR {
+			return None;
+		}
+
+		let index = (year - Self::FIRST_YEAR) as usize;
+		Some(match self {
+			Self::China => CHINA[index],
+			Self::Japan => JAPAN[index],
+		})
+	}
+
+	#[inline]
+	/// Number of months (`12` or `13`) in a table entry
+	const fn months(entry: u32) -> usize {
+		if (entry >> 13) & 0xF == 0 { 12 } else { 13 }
+	}
+
+	#[inline]
+	/// Days in the month at position `index` of a table entry
+	const fn days_at(entry: u32, index: usize) -> u8 {
+		29 + ((entry >> index) & 1) as u8
+	}
+
+	#[inline]
+	/// Position of `month` within a table entry, [`None`] if it doesn't exist
+	const fn index(entry: u32, month: u8, leap_month: bool) -> Option<usize> {
+		if month == 0 || month > 12 {
+			return None;
+		}
+
+		let leap = ((entry >> 13) & 0xF) as u8;
+		if leap_month {
+			if leap == month { Some(month as usize) } else { None }
+		} else if leap != 0 && month > leap {
+			Some(month as usize)
+		} else {
+			Some(month as usize - 1)
+		}
+	}
+
+	#[inline]
+	/// Rata Die of the new year of a table entry
+	const fn new_year_rata_die(entry: u32, year: i32) -> i128 {
+		Date::new(year as i16, 1, 1).to_rata_die() + (entry >> 17) as i128
+	}
+
+	#[inline]
+	/// The number of the month that is followed by a leap month in `year`
+	///
+	/// Returns [`None`] if `year` has no leap month.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseCalendar::China.leap_month(2023), Some(2));
+	/// assert_eq!(ChineseCalendar::China.leap_month(2024), None);
+	/// assert_eq!(ChineseCalendar::China.leap_month(2025), Some(6));
+	/// assert_eq!(ChineseCalendar::China.leap_month(2033), Some(11));
+	/// ```
+	pub const fn leap_month(self, year: i32) -> Option<u8> {
+		match self.entry(year) {
+			Some(entry) if (entry >> 13) & 0xF != 0 => Some(((entry >> 13) & 0xF) as u8),
+			_ => None,
+		}
+	}
+
+	#[inline]
+	/// Days in `month` of `year`, [`None`] if the month does not exist
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseCalendar::China.days_in_month(2023, 2, false), Some(30));
+	/// assert_eq!(ChineseCalendar::China.days_in_month(2023, 2, true),  Some(29));
+	/// assert_eq!(ChineseCalendar::China.days_in_month(2023, 3, true),  None);
+	/// ```
+	pub const fn days_in_month(self, year: i32, month: u8, leap_month: bool) -> Option<u8> {
+		let Some(entry) = self.entry(year) else { return None };
+		match Self::index(entry, month, leap_month) {
+			Some(index) => Some(Self::days_at(entry, index)),
+			None => None,
+		}
+	}
+
+	/// Days in `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseCalendar::China.days_in_year(2023), Some(384));
+	/// assert_eq!(ChineseCalendar::China.days_in_year(2024), Some(354));
+	/// assert_eq!(ChineseCalendar::China.days_in_year(1899), None);
+	/// ```
+	pub const fn days_in_year(self, year: i32) -> Option<u16> {
+		let Some(entry) = self.entry(year) else { return None };
+
+		let mut days = 0;
+		let mut index = 0;
+		while index < Self::months(entry) {
+			days += Self::days_at(entry, index) as u16;
+			index += 1;
+		}
+		Some(days)
+	}
+
+	#[inline]
+	/// The [`Date`] of the new year (`1/1`) of `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseCalendar::China.new_year(1900), Some(Date::new(1900, 1, 31)));
+	/// assert_eq!(ChineseCalendar::China.new_year(2024), Some(Date::new(2024, 2, 10)));
+	/// assert_eq!(ChineseCalendar::China.new_year(2025), Some(Date::new(2025, 1, 29)));
+	/// assert_eq!(ChineseCalendar::China.new_year(2101), None);
+	/// ```
+	pub const fn new_year(self, year: i32) -> Option<Date> {
+		match self.entry(year) {
+			Some(entry) => Some(Date::from_rata_die(Self::new_year_rata_die(entry, year))),
+			None => None,
+		}
+	}
+
+	/// Convert a [`ChineseDate`] into a Rata Die
+	///
+	/// Returns [`None`] if `date` does not exist.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseCalendar::China.to_rata_die(ChineseDate::new(2024, 1, false, 1)), Some(738926));
+	/// assert_eq!(ChineseCalendar::China.to_rata_die(ChineseDate::new(2024, 1, true, 1)),  None);
+	/// ```
+	pub const fn to_rata_die(self, date: ChineseDate) -> Option<i128> {
+		let Some(entry) = self.entry(date.year) else { return None };
+		let Some(target) = Self::index(entry, date.month, date.leap_month) else { return None };
+
+		if date.day > Self::days_at(entry, target) {
+			return None;
+		}
+
+		let mut rata_die = Self::new_year_rata_die(entry, date.year);
+		let mut index = 0;
+		while index < target {
+			rata_die += Self::days_at(entry, index) as i128;
+			index += 1;
+		}
+		Some(rata_die + date.day as i128 - 1)
+	}
+
+	/// Convert a Rata Die into a [`ChineseDate`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseCalendar::China.from_rata_die(738926), Some(ChineseDate::new(2024, 1, false, 1)));
+	/// assert_eq!(ChineseCalendar::China.from_rata_die(0), None);
+	/// ```
+	pub const fn from_rata_die(self, rata_die: i128) -> Option<ChineseDate> {
+		let mut year = Date::from_rata_die(rata_die).year().inner() as i32;
+
+		// Dates before the new year belong to the previous lunar year.
+		let (entry, new_year) = match self.entry(year) {
+			Some(entry) if rata_die >= Self::new_year_rata_die(entry, year) => {
+				(entry, Self::new_year_rata_die(entry, year))
+			},
+			_ => {
+				year -= 1;
+				match self.entry(year) {
+					Some(entry) => (entry, Self::new_year_rata_die(entry, year)),
+					None => return None,
+				}
+			},
+		};
+
+		let mut days = rata_die - new_year;
+		let mut index = 0;
+		while index < Self::months(entry) {
+			let len = Self::days_at(entry, index) as i128;
+			if days < len {
+				let leap = ((entry >> 13) & 0xF) as usize;
+				let (month, leap_month) = if leap != 0 && index == leap {
+					(index, true)
+				} else if leap != 0 && index > leap {
+					(index, false)
+				} else {
+					(index + 1, false)
+				};
+
+				return Some(ChineseDate {
+					year,
+					month: month as u8,
+					leap_month,
+					day: days as u8 + 1,
+				});
+			}
+			days -= len;
+			index += 1;
+		}
+
+		// After the end of `LAST_YEAR`.
+		None
+	}
+
+	#[inline]
+	/// Convert a [`ChineseDate`] into a [`Date`]
+	pub const fn to_date(self, date: ChineseDate) -> Option<Date> {
+		match self.to_rata_die(date) {
+			Some(rata_die) => Some(Date::from_rata_die(rata_die)),
+			None => None,
+		}
+	}
+
+	#[inline]
+	/// Convert a [`Date`] into a [`ChineseDate`]
+	pub const fn from_date(self, date: Date) -> Option<ChineseDate> {
+		self.from_rata_die(date.to_rata_die())
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- ChineseDate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Chinese lunisolar calendar date
+///
+/// The year is numbered by the Gregorian year it starts in.
+///
+/// A leap month has the same number as the month before it,
+/// e.g. `閏二月` is `month: 2, leap_month: true`.
+///
+/// [`ChineseDate::from_date`] and [`ChineseDate::to_date`] use [`ChineseCalendar::China`].
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = ChineseDate::from_date(Date::new(2023, 9, 29)).unwrap();
+/// assert_eq!(date, ChineseDate::new(2023, 8, false, 15));
+/// assert_eq!(date.to_date(), Some(Date::new(2023, 9, 29)));
+/// ```
+pub struct ChineseDate {
+	year: i32,
+	month: u8,
+	leap_month: bool,
+	day: u8,
+}
+
+impl ChineseDate {
+	#[inline]
+	/// Create a new [`ChineseDate`]
+	///
+	/// Whether the date actually exists is checked on conversion.
+	///
+	/// ## Panics
+	/// This function panics if:
+	/// - `month` is not `1..=12`
+	/// - `day` is not `1..=30`
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// ChineseDate::new(2023, 13, false, 1);
+	/// ```
+	pub const fn new(year: i32, month: u8, leap_month: bool, day: u8) -> Self {
+		assert!(month != 0, "month was 0");
+		assert!(month < 13, "month was greater than 12");
+		assert!(day != 0, "day was 0");
+		assert!(day < 31, "day was greater than 30");
+		Self { year, month, leap_month, day }
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseDate::new(2023, 2, true, 1).year(), 2023);
+	/// ```
+	pub const fn year(self) -> i32 {
+		self.year
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseDate::new(2023, 2, true, 1).month(), 2);
+	/// ```
+	pub const fn month(self) -> u8 {
+		self.month
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert!(ChineseDate::new(2023, 2, true, 1).is_leap_month());
+	/// ```
+	pub const fn is_leap_month(self) -> bool {
+		self.leap_month
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseDate::new(2023, 2, true, 1).day(), 1);
+	/// ```
+	pub const fn day(self) -> u8 {
+		self.day
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseDate::new(2023, 2, true, 1).inner(), (2023, 2, true, 1));
+	/// ```
+	pub const fn inner(self) -> (i32, u8, bool, u8) {
+		(self.year, self.month, self.leap_month, self.day)
+	}
+
+	#[inline]
+	/// The sexagenary name of this lunar year
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// // 2024-02-09 was still in the year of the rabbit.
+	/// let date = ChineseDate::from_date(Date::new(2024, 2, 9)).unwrap();
+	/// assert_eq!(date.year_sexagenary().as_str(), "癸卯");
+	/// assert_eq!(date.year_sexagenary().branch(), EarthlyBranch::Mao);
+	/// ```
+	pub const fn year_sexagenary(self) -> Sexagenary {
+		Sexagenary::from_year(self.year)
+	}
+
+	#[inline]
+	/// Convert a [`Date`] into a [`ChineseDate`] with [`ChineseCalendar::China`]
+	pub const fn from_date(date: Date) -> Option<Self> {
+		ChineseCalendar::China.from_date(date)
+	}
+
+	#[inline]
+	/// Convert into a [`Date`] with [`ChineseCalendar::China`]
+	pub const fn to_date(self) -> Option<Date> {
+		ChineseCalendar::China.to_date(self)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- ChineseHoliday
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Traditional Chinese holidays on fixed lunar dates
+///
+/// ```rust
+/// # use nichi::*;
+/// let year = Year(2024);
+/// assert_eq!(ChineseHoliday::SpringFestival.date(year),     Some(Date::new(2024, 2, 10)));
+/// assert_eq!(ChineseHoliday::LanternFestival.date(year),    Some(Date::new(2024, 2, 24)));
+/// assert_eq!(ChineseHoliday::DragonBoatFestival.date(year), Some(Date::new(2024, 6, 10)));
+/// assert_eq!(ChineseHoliday::Qixi.date(year),               Some(Date::new(2024, 8, 10)));
+/// assert_eq!(ChineseHoliday::MidAutumnFestival.date(year),  Some(Date::new(2024, 9, 17)));
+/// assert_eq!(ChineseHoliday::DoubleNinthFestival.date(year), Some(Date::new(2024, 10, 11)));
+/// ```
+pub enum ChineseHoliday {
+	/// `1/1`, Lunar New Year
+	SpringFestival,
+	/// `1/15`
+	LanternFestival,
+	/// `5/5`
+	DragonBoatFestival,
+	/// `7/7`
+	Qixi,
+	/// `7/15`
+	GhostFestival,
+	/// `8/15`
+	MidAutumnFestival,
+	/// `9/9`
+	DoubleNinthFestival,
+}
+
+impl ChineseHoliday {
+	/// All holidays, in order of the year
+	pub const ALL: [Self; 7] = [
+		Self::SpringFestival,
+		Self::LanternFestival,
+		Self::DragonBoatFestival,
+		Self::Qixi,
+		Self::GhostFestival,
+		Self::MidAutumnFestival,
+		Self::DoubleNinthFestival,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseHoliday::SpringFestival.as_str(),    "Spring Festival");
+	/// assert_eq!(ChineseHoliday::MidAutumnFestival.as_str(), "Mid-Autumn Festival");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::SpringFestival      => "Spring Festival",
+			Self::LanternFestival     => "Lantern Festival",
+			Self::DragonBoatFestival  => "Dragon Boat Festival",
+			Self::Qixi                => "Qixi Festival",
+			Self::GhostFestival       => "Ghost Festival",
+			Self::MidAutumnFestival   => "Mid-Autumn Festival",
+			Self::DoubleNinthFestival => "Double Ninth Festival",
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseHoliday::SpringFestival.as_str_zh(),    "春节");
+	/// assert_eq!(ChineseHoliday::MidAutumnFestival.as_str_zh(), "中秋节");
+	/// ```
+	pub const fn as_str_zh(self) -> &'static str {
+		match self {
+			Self::SpringFestival      => "春节",
+			Self::LanternFestival     => "元宵节",
+			Self::DragonBoatFestival  => "端午节",
+			Self::Qixi                => "七夕",
+			Self::GhostFestival       => "中元节",
+			Self::MidAutumnFestival   => "中秋节",
+			Self::DoubleNinthFestival => "重阳节",
+		}
+	}
+
+	#[inline]
+	/// The [`ChineseDate`] of this holiday in the lunar `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseHoliday::Qixi.chinese_date(2024), ChineseDate::new(2024, 7, false, 7));
+	/// ```
+	pub const fn chinese_date(self, year: i32) -> ChineseDate {
+		let (month, day) = match self {
+			Self::SpringFestival      => (1, 1),
+			Self::LanternFestival     => (1, 15),
+			Self::DragonBoatFestival  => (5, 5),
+			Self::Qixi                => (7, 7),
+			Self::GhostFestival       => (7, 15),
+			Self::MidAutumnFestival   => (8, 15),
+			Self::DoubleNinthFestival => (9, 9),
+		};
+
+		ChineseDate { year, month, leap_month: false, day }
+	}
+
+	#[inline]
+	/// The [`Date`] of this holiday in the Gregorian `year`
+	///
+	/// Returns [`None`] outside of [`ChineseCalendar::FIRST_YEAR`] and [`ChineseCalendar::LAST_YEAR`].
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ChineseHoliday::SpringFestival.date(Year(2023)),    Some(Date::new(2023, 1, 22)));
+	/// assert_eq!(ChineseHoliday::MidAutumnFestival.date(Year(2023)), Some(Date::new(2023, 9, 29)));
+	/// assert_eq!(ChineseHoliday::MidAutumnFestival.date(Year(1800)), None);
+	/// ```
+	pub const fn date(self, year: Year) -> Option<Date> {
+		self.chinese_date(year.inner() as i32).to_date()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Rokuyo
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Rokuyō (六曜), the Japanese 6-day cycle of auspicious days
+///
+/// The cycle is `(month + day) % 6` of Japan's old calendar,
+/// so it restarts on the first day of every lunar month.
+///
+/// ```rust
+/// # use nichi::*;
+/// assert_eq!(Rokuyo::from_date(Date::new(2024, 1, 1)),  Some(Rokuyo::Shakko));
+/// assert_eq!(Rokuyo::from_date(Date::new(2024, 2, 10)), Some(Rokuyo::Sensho));
+/// assert_eq!(Rokuyo::from_date(Date::new(2024, 2, 10)).unwrap().as_str_jp(), "先勝");
+/// ```
+pub enum Rokuyo {
+	#[default]
+	/// 先勝, good in the morning
+	Sensho,
+	/// 友引, bad for funerals
+	Tomobiki,
+	/// 先負, good in the afternoon
+	Senbu,
+	/// 仏滅, the most unlucky day
+	Butsumetsu,
+	/// 大安, the most lucky day
+	Taian,
+	/// 赤口, good only around noon
+	Shakko,
+}
+
+impl Rokuyo {
+	/// All [`Rokuyo`], in order of the cycle
+	pub const ALL: [Self; 6] = [
+		Self::Sensho,
+		Self::Tomobiki,
+		Self::Senbu,
+		Self::Butsumetsu,
+		Self::Taian,
+		Self::Shakko,
+	];
+
+	#[inline]
+	/// The [`Rokuyo`] of a lunar month and day
+	///
+	/// Leap months use the number of the month before them.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Rokuyo::from_month_day(1, 1), Rokuyo::Sensho);
+	/// assert_eq!(Rokuyo::from_month_day(2, 1), Rokuyo::Tomobiki);
+	/// assert_eq!(Rokuyo::from_month_day(1, 3), Rokuyo::Senbu);
+	/// assert_eq!(Rokuyo::from_month_day(7, 1), Rokuyo::Sensho);
+	/// ```
+	pub const fn from_month_day(month: u8, day: u8) -> Self {
+		Self::ALL[(month as usize + day as usize + 4) % 6]
+	}
+
+	#[inline]
+	/// The [`Rokuyo`] of a [`ChineseDate`]
+	///
+	/// This should be a date of [`ChineseCalendar::Japan`].
+	pub const fn from_chinese_date(date: ChineseDate) -> Self {
+		Self::from_month_day(date.month, date.day)
+	}
+
+	#[inline]
+	/// The [`Rokuyo`] of a [`Date`], using [`ChineseCalendar::Japan`]
+	///
+	/// Returns [`None`] outside of [`ChineseCalendar::FIRST_YEAR`] and [`ChineseCalendar::LAST_YEAR`].
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Rokuyo::from_date(Date::new(2025, 1, 1)), Some(Rokuyo::Sensho));
+	/// assert_eq!(Rokuyo::from_date(Date::new(1800, 1, 1)), None);
+	/// ```
+	pub const fn from_date(date: Date) -> Option<Self> {
+		match ChineseCalendar::Japan.from_date(date) {
+			Some(date) => Some(Self::from_chinese_date(date)),
+			None => None,
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Rokuyo::Sensho.as_str(),     "Sensho");
+	/// assert_eq!(Rokuyo::Tomobiki.as_str(),   "Tomobiki");
+	/// assert_eq!(Rokuyo::Senbu.as_str(),      "Senbu");
+	/// assert_eq!(Rokuyo::Butsumetsu.as_str(), "Butsumetsu");
+	/// assert_eq!(Rokuyo::Taian.as_str(),      "Taian");
+	/// assert_eq!(Rokuyo::Shakko.as_str(),     "Shakko");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Sensho     => "Sensho",
+			Self::Tomobiki   => "Tomobiki",
+			Self::Senbu      => "Senbu",
+			Self::Butsumetsu => "Butsumetsu",
+			Self::Taian      => "Taian",
+			Self::Shakko     => "Shakko",
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Rokuyo::Sensho.as_str_jp(),     "先勝");
+	/// assert_eq!(Rokuyo::Tomobiki.as_str_jp(),   "友引");
+	/// assert_eq!(Rokuyo::Senbu.as_str_jp(),      "先負");
+	/// assert_eq!(Rokuyo::Butsumetsu.as_str_jp(), "仏滅");
+	/// assert_eq!(Rokuyo::Taian.as_str_jp(),      "大安");
+	/// assert_eq!(Rokuyo::Shakko.as_str_jp(),     "赤口");
+	/// ```
+	pub const fn as_str_jp(self) -> &'static str {
+		match self {
+			Self::Sensho     => "先勝",
+			Self::Tomobiki   => "友引",
+			Self::Senbu      => "先負",
+			Self::Butsumetsu => "仏滅",
+			Self::Taian      => "大安",
+			Self::Shakko     => "赤口",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- HeavenlyStem
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Heavenly stem (天干)
+#[allow(missing_docs)]
+pub enum HeavenlyStem {
+	#[default]
+	Jia  = 1,
+	Yi   = 2,
+	Bing = 3,
+	Ding = 4,
+	Wu   = 5,
+	Ji   = 6,
+	Geng = 7,
+	Xin  = 8,
+	Ren  = 9,
+	Gui  = 10,
+}
+
+impl HeavenlyStem {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HeavenlyStem::ALL[0], HeavenlyStem::Jia);
+	/// assert_eq!(HeavenlyStem::ALL[9], HeavenlyStem::Gui);
+	/// ```
+	pub const ALL: [Self; 10] = [
+		Self::Jia,
+		Self::Yi,
+		Self::Bing,
+		Self::Ding,
+		Self::Wu,
+		Self::Ji,
+		Self::Geng,
+		Self::Xin,
+		Self::Ren,
+		Self::Gui,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HeavenlyStem::new(1),  HeavenlyStem::Jia);
+	/// assert_eq!(HeavenlyStem::new(10), HeavenlyStem::Gui);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// HeavenlyStem::new(11);
+	/// ```
+	pub const fn new(stem: u8) -> Self {
+		assert!(stem != 0, "stem must not be 0");
+		assert!(stem < 11, "stem must not be > 10");
+		Self::ALL[stem as usize - 1]
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HeavenlyStem::Jia.inner(), 1);
+	/// assert_eq!(HeavenlyStem::Gui.inner(), 10);
+	/// ```
+	pub const fn inner(self) -> u8 {
+		self as u8
+	}
+
+	#[inline]
+	/// Pinyin name of the stem
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HeavenlyStem::Jia.as_str(), "Jia");
+	/// assert_eq!(HeavenlyStem::Gui.as_str(), "Gui");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Jia  => "Jia",
+			Self::Yi   => "Yi",
+			Self::Bing => "Bing",
+			Self::Ding => "Ding",
+			Self::Wu   => "Wu",
+			Self::Ji   => "Ji",
+			Self::Geng => "Geng",
+			Self::Xin  => "Xin",
+			Self::Ren  => "Ren",
+			Self::Gui  => "Gui",
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HeavenlyStem::Jia.as_str_zh(), "甲");
+	/// assert_eq!(HeavenlyStem::Gui.as_str_zh(), "癸");
+	/// ```
+	pub const fn as_str_zh(self) -> &'static str {
+		match self {
+			Self::Jia  => "甲",
+			Self::Yi   => "乙",
+			Self::Bing => "丙",
+			Self::Ding => "丁",
+			Self::Wu   => "戊",
+			Self::Ji   => "己",
+			Self::Geng => "庚",
+			Self::Xin  => "辛",
+			Self::Ren  => "壬",
+			Self::Gui  => "癸",
+		}
+	}
+
+	#[inline]
+	/// Japanese reading of the stem
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HeavenlyStem::Jia.as_str_jp(), "きのえ");
+	/// assert_eq!(HeavenlyStem::Gui.as_str_jp(), "みずのと");
+	/// ```
+	pub const fn as_str_jp(self) -> &'static str {
+		match self {
+			Self::Jia  => "きのえ",
+			Self::Yi   => "きのと",
+			Self::Bing => "ひのえ",
+			Self::Ding => "ひのと",
+			Self::Wu   => "つちのえ",
+			Self::Ji   => "つちのと",
+			Self::Geng => "かのえ",
+			Self::Xin  => "かのと",
+			Self::Ren  => "みずのえ",
+			Self::Gui  => "みずのと",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- EarthlyBranch
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Earthly branch (地支), and its zodiac animal
+#[allow(missing_docs)]
+pub enum EarthlyBranch {
+	#[default]
+	Zi   = 1,
+	Chou = 2,
+	Yin  = 3,
+	Mao  = 4,
+	Chen = 5,
+	Si   = 6,
+	Wu   = 7,
+	Wei  = 8,
+	Shen = 9,
+	You  = 10,
+	Xu   = 11,
+	Hai  = 12,
+}
+
+impl EarthlyBranch {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EarthlyBranch::ALL[0],  EarthlyBranch::Zi);
+	/// assert_eq!(EarthlyBranch::ALL[11], EarthlyBranch::Hai);
+	/// ```
+	pub const ALL: [Self; 12] = [
+		Self::Zi,
+		Self::Chou,
+		Self::Yin,
+		Self::Mao,
+		Self::Chen,
+		Self::Si,
+		Self::Wu,
+		Self::Wei,
+		Self::Shen,
+		Self::You,
+		Self::Xu,
+		Self::Hai,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EarthlyBranch::new(1),  EarthlyBranch::Zi);
+	/// assert_eq!(EarthlyBranch::new(12), EarthlyBranch::Hai);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// EarthlyBranch::new(13);
+	/// ```
+	pub const fn new(branch: u8) -> Self {
+		assert!(branch != 0, "branch must not be 0");
+		assert!(branch < 13, "branch must not be > 12");
+		Self::ALL[branch as usize - 1]
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EarthlyBranch::Zi.inner(),  1);
+	/// assert_eq!(EarthlyBranch::Hai.inner(), 12);
+	/// ```
+	pub const fn inner(self) -> u8 {
+		self as u8
+	}
+
+	#[inline]
+	/// Pinyin name of the branch
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EarthlyBranch::Zi.as_str(),  "Zi");
+	/// assert_eq!(EarthlyBranch::Hai.as_str(), "Hai");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Zi   => "Zi",
+			Self::Chou => "Chou",
+			Self::Yin  => "Yin",
+			Self::Mao  => "Mao",
+			Self::Chen => "Chen",
+			Self::Si   => "Si",
+			Self::Wu   => "Wu",
+			Self::Wei  => "Wei",
+			Self::Shen => "Shen",
+			Self::You  => "You",
+			Self::Xu   => "Xu",
+			Self::Hai  => "Hai",
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EarthlyBranch::Zi.as_str_zh(),  "子");
+	/// assert_eq!(EarthlyBranch::Hai.as_str_zh(), "亥");
+	/// ```
+	pub const fn as_str_zh(self) -> &'static str {
+		match self {
+			Self::Zi   => "子",
+			Self::Chou => "丑",
+			Self::Yin  => "寅",
+			Self::Mao  => "卯",
+			Self::Chen => "辰",
+			Self::Si   => "巳",
+			Self::Wu   => "午",
+			Self::Wei  => "未",
+			Self::Shen => "申",
+			Self::You  => "酉",
+			Self::Xu   => "戌",
+			Self::Hai  => "亥",
+		}
+	}
+
+	#[inline]
+	/// Japanese reading of the branch
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EarthlyBranch::Zi.as_str_jp(),   "ね");
+	/// assert_eq!(EarthlyBranch::Chen.as_str_jp(), "たつ");
+	/// ```
+	pub const fn as_str_jp(self) -> &'static str {
+		match self {
+			Self::Zi   => "ね",
+			Self::Chou => "うし",
+			Self::Yin  => "とら",
+			Self::Mao  => "う",
+			Self::Chen => "たつ",
+			Self::Si   => "み",
+			Self::Wu   => "うま",
+			Self::Wei  => "ひつじ",
+			Self::Shen => "さる",
+			Self::You  => "とり",
+			Self::Xu   => "いぬ",
+			Self::Hai  => "い",
+		}
+	}
+
+	#[inline]
+	/// English name of the zodiac animal
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EarthlyBranch::Zi.animal(),   "Rat");
+	/// assert_eq!(EarthlyBranch::Chen.animal(), "Dragon");
+	/// ```
+	pub const fn animal(self) -> &'static str {
+		match self {
+			Self::Zi   => "Rat",
+			Self::Chou => "Ox",
+			Self::Yin  => "Tiger",
+			Self::Mao  => "Rabbit",
+			Self::Chen => "Dragon",
+			Self::Si   => "Snake",
+			Self::Wu   => "Horse",
+			Self::Wei  => "Goat",
+			Self::Shen => "Monkey",
+			Self::You  => "Rooster",
+			Self::Xu   => "Dog",
+			Self::Hai  => "Pig",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Sexagenary
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// A position in the sexagenary cycle (干支), `1..=60`
+///
+/// Used to name both years and days, starting from `甲子`.
+///
+/// ```rust
+/// # use nichi::*;
+/// let year = Sexagenary::from_year(2024);
+/// assert_eq!(year.stem(),      HeavenlyStem::Jia);
+/// assert_eq!(year.branch(),    EarthlyBranch::Chen);
+/// assert_eq!(year.as_str(),    "甲辰");
+/// assert_eq!(year.as_str_jp(), "きのえたつ");
+/// ```
+pub struct Sexagenary(u8);
+
+impl Sexagenary {
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Sexagenary::new(1).as_str(),  "甲子");
+	/// assert_eq!(Sexagenary::new(60).as_str(), "癸亥");
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// Sexagenary::new(61);
+	/// ```
+	pub const fn new(index: u8) -> Self {
+		assert!(index != 0, "index must not be 0");
+		assert!(index < 61, "index must not be > 60");
+		Self(index)
+	}
+
+	#[inline]
+	/// The sexagenary name of a year
+	///
+	/// The name changes at the lunar new year in China, and on January 1st in Japan.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Sexagenary::from_year(1984).as_str(), "甲子");
+	/// assert_eq!(Sexagenary::from_year(2023).as_str(), "癸卯");
+	/// assert_eq!(Sexagenary::from_year(-2696).as_str(), "甲子");
+	/// ```
+	pub const fn from_year(year: i32) -> Self {
+		Self(((year as i64 - 4).rem_euclid(60) + 1) as u8)
+	}
+
+	#[inline]
+	/// The sexagenary name of a day
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Sexagenary::from_date(Date::new(1949, 10, 1)).as_str(), "甲子");
+	/// assert_eq!(Sexagenary::from_date(Date::new(2024, 1, 1)).as_str(),  "甲子");
+	/// assert_eq!(Sexagenary::from_date(Date::new(2024, 1, 2)).as_str(),  "乙丑");
+	/// ```
+	pub const fn from_date(date: Date) -> Self {
+		Self(((date.to_rata_die() - SEXAGENARY_DAY_EPOCH).rem_euclid(60) + 1) as u8)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Sexagenary::new(60).inner(), 60);
+	/// ```
+	pub const fn inner(self) -> u8 {
+		self.0
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Sexagenary::new(11).stem(), HeavenlyStem::Jia);
+	/// ```
+	pub const fn stem(self) -> HeavenlyStem {
+		HeavenlyStem::ALL[(self.0 as usize - 1) % 10]
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Sexagenary::new(11).branch(), EarthlyBranch::Xu);
+	/// ```
+	pub const fn branch(self) -> EarthlyBranch {
+		EarthlyBranch::ALL[(self.0 as usize - 1) % 12]
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Sexagenary::new(11).as_str(), "甲戌");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		const STR: [&str; 60] = [
+			"甲子", "乙丑", "丙寅", "丁卯", "戊辰", "己巳", "庚午", "辛未", "壬申", "癸酉",
+			"甲戌", "乙亥", "丙子", "丁丑", "戊寅", "己卯", "庚辰", "辛巳", "壬午", "癸未",
+			"甲申", "乙酉", "丙戌", "丁亥", "戊子", "己丑", "庚寅", "辛卯", "壬辰", "癸巳",
+			"甲午", "乙未", "丙申", "丁酉", "戊戌", "己亥", "庚子", "辛丑", "壬寅", "癸卯",
+			"甲辰", "乙巳", "丙午", "丁未", "戊申", "己酉", "庚戌", "辛亥", "壬子", "癸丑",
+			"甲寅", "乙卯", "丙辰", "丁巳", "戊午", "己未", "庚申", "辛酉", "壬戌", "癸亥",
+		];
+		STR[self.0 as usize - 1]
+	}
+
+	#[inline]
+	/// Japanese reading
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Sexagenary::new(11).as_str_jp(), "きのえいぬ");
+	/// ```
+	pub const fn as_str_jp(self) -> &'static str {
+		const STR: [&str; 60] = [
+			"きのえね", "きのとうし", "ひのえとら", "ひのとう", "つちのえたつ", "つちのとみ",
+			"かのえうま", "かのとひつじ", "みずのえさる", "みずのととり", "きのえいぬ", "きのとい",
+			"ひのえね", "ひのとうし", "つちのえとら", "つちのとう", "かのえたつ", "かのとみ",
+			"みずのえうま", "みずのとひつじ", "きのえさる", "きのととり", "ひのえいぬ", "ひのとい",
+			"つちのえね", "つちのとうし", "かのえとら", "かのとう", "みずのえたつ", "みずのとみ",
+			"きのえうま", "きのとひつじ", "ひのえさる", "ひのととり", "つちのえいぬ", "つちのとい",
+			"かのえね", "かのとうし", "みずのえとら", "みずのとう", "きのえたつ", "きのとみ",
+			"ひのえうま", "ひのとひつじ", "つちのえさる", "つちのととり", "かのえいぬ", "かのとい",
+			"みずのえね", "みずのとうし", "きのえとら", "きのとう", "ひのえたつ", "ひのとみ",
+			"つちのえうま", "つちのとひつじ", "かのえさる", "かのととり", "みずのえいぬ", "みずのとい",
+		];
+		STR[self.0 as usize - 1]
+	}
+}
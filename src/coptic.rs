@@ -0,0 +1,408 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+use crate::macros::impl_u8_enum;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Rata Die of `1 Thout 1 AM`, `August 29th, 284` (Julian)
+const COPTIC_EPOCH: i128 = 103605;
+
+//---------------------------------------------------------------------------------------------------- Free
+// The Coptic and Ethiopian calendars share the same
+// arithmetic and only differ in their epoch.
+
+#[inline]
+/// If `year` has a 6th epagomenal day
+pub(crate) const fn alexandrian_is_leap(year: i32) -> bool {
+	(year as i64).rem_euclid(4) == 3
+}
+
+#[inline]
+/// Days in the `month`th month of `year`
+pub(crate) const fn alexandrian_days_in_month(year: i32, month: u8) -> u8 {
+	if month < 13 {
+		30
+	} else if alexandrian_is_leap(year) {
+		6
+	} else {
+		5
+	}
+}
+
+#[inline]
+/// Convert a date into a Rata Die
+pub(crate) const fn alexandrian_to_rata_die(epoch: i128, year: i32, month: u8, day: u8) -> i128 {
+	let year = year as i128;
+	epoch - 1 + 365 * (year - 1) + year.div_euclid(4) + 30 * (month as i128 - 1) + day as i128
+}
+
+#[inline]
+/// Convert a Rata Die into `(year, month, day)`
+pub(crate) const fn alexandrian_from_rata_die(epoch: i128, rata_die: i128) -> (i32, u8, u8) {
+	let year  = (4 * (rata_die - epoch) + 1463).div_euclid(1461) as i32;
+	let month = (rata_die - alexandrian_to_rata_die(epoch, year, 1, 1)).div_euclid(30) as u8 + 1;
+	let day   = (rata_die + 1 - alexandrian_to_rata_die(epoch, year, month, 1)) as u8;
+	(year, month, day)
+}
+
+//---------------------------------------------------------------------------------------------------- CopticMonth
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Month in a Coptic year
+///
+/// [`CopticMonth::Nasie`] is the 5 (or 6 in leap years) epagomenal days at the end of the year.
+#[allow(missing_docs)]
+pub enum CopticMonth {
+	#[default]
+	Thout    = 1,
+	Paopi    = 2,
+	Hathor   = 3,
+	Koiak    = 4,
+	Tobi     = 5,
+	Meshir   = 6,
+	Paremhat = 7,
+	Parmouti = 8,
+	Pashons  = 9,
+	Paoni    = 10,
+	Epip     = 11,
+	Mesori   = 12,
+	Nasie    = 13,
+}
+
+impl CopticMonth {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticMonth::FIRST, CopticMonth::Thout);
+	/// ```
+	pub const FIRST: Self = Self::Thout;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticMonth::LAST, CopticMonth::Nasie);
+	/// ```
+	pub const LAST: Self = Self::Nasie;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticMonth::ALL[0],  CopticMonth::Thout);
+	/// assert_eq!(CopticMonth::ALL[12], CopticMonth::Nasie);
+	/// ```
+	pub const ALL: [Self; 13] = [
+		Self::Thout,
+		Self::Paopi,
+		Self::Hathor,
+		Self::Koiak,
+		Self::Tobi,
+		Self::Meshir,
+		Self::Paremhat,
+		Self::Parmouti,
+		Self::Pashons,
+		Self::Paoni,
+		Self::Epip,
+		Self::Mesori,
+		Self::Nasie,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticMonth::new(1),  CopticMonth::Thout);
+	/// assert_eq!(CopticMonth::new(13), CopticMonth::Nasie);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// CopticMonth::new(14);
+	/// ```
+	pub const fn new(month: u8) -> Self {
+		assert!(month != 0, "month must not be 0");
+		assert!(month < 14, "month must not be > 13");
+		// SAFETY: repr(u8)
+		unsafe { Self::new_unchecked(month) }
+	}
+
+	#[inline]
+	/// ## Safety
+	/// `month` must be `1..=13`.
+	pub const unsafe fn new_unchecked(month: u8) -> Self {
+		debug_assert!(month != 0, "month must not be 0");
+		debug_assert!(month < 14, "month must not be > 13");
+		// SAFETY: repr(u8)
+		std::mem::transmute(month)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticMonth::new_saturating(0),  CopticMonth::Thout);
+	/// assert_eq!(CopticMonth::new_saturating(14), CopticMonth::Nasie);
+	/// ```
+	pub const fn new_saturating(month: u8) -> Self {
+		if month == 0 {
+			Self::FIRST
+		} else if month < 14 {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(month) }
+		} else {
+			Self::LAST
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticMonth::new_wrapping(0),  CopticMonth::Nasie);
+	/// assert_eq!(CopticMonth::new_wrapping(14), CopticMonth::Thout);
+	/// ```
+	pub const fn new_wrapping(month: u8) -> Self {
+		let month = month % 13;
+		if month == 0 {
+			Self::LAST
+		} else {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(month) }
+		}
+	}
+
+	impl_u8_enum!();
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticMonth::Thout.inner(), 1);
+	/// assert_eq!(CopticMonth::Nasie.inner(), 13);
+	/// ```
+	pub const fn inner(self) -> u8 {
+		// SAFETY: repr(u8)
+		unsafe { std::mem::transmute(self) }
+	}
+
+	#[inline]
+	/// Transliterated name of the month
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticMonth::Thout.as_str(),    "Thout");
+	/// assert_eq!(CopticMonth::Paopi.as_str(),    "Paopi");
+	/// assert_eq!(CopticMonth::Hathor.as_str(),   "Hathor");
+	/// assert_eq!(CopticMonth::Koiak.as_str(),    "Koiak");
+	/// assert_eq!(CopticMonth::Tobi.as_str(),     "Tobi");
+	/// assert_eq!(CopticMonth::Meshir.as_str(),   "Meshir");
+	/// assert_eq!(CopticMonth::Paremhat.as_str(), "Paremhat");
+	/// assert_eq!(CopticMonth::Parmouti.as_str(), "Parmouti");
+	/// assert_eq!(CopticMonth::Pashons.as_str(),  "Pashons");
+	/// assert_eq!(CopticMonth::Paoni.as_str(),    "Paoni");
+	/// assert_eq!(CopticMonth::Epip.as_str(),     "Epip");
+	/// assert_eq!(CopticMonth::Mesori.as_str(),   "Mesori");
+	/// assert_eq!(CopticMonth::Nasie.as_str(),    "Pi Kogi Enavot");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Thout    => "Thout",
+			Self::Paopi    => "Paopi",
+			Self::Hathor   => "Hathor",
+			Self::Koiak    => "Koiak",
+			Self::Tobi     => "Tobi",
+			Self::Meshir   => "Meshir",
+			Self::Paremhat => "Paremhat",
+			Self::Parmouti => "Parmouti",
+			Self::Pashons  => "Pashons",
+			Self::Paoni    => "Paoni",
+			Self::Epip     => "Epip",
+			Self::Mesori   => "Mesori",
+			Self::Nasie    => "Pi Kogi Enavot",
+		}
+	}
+
+	#[inline]
+	/// Arabic name of the month, as used in Egypt
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticMonth::Thout.as_str_ar(),    "توت");
+	/// assert_eq!(CopticMonth::Paopi.as_str_ar(),    "بابه");
+	/// assert_eq!(CopticMonth::Hathor.as_str_ar(),   "هاتور");
+	/// assert_eq!(CopticMonth::Koiak.as_str_ar(),    "كيهك");
+	/// assert_eq!(CopticMonth::Tobi.as_str_ar(),     "طوبة");
+	/// assert_eq!(CopticMonth::Meshir.as_str_ar(),   "أمشير");
+	/// assert_eq!(CopticMonth::Paremhat.as_str_ar(), "برمهات");
+	/// assert_eq!(CopticMonth::Parmouti.as_str_ar(), "برمودة");
+	/// assert_eq!(CopticMonth::Pashons.as_str_ar(),  "بشنس");
+	/// assert_eq!(CopticMonth::Paoni.as_str_ar(),    "بؤونة");
+	/// assert_eq!(CopticMonth::Epip.as_str_ar(),     "أبيب");
+	/// assert_eq!(CopticMonth::Mesori.as_str_ar(),   "مسرى");
+	/// assert_eq!(CopticMonth::Nasie.as_str_ar(),    "نسيء");
+	/// ```
+	pub const fn as_str_ar(self) -> &'static str {
+		match self {
+			Self::Thout    => "توت",
+			Self::Paopi    => "بابه",
+			Self::Hathor   => "هاتور",
+			Self::Koiak    => "كيهك",
+			Self::Tobi     => "طوبة",
+			Self::Meshir   => "أمشير",
+			Self::Paremhat => "برمهات",
+			Self::Parmouti => "برمودة",
+			Self::Pashons  => "بشنس",
+			Self::Paoni    => "بؤونة",
+			Self::Epip     => "أبيب",
+			Self::Mesori   => "مسرى",
+			Self::Nasie    => "نسيء",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- CopticDate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Coptic calendar date, Anno Martyrum
+///
+/// 12 months of 30 days followed by [`CopticMonth::Nasie`],
+/// which has 6 days when `year % 4 == 3`, else 5.
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = CopticDate::from_date(Date::new(2023, 10, 20));
+/// assert_eq!(date, CopticDate::new(1740, 2, 9));
+/// assert_eq!(date.month().as_str(), "Paopi");
+/// assert_eq!(date.to_date(), Date::new(2023, 10, 20));
+/// ```
+pub struct CopticDate {
+	year: i32,
+	month: CopticMonth,
+	day: u8,
+}
+
+impl CopticDate {
+	#[inline]
+	/// Create a new [`CopticDate`] from numbers
+	///
+	/// ## Panics
+	/// This function panics if:
+	/// - `month` is not `1..=13`
+	/// - `day` is not `1..=30`
+	/// - `day` does not exist in [`CopticMonth::Nasie`] of `year`
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// // Not a leap year.
+	/// CopticDate::new(1740, 13, 6);
+	/// ```
+	pub const fn new(year: i32, month: u8, day: u8) -> Self {
+		let month = CopticMonth::new(month);
+		assert!(day != 0, "day was 0");
+		assert!(day <= Self::days_in_month(year, month), "day does not exist in month");
+		Self { year, month, day }
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticDate::new(1740, 2, 9).year(), 1740);
+	/// ```
+	pub const fn year(self) -> i32 {
+		self.year
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticDate::new(1740, 2, 9).month(), CopticMonth::Paopi);
+	/// ```
+	pub const fn month(self) -> CopticMonth {
+		self.month
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticDate::new(1740, 2, 9).day(), 9);
+	/// ```
+	pub const fn day(self) -> u8 {
+		self.day
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticDate::new(1740, 2, 9).inner(), (1740, 2, 9));
+	/// ```
+	pub const fn inner(self) -> (i32, u8, u8) {
+		(self.year, self.month.inner(), self.day)
+	}
+
+	#[inline]
+	/// If [`CopticMonth::Nasie`] has 6 days in `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert!(CopticDate::is_leap(1739));
+	/// assert!(!CopticDate::is_leap(1740));
+	/// ```
+	pub const fn is_leap(year: i32) -> bool {
+		alexandrian_is_leap(year)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticDate::days_in_month(1740, CopticMonth::Thout), 30);
+	/// assert_eq!(CopticDate::days_in_month(1739, CopticMonth::Nasie), 6);
+	/// assert_eq!(CopticDate::days_in_month(1740, CopticMonth::Nasie), 5);
+	/// ```
+	pub const fn days_in_month(year: i32, month: CopticMonth) -> u8 {
+		alexandrian_days_in_month(year, month.inner())
+	}
+
+	#[inline]
+	/// Convert into a Rata Die
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticDate::new(1, 1, 1).to_rata_die(),      103605);
+	/// assert_eq!(CopticDate::new(1662, 3, 3).to_rata_die(),   710347);
+	/// ```
+	pub const fn to_rata_die(self) -> i128 {
+		alexandrian_to_rata_die(COPTIC_EPOCH, self.year, self.month.inner(), self.day)
+	}
+
+	#[inline]
+	/// Convert a Rata Die into a [`CopticDate`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticDate::from_rata_die(710347), CopticDate::new(1662, 3, 3));
+	/// ```
+	pub const fn from_rata_die(rata_die: i128) -> Self {
+		let (year, month, day) = alexandrian_from_rata_die(COPTIC_EPOCH, rata_die);
+		// SAFETY: `month` is `1..=13`
+		Self { year, month: unsafe { CopticMonth::new_unchecked(month) }, day }
+	}
+
+	#[inline]
+	/// Convert a [`Date`] into a [`CopticDate`]
+	pub const fn from_date(date: Date) -> Self {
+		Self::from_rata_die(date.to_rata_die())
+	}
+
+	#[inline]
+	/// Convert into a [`Date`]
+	pub const fn to_date(self) -> Date {
+		Date::from_rata_die(self.to_rata_die())
+	}
+
+	#[inline]
+	/// The [`Date`] of Nayrouz (`1 Thout`) in the Gregorian `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(CopticDate::new_year(Year(2023)), Date::new(2023, 9, 12));
+	/// assert_eq!(CopticDate::new_year(Year(2024)), Date::new(2024, 9, 11));
+	/// ```
+	pub const fn new_year(year: Year) -> Date {
+		Date::from_rata_die(alexandrian_to_rata_die(COPTIC_EPOCH, year.inner() as i32 - 283, 1, 1))
+	}
+}
@@ -3,9 +3,22 @@ use crate::weekday::Weekday;
 use crate::year::Year;
 use crate::month::Month;
 use crate::day::Day;
+use crate::free::{days_from_civil,civil_from_days};
+use crate::moon::Moon;
+use crate::format::DateFormat;
+#[cfg(feature = "regex")]
 use once_cell::sync::Lazy;
+#[cfg(feature = "regex")]
 use regex::Regex;
 
+//---------------------------------------------------------------------------------------------------- Constants
+/// Julian Day Number of the `UNIX_EPOCH`
+const JULIAN_DAY_UNIX_EPOCH: i128 = 2440588;
+/// Modified Julian Day of the `UNIX_EPOCH`
+const MJD_UNIX_EPOCH: i128 = 40587;
+/// Rata Die of the `UNIX_EPOCH`
+const RATA_DIE_UNIX_EPOCH: i128 = 719163;
+
 //---------------------------------------------------------------------------------------------------- Date
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
@@ -114,6 +127,12 @@ impl Date {
 	///
 	/// // A good album was released on a Wednesday.
 	/// assert_eq!(Date::new(2018, 4, 25).weekday(), Weekday::Wednesday);
+	///
+	/// // Years before 1 and at the ends of `i16` work too, in the proleptic Gregorian calendar.
+	/// assert_eq!(Date::new(-43, 3, 15).weekday(), Weekday::Friday);
+	/// assert_eq!(Date::new(0, 1, 1).weekday(), Weekday::Saturday);
+	/// assert_eq!(Date::new(i16::MAX, 12, 31).weekday(), Weekday::Sunday);
+	/// assert_eq!(Date::new(i16::MIN, 1, 1).weekday(), Weekday::Thursday);
 	/// ```
 	///
 	/// ## Algorithm
@@ -137,17 +156,20 @@ impl Date {
 
 		let month: usize = month as usize - 1;
 
+		// `i32` and flooring division, so years near `i16::MAX` and negative years work.
 		let year = if month < 2 {
-			year.saturating_sub(1)
+			year as i32 - 1
 		} else {
-			year
+			year as i32
 		};
 
-		const LUT: [i16; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+		const LUT: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
 		// SAFETY: `month` is now 0..=11, will never panic.
-		let lut: i16 = LUT[month as usize];
+		let lut: i32 = LUT[month as usize];
 
-		let weekday: i16 = (year + year/4 - year/100 + year/400 + lut + day as i16) % 7;
+		let weekday: i32 = (
+			year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400) + lut + day as i32
+		).rem_euclid(7);
 		assert!(weekday >= 0);
 		assert!(weekday < 7);
 
@@ -155,6 +177,50 @@ impl Date {
 		Weekday::ALL[weekday as usize]
 	}
 
+	#[inline]
+	/// The ISO 8601 weekday, Monday is `1` and Sunday is `7`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Date::new(2023, 10, 16).weekday_iso(), 1);
+	/// assert_eq!(Date::new(2023, 10, 22).weekday_iso(), 7);
+	/// ```
+	pub const fn weekday_iso(self) -> u8 {
+		(self.weekday().inner() + 5) % 7 + 1
+	}
+
+	#[inline]
+	/// The day of the year, `1..=366`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Date::new(2023, 1, 1).day_of_year(),   1);
+	/// assert_eq!(Date::new(2023, 10, 20).day_of_year(), 293);
+	/// assert_eq!(Date::new(2024, 12, 31).day_of_year(), 366);
+	/// ```
+	pub const fn day_of_year(self) -> u16 {
+		(self.as_days() - days_from_civil(self.year.inner() as i128, Month::January, Day::First)) as u16 + 1
+	}
+
+	#[inline]
+	/// The ISO 8601 week-based year and week, `1..=53`
+	///
+	/// Weeks start on Monday and the first week of
+	/// the year is the one with the year's first Thursday.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Date::new(2023, 10, 20).iso_week(), (Year(2023), 42));
+	///
+	/// // These belong to the previous or next year.
+	/// assert_eq!(Date::new(2021, 1, 3).iso_week(),   (Year(2020), 53));
+	/// assert_eq!(Date::new(2024, 12, 30).iso_week(), (Year(2025), 1));
+	/// ```
+	pub const fn iso_week(self) -> (Year, u8) {
+		let thursday = Self::from_days(self.as_days() - self.weekday_iso() as i128 + 4);
+		(thursday.year, ((thursday.day_of_year() - 1) / 7 + 1) as u8)
+	}
+
 	/// ```rust
 	/// # use nichi::*;
 	/// let date = Date::new(2000, 12, 25);
@@ -205,8 +271,11 @@ impl Date {
 	#[inline]
 	/// Create [`Date`] from a string
 	///
+	/// This scans the bytes of the input directly, it does not allocate.
+	///
 	/// ## Invariants
-	/// - The year must be `1000..=9999`
+	/// - The year must be `1000..=9999`, 2 digit years are not expanded, see [`DateParser::pivot`](crate::DateParser::pivot)
+	///   - For years before `1000` or BCE, use [`Date::parse_with_format`] with `%Y`, or `%L` and `%E`, see [`HistoricalYear`](crate::HistoricalYear)
 	/// - The month must be at least the first 3 letters of the month in english (`oct`, `Dec`, `SEP`, etc)
 	/// - The day must be a number, either optionally with a leading `0` or suffixed by `th`, `rd`, `nd`, `st` (but not both, e.g, `3rd` is OK, `03` is OK, `03rd` is INVALID)
 	///
@@ -288,6 +357,58 @@ impl Date {
 	/// assert_eq!(Date::from_str("April 3rd, 1000").unwrap(), april_3rd_1000);
 	/// ```
 	pub fn from_str(s: &str) -> Option<Self> {
+		crate::scan::iso(s).or_else(|| crate::scan::heuristic(s))
+	}
+
+	#[cfg(feature = "regex")]
+	/// The original [`Date::from_str`], using [`regex`]
+	///
+	/// [`Date::from_str`] is now a hand-written scanner that does not allocate
+	/// or compile anything, this is kept to check it against.
+	///
+	/// It accepts the same inputs as [`Date::from_str`] and returns the same [`Date`],
+	/// except that it panics when there is a non-ASCII character within an ISO 8601-like date.
+	/// Both treat any Unicode decimal digit as `\d` and Unicode word characters as `\w`,
+	/// e.g, `٢٠١٠` is 4 digits and `‿` is not a boundary.
+	///
+	/// This requires the `regex` feature.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// // Random input made from pieces of dates.
+	/// const PIECES: &[&str] = &[
+	/// 	"0", "1", "2", "3", "9", "10", "12", "25", "31", "2010", "1000", "0999", "19999",
+	/// 	"-", "/", ".", "_", " ", ", ", "\n", "a", "_a", "th", "TH", "rd", "st", "nd",
+	/// 	"dec", "Dec", "DEC", "dEc", "december", "apr", "APRIL", "may", "Sep",
+	/// 	"‿", "\u{301}", "٢٠١٠", "٣", "５", "日", "²", "é",
+	/// ];
+	///
+	/// let mut state: u64 = 0x2545F4914F6CDD1D;
+	/// let mut next = || {
+	/// 	state ^= state << 13;
+	/// 	state ^= state >> 7;
+	/// 	state ^= state << 17;
+	/// 	state
+	/// };
+	///
+	/// for _ in 0..20_000 {
+	/// 	let mut input = String::new();
+	/// 	for _ in 0..(next() % 8) {
+	/// 		input.push_str(PIECES[(next() % PIECES.len() as u64) as usize]);
+	/// 	}
+	/// 	// The original panics on some non-ASCII input.
+	/// 	let Ok(regex) = std::panic::catch_unwind(|| Date::from_str_regex(&input)) else {
+	/// 		assert!(!input.is_ascii());
+	/// 		continue;
+	/// 	};
+	/// 	assert_eq!(Date::from_str(&input), regex, "{input:?}");
+	/// }
+	///
+	/// assert_eq!(Date::from_str("3‿2010december日"), None);
+	/// assert_eq!(Date::from_str("_aDEC٢٠١٠decrd2010TH"), None);
+	/// assert_eq!(Date::from_str("3 2010december日"), Some(Date::new(2010, 12, 3)));
+	/// ```
+	pub fn from_str_regex(s: &str) -> Option<Self> {
 		// Debug.
 		// println!("{s}");
 
@@ -422,41 +543,7 @@ r"Jan|jan|JAN|Feb|feb|FEB|Mar|mar|MAR|Apr|apr|APR|Jun|jun|JUN|Jul|jul|JUL|Aug|au
 			seconds_relative_to_unix_epoch
 		};
 
-		let z:   i64 = ((s / 86400) + 719468) as i64;
-		let era: i64 = if z >= 0 { z } else { z - 146096 } / 146097;
-		let doe: u64 = (z - era * 146097) as u64;
-		let yoe: u64 = (doe - doe/1460 + doe/36524 - doe/146096) / 365;
-		let y:   i64 = (yoe as i64) + era * 400;
-		let doy: u64 = doe - (365*yoe + yoe/4 - yoe/100);
-		let mp:  u64 = (5*doy + 2)/153;
-		let d:   u8  = (doy - (153*mp+2)/5 + 1) as u8;
-		let m:   u8  = (if mp < 10 { mp + 3 } else { mp - 9 }) as u8;
-
-		debug_assert!(m != 0);
-		debug_assert!(m < 13);
-		debug_assert!(d != 0);
-		debug_assert!(d < 32);
-
-		let y = if m <= 2 {
-			y + 1
-		} else {
-			y
-		};
-
-		let y = if y > i16::MAX as i64 {
-			Year::MAX
-		} else if y < i16::MIN as i64 {
-			Year::MIN
-		} else {
-			Year(y as i16)
-		};
-
-		// SAFETY: algorithm above is assumed to not produce invalid values
-		unsafe { Self {
-			year: y,
-			month: Month::new_unchecked(m),
-			day: Day::new_unchecked(d)
-		}}
+		Self::from_days(s / 86400)
 	}
 
 	#[inline]
@@ -466,7 +553,7 @@ r"Jan|jan|JAN|Feb|feb|FEB|Mar|mar|MAR|Apr|apr|APR|Jun|jun|JUN|Jul|jul|JUL|Aug|au
 	///
 	/// It is the reverse of [`Date::from_unix`].
 	///
-	/// A negative `year` represents BCE years, e.g, `-1` is `1 BCE`.
+	/// Years use astronomical numbering, `0` is `1 BCE` and `-1` is `2 BCE`, see [`HistoricalYear`](crate::HistoricalYear).
 	///
 	/// Values before the `UNIX_EPOCH` (before `January 1st, 1970`) will return negative values.
 	///
@@ -481,25 +568,207 @@ r"Jan|jan|JAN|Feb|feb|FEB|Mar|mar|MAR|Apr|apr|APR|Jun|jun|JUN|Jul|jul|JUL|Aug|au
 	/// ## Algorithm
 	/// <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
 	pub const fn as_unix(self) -> i128 {
-		let (year, month, day) = self.inner();
+		self.as_days() * 86400
+	}
+
+	#[inline]
+	/// Convert a [`Date`] to a Julian Day Number
+	///
+	/// This is the integer count of days since
+	/// `November 24th, 4714 BCE` in the proleptic Gregorian calendar,
+	/// i.e, the Julian Day that begins at noon UTC of this [`Date`].
+	///
+	/// It is the reverse of [`Date::from_julian_day`].
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Date::new(2000, 1, 1).to_julian_day(),  2451545);
+	/// assert_eq!(Date::new(1970, 1, 1).to_julian_day(),  2440588);
+	/// assert_eq!(Date::new(-4713, 11, 24).to_julian_day(), 0);
+	/// ```
+	pub const fn to_julian_day(self) -> i128 {
+		self.as_days() + JULIAN_DAY_UNIX_EPOCH
+	}
+
+	#[inline]
+	/// Convert a Julian Day Number into a [`Date`]
+	///
+	/// It is the reverse of [`Date::to_julian_day`].
+	///
+	/// Days before [`Year::MIN`] or after [`Year::MAX`] saturate to their first or last day.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Date::from_julian_day(2451545), Date::new(2000, 1, 1));
+	/// assert_eq!(Date::from_julian_day(2460238), Date::new(2023, 10, 20));
+	/// assert_eq!(Date::from_julian_day(0),       Date::new(-4713, 11, 24));
+	///
+	/// assert_eq!(Date::from_julian_day(i128::MIN), Date::new(i16::MIN, 1, 1));
+	/// assert_eq!(Date::from_julian_day(i128::MAX), Date::new(i16::MAX, 12, 31));
+	/// ```
+	pub const fn from_julian_day(julian_day: i128) -> Self {
+		Self::from_days(julian_day.saturating_sub(JULIAN_DAY_UNIX_EPOCH))
+	}
+
+	#[inline]
+	/// Convert a [`Date`] to a Modified Julian Day
+	///
+	/// This is the integer count of days since `November 17th, 1858`.
+	///
+	/// It is the reverse of [`Date::from_mjd`].
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Date::new(1858, 11, 17).to_mjd(), 0);
+	/// assert_eq!(Date::new(1970, 1, 1).to_mjd(),    40587);
+	/// assert_eq!(Date::new(2000, 1, 1).to_mjd(),    51544);
+	/// ```
+	pub const fn to_mjd(self) -> i128 {
+		self.as_days() + MJD_UNIX_EPOCH
+	}
 
-		let year  = year as i64;
-		let month = month as u64;
-		let day   = day as u64;
+	#[inline]
+	/// Convert a Modified Julian Day into a [`Date`]
+	///
+	/// It is the reverse of [`Date::to_mjd`].
+	///
+	/// Days before [`Year::MIN`] or after [`Year::MAX`] saturate to their first or last day.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Date::from_mjd(0),     Date::new(1858, 11, 17));
+	/// assert_eq!(Date::from_mjd(-1),    Date::new(1858, 11, 16));
+	/// assert_eq!(Date::from_mjd(60237), Date::new(2023, 10, 20));
+	///
+	/// assert_eq!(Date::from_mjd(i128::MIN), Date::new(i16::MIN, 1, 1));
+	/// assert_eq!(Date::from_mjd(i128::MAX), Date::new(i16::MAX, 12, 31));
+	/// ```
+	pub const fn from_mjd(mjd: i128) -> Self {
+		Self::from_days(mjd.saturating_sub(MJD_UNIX_EPOCH))
+	}
 
-		let year = if month <= 2 {
-			year - 1
+	#[inline]
+	/// Convert a [`Date`] to a Rata Die
+	///
+	/// This is the integer count of days where
+	/// `January 1st, 1` (proleptic Gregorian) is day `1`.
+	///
+	/// It is the reverse of [`Date::from_rata_die`].
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Date::new(1, 1, 1).to_rata_die(),     1);
+	/// assert_eq!(Date::new(0, 12, 31).to_rata_die(),   0);
+	/// assert_eq!(Date::new(1970, 1, 1).to_rata_die(),  719163);
+	/// assert_eq!(Date::new(2023, 10, 20).to_rata_die(), 738813);
+	/// ```
+	pub const fn to_rata_die(self) -> i128 {
+		self.as_days() + RATA_DIE_UNIX_EPOCH
+	}
+
+	#[inline]
+	/// Convert a Rata Die into a [`Date`]
+	///
+	/// It is the reverse of [`Date::to_rata_die`].
+	///
+	/// Days before [`Year::MIN`] or after [`Year::MAX`] saturate to their first or last day.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Date::from_rata_die(1),      Date::new(1, 1, 1));
+	/// assert_eq!(Date::from_rata_die(719163), Date::new(1970, 1, 1));
+	///
+	/// let date = Date::new(2023, 10, 20);
+	/// assert_eq!(Date::from_rata_die(date.to_rata_die()), date);
+	///
+	/// assert_eq!(Date::from_rata_die(i128::MIN), Date::new(i16::MIN, 1, 1));
+	/// assert_eq!(Date::from_rata_die(i128::MAX), Date::new(i16::MAX, 12, 31));
+	/// assert_eq!(Date::from_rata_die(Date::new(i16::MAX, 12, 31).to_rata_die() + 1), Date::new(i16::MAX, 12, 31));
+	/// ```
+	pub const fn from_rata_die(rata_die: i128) -> Self {
+		Self::from_days(rata_die.saturating_sub(RATA_DIE_UNIX_EPOCH))
+	}
+
+	#[inline]
+	/// Format with a `strftime`-style `pattern`
+	///
+	/// The returned [`DateFormat`] implements [`std::fmt::Display`] and
+	/// does not allocate, see it for the supported specifiers.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let date = Date::new(2023, 10, 20);
+	/// assert_eq!(date.format("%Y/%m/%d").to_string(),      "2023/10/20");
+	/// assert_eq!(date.format("%a, %-d %b %Y").to_string(), "Fri, 20 Oct 2023");
+	/// println!("{}", date.format("%A %B %o"));
+	/// ```
+	pub const fn format(self, pattern: &str) -> DateFormat<'_> {
+		DateFormat::new(self, pattern)
+	}
+
+	#[inline]
+	/// The [`Moon`] on this day in UTC
+	///
+	/// See [`MoonPhase`](crate::MoonPhase) for accuracy.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// // 2024-01-25 17:54 UTC
+	/// let moon = Date::new(2024, 1, 25).moon_phase();
+	/// assert_eq!(moon.phase(), MoonPhase::FullMoon);
+	/// assert!(moon.illumination() > 0.99);
+	///
+	/// let moon = Date::new(2024, 1, 28).moon_phase();
+	/// assert_eq!(moon.phase(), MoonPhase::WaningGibbous);
+	/// assert_eq!(moon.phase().as_str_jp(), "居待月");
+	///
+	/// let moon = Date::new(2024, 1, 11).moon_phase();
+	/// assert_eq!(moon.phase(), MoonPhase::NewMoon);
+	/// assert!(moon.illumination() < 0.01);
+	/// ```
+	pub fn moon_phase(self) -> Moon {
+		Moon::on(self, 0)
+	}
+
+	#[inline]
+	/// The [`Moon`] on this day, `utc_offset` seconds away from UTC
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// // 2024-01-25 17:54 UTC is 2024-01-26 02:54 in Japan.
+	/// let date = Date::new(2024, 1, 26);
+	/// assert_eq!(date.moon_phase_with_offset(0).phase(),        MoonPhase::WaningGibbous);
+	/// assert_eq!(date.moon_phase_with_offset(9 * 3600).phase(), MoonPhase::FullMoon);
+	/// ```
+	pub fn moon_phase_with_offset(self, utc_offset: i32) -> Moon {
+		Moon::on(self, utc_offset)
+	}
+
+	#[inline]
+	/// Days relative to the `UNIX_EPOCH`.
+	const fn as_days(self) -> i128 {
+		days_from_civil(self.year.inner() as i128, self.month, self.day)
+	}
+
+	#[inline]
+	/// Days relative to the `UNIX_EPOCH`, saturating at the first day of
+	/// [`Year::MIN`] and the last day of [`Year::MAX`].
+	const fn from_days(days: i128) -> Self {
+		let min = days_from_civil(i16::MIN as i128, Month::January, Day::First);
+		let max = days_from_civil(i16::MAX as i128, Month::December, Day::ThirtyFirst);
+
+		// Clamp first, so the year fits in `Year`.
+		let days = if days < min {
+			min
+		} else if days > max {
+			max
 		} else {
-			year
+			days
 		};
 
-		let era: i64 = if year >= 0 { year } else { year - 399 } / 400;
-		let yoe: u64 = (year - era * 400) as u64;
-		let doy: u64 = (153 * if month > 2 { month - 3 } else { month + 9 } + 2) / 5 + day - 1;
-		let doe: u64 = yoe * 365 + yoe/4 - yoe/100 + doy;
-
-		((era as i128) * 146097 + (doe as i128) - 719468) * 86400
+		let (y, month, day) = civil_from_days(days);
+		Self { year: Year(y as i16), month, day }
 	}
 }
 
-//---------------------------------------------------------------------------------------------------- Impl
\ No newline at end of file
+//---------------------------------------------------------------------------------------------------- Impl
@@ -0,0 +1,567 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::month::Month;
+use crate::weekday::Weekday;
+use crate::free::days_in_month;
+use crate::pivot::{YearPivot,YearExpansion};
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Weight of an interpretation in the preferred [`DateOrder`]
+const WEIGHT_PREFERRED: f64 = 1.0;
+/// Weight of an interpretation in any other [`DateOrder`]
+const WEIGHT_OTHER: f64 = 0.5;
+/// Weight multiplier when a weekday in the input does not match
+const WEIGHT_WEEKDAY_MISMATCH: f64 = 0.1;
+/// Confidence multiplier for a number that looks like a label, e.g, `Room 12`
+const CONFIDENCE_LABEL: f64 = 0.2;
+/// Confidence multiplier for a 2 digit year
+const CONFIDENCE_TWO_DIGIT_YEAR: f64 = 0.9;
+/// Confidence multiplier for 3 numbers within a longer run of numbers
+const CONFIDENCE_LONG_RUN: f64 = 0.5;
+
+//---------------------------------------------------------------------------------------------------- DateOrder
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Order of the year, month and day in a date
+pub enum DateOrder {
+	#[default]
+	/// `2023/11/10`, ISO 8601, East Asia
+	YMD = 1,
+	/// `10/11/2023`, most of the world
+	DMY = 2,
+	/// `11/10/2023`, United States
+	MDY = 3,
+}
+
+impl DateOrder {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(DateOrder::ALL, [DateOrder::YMD, DateOrder::DMY, DateOrder::MDY]);
+	/// ```
+	pub const ALL: [Self; 3] = [
+		Self::YMD,
+		Self::DMY,
+		Self::MDY,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(DateOrder::YMD.as_str(), "YMD");
+	/// assert_eq!(DateOrder::DMY.as_str(), "DMY");
+	/// assert_eq!(DateOrder::MDY.as_str(), "MDY");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::YMD => "YMD",
+			Self::DMY => "DMY",
+			Self::MDY => "MDY",
+		}
+	}
+
+	#[inline]
+	/// The [`DateOrder`] of the year, month and day positions
+	pub(crate) const fn from_positions(year: usize, month: usize, day: usize) -> Self {
+		if year < month && year < day {
+			Self::YMD
+		} else if day < month {
+			Self::DMY
+		} else {
+			Self::MDY
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- DateCandidate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd)]
+/// A possible [`Date`] found by [`DateParser::candidates`]
+pub struct DateCandidate {
+	date: Date,
+	confidence: f64,
+	order: DateOrder,
+	start: usize,
+	end: usize,
+	expansion: Option<YearExpansion>,
+}
+
+impl DateCandidate {
+	#[inline]
+	/// The [`Date`]
+	pub const fn date(self) -> Date {
+		self.date
+	}
+
+	#[inline]
+	/// How likely this is the intended [`Date`], `0.0..=1.0`
+	///
+	/// The confidence of all candidates adds up to `1.0` or less.
+	pub const fn confidence(self) -> f64 {
+		self.confidence
+	}
+
+	#[inline]
+	/// The [`DateOrder`] this candidate was read in
+	pub const fn order(self) -> DateOrder {
+		self.order
+	}
+
+	#[inline]
+	/// The byte range of the input this candidate was read from
+	pub const fn span(self) -> std::ops::Range<usize> {
+		self.start..self.end
+	}
+
+	#[inline]
+	/// How the year was expanded, if it was 2 digits
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let candidates = DateParser::new().order(DateOrder::MDY).candidates("10/20/23");
+	/// let expansion = candidates[0].expansion().unwrap();
+	/// assert_eq!(expansion.two_digit(), 23);
+	/// assert_eq!(expansion.year(),      Year(2023));
+	/// assert_eq!(expansion.pivot(),     YearPivot::POSIX);
+	///
+	/// let candidates = DateParser::new().order(DateOrder::MDY).candidates("10/20/2023");
+	/// assert_eq!(candidates[0].expansion(), None);
+	/// ```
+	pub const fn expansion(self) -> Option<YearExpansion> {
+		self.expansion
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Token
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+/// A piece of the input
+enum Token {
+	/// Digits, optionally followed by an ordinal suffix
+	Number { value: u32, digits: u8, ordinal: bool },
+	Month(Month),
+	Weekday(Weekday),
+	/// Any other word
+	Word,
+	Separator(char),
+}
+
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+/// A [`Token::Number`] or [`Token::Month`] in a run of them
+struct Component {
+	token: Token,
+	/// A number right after a word, e.g, `Room 12`
+	label: bool,
+	start: usize,
+	end: usize,
+}
+
+/// Split the input into [`Token`]s with their byte ranges
+fn tokenize(s: &str) -> Vec<(Token, usize, usize)> {
+	let mut tokens = Vec::new();
+	let mut chars = s.char_indices().peekable();
+
+	while let Some((start, c)) = chars.next() {
+		let mut end = start + c.len_utf8();
+
+		if c.is_ascii_digit() {
+			while let Some((i, c)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+				end = i + c.len_utf8();
+			}
+			let digits = end - start;
+
+			// Ordinal suffix.
+			let suffix = s.get(end..end + 2).filter(|x| ["st", "nd", "rd", "th"].iter().any(|o| x.eq_ignore_ascii_case(o)));
+			let ordinal = suffix.is_some() && !s[end + 2..].starts_with(char::is_alphabetic);
+			if ordinal {
+				chars.next();
+				chars.next();
+				end += 2;
+			}
+
+			let token = match s[start..start + digits].parse() {
+				Ok(value) if digits <= 5 => Token::Number { value, digits: digits as u8, ordinal },
+				_ => Token::Word,
+			};
+			tokens.push((token, start, end));
+		} else if c.is_alphabetic() {
+			while let Some((i, c)) = chars.next_if(|(_, c)| c.is_alphabetic()) {
+				end = i + c.len_utf8();
+			}
+			let word = &s[start..end];
+			let token = if let Some(month) = Month::from_str(word) {
+				Token::Month(month)
+			} else if let Some(weekday) = Weekday::from_str(word) {
+				Token::Weekday(weekday)
+			} else {
+				Token::Word
+			};
+			tokens.push((token, start, end));
+		} else {
+			tokens.push((Token::Separator(c), start, end));
+		}
+	}
+
+	tokens
+}
+
+//---------------------------------------------------------------------------------------------------- DateParser
+#[derive(Copy,Clone,Debug,PartialEq)]
+/// Configurable heuristic [`Date`] parser
+///
+/// Unlike [`Date::from_str`], this considers every plausible reading
+/// of the input and scores them, instead of taking the first guess.
+///
+/// The input is split into runs of numbers and month names joined by separators.
+/// Every 3 in a row are read as each [`DateOrder`] that forms an existing [`Date`].
+///
+/// Readings are scored by:
+/// - The preferred [`DateOrder`], which wins ties
+/// - Weekdays in the input, e.g, `Friday 10/11/2023`
+/// - Numbers that look like labels, e.g, `Room 12`
+/// - 2 digit years, which are expanded with [`DateParser::pivot`]
+///
+/// ```rust
+/// # use nichi::*;
+/// let dmy = DateParser::new().order(DateOrder::DMY);
+/// let mdy = DateParser::new().order(DateOrder::MDY);
+///
+/// // Ambiguous, the preference decides.
+/// assert_eq!(dmy.parse("10/11/2023"), Some(Date::new(2023, 11, 10)));
+/// assert_eq!(mdy.parse("10/11/2023"), Some(Date::new(2023, 10, 11)));
+///
+/// // Only 1 reading exists.
+/// assert_eq!(mdy.parse("25/12/2023"), Some(Date::new(2023, 12, 25)));
+///
+/// // The weekday decides.
+/// assert_eq!(mdy.parse("Friday, 10/11/2023"), Some(Date::new(2023, 11, 10)));
+///
+/// // `12` is a room number, not a day.
+/// assert_eq!(dmy.parse("Room 12, March 2023"), None);
+/// ```
+///
+/// All the readings can be inspected with [`DateParser::candidates`]:
+/// ```rust
+/// # use nichi::*;
+/// let candidates = DateParser::new().order(DateOrder::DMY).candidates("10/11/2023");
+/// assert_eq!(candidates.len(), 2);
+///
+/// assert_eq!(candidates[0].date(),  Date::new(2023, 11, 10));
+/// assert_eq!(candidates[0].order(), DateOrder::DMY);
+/// assert!((candidates[0].confidence() - 0.667).abs() < 0.001);
+///
+/// assert_eq!(candidates[1].date(),  Date::new(2023, 10, 11));
+/// assert_eq!(candidates[1].order(), DateOrder::MDY);
+/// assert!((candidates[1].confidence() - 0.333).abs() < 0.001);
+/// ```
+pub struct DateParser {
+	order: DateOrder,
+	/// Bit `n` is set if ASCII character `n` is a separator
+	separators: u128,
+	min_year: i16,
+	max_year: i16,
+	min_confidence: f64,
+	pivot: YearPivot,
+}
+
+impl DateParser {
+	/// The default separators
+	pub const SEPARATORS: &'static str = " ,-/.";
+
+	#[inline]
+	/// Create a new [`DateParser`]
+	///
+	/// | Setting | Default |
+	/// |---------|---------|
+	/// | [`DateParser::order`] | [`DateOrder::YMD`] |
+	/// | [`DateParser::separators`] | [`DateParser::SEPARATORS`] |
+	/// | [`DateParser::year_range`] | `1000..=9999` |
+	/// | [`DateParser::min_confidence`] | `0.5` |
+	/// | [`DateParser::pivot`] | [`YearPivot::POSIX`] |
+	pub const fn new() -> Self {
+		Self {
+			order: DateOrder::YMD,
+			separators: 0,
+			min_year: 1000,
+			max_year: 9999,
+			min_confidence: 0.5,
+			pivot: YearPivot::POSIX,
+		}.separators(Self::SEPARATORS)
+	}
+
+	#[inline]
+	/// The preferred [`DateOrder`] for ambiguous input
+	pub const fn order(mut self, order: DateOrder) -> Self {
+		self.order = order;
+		self
+	}
+
+	#[inline]
+	/// The ASCII characters allowed between the year, month and day
+	///
+	/// A space allows any whitespace, non-ASCII characters are ignored.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let parser = DateParser::new().separators("/");
+	/// assert_eq!(parser.parse("2023/10/20"), Some(Date::new(2023, 10, 20)));
+	/// assert_eq!(parser.parse("2023-10-20"), None);
+	/// ```
+	pub const fn separators(mut self, separators: &str) -> Self {
+		let bytes = separators.as_bytes();
+		self.separators = 0;
+		let mut i = 0;
+		while i < bytes.len() {
+			if bytes[i] < 128 {
+				self.separators |= 1 << bytes[i];
+			}
+			i += 1;
+		}
+		self
+	}
+
+	#[inline]
+	/// The range of years to accept, inclusive
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let parser = DateParser::new().order(DateOrder::MDY);
+	/// assert_eq!(parser.parse("1/2/1850"), Some(Date::new(1850, 1, 2)));
+	/// assert_eq!(parser.year_range(1900, 2100).parse("1/2/1850"), None);
+	/// ```
+	pub const fn year_range(mut self, min: i16, max: i16) -> Self {
+		self.min_year = min;
+		self.max_year = max;
+		self
+	}
+
+	#[inline]
+	/// The minimum confidence for [`DateParser::parse`] to return a [`Date`], `0.0..=1.0`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let parser = DateParser::new().order(DateOrder::DMY);
+	/// assert_eq!(parser.parse("10/11/2023"), Some(Date::new(2023, 11, 10)));
+	/// assert_eq!(parser.min_confidence(0.9).parse("10/11/2023"), None);
+	/// ```
+	pub const fn min_confidence(mut self, min_confidence: f64) -> Self {
+		self.min_confidence = min_confidence;
+		self
+	}
+
+	#[inline]
+	/// The [`YearPivot`] that expands 2 digit years
+	///
+	/// Expanded years must still be within [`DateParser::year_range`].
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let parser = DateParser::new();
+	/// assert_eq!(parser.parse("20-Oct-99"), Some(Date::new(1999, 10, 20)));
+	/// assert_eq!(parser.parse("20-Oct-49"), Some(Date::new(2049, 10, 20)));
+	///
+	/// let parser = parser.pivot(YearPivot::Fixed(1940));
+	/// assert_eq!(parser.parse("20-Oct-49"), Some(Date::new(1949, 10, 20)));
+	///
+	/// let today = Date::new(2023, 10, 20);
+	/// let parser = DateParser::new().order(DateOrder::MDY).pivot(YearPivot::sliding(today, 0));
+	/// assert_eq!(parser.parse("10/20/23"), Some(Date::new(2023, 10, 20)));
+	/// assert_eq!(parser.parse("10/20/24"), Some(Date::new(1924, 10, 20)));
+	/// ```
+	pub const fn pivot(mut self, pivot: YearPivot) -> Self {
+		self.pivot = pivot;
+		self
+	}
+
+	/// Parse the most likely [`Date`] out of `s`
+	///
+	/// Returns [`None`] if there is no candidate with at least [`DateParser::min_confidence`].
+	pub fn parse(&self, s: &str) -> Option<Date> {
+		self.candidates(s)
+			.first()
+			.filter(|c| c.confidence >= self.min_confidence)
+			.map(|c| c.date)
+	}
+
+	/// Every plausible [`Date`] in `s`, most likely first
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let candidates = DateParser::new().candidates("Room 12, March 2023");
+	/// assert_eq!(candidates.len(), 1);
+	/// assert_eq!(candidates[0].date(), Date::new(2023, 3, 12));
+	/// assert_eq!(candidates[0].span(), 5..19);
+	/// assert!(candidates[0].confidence() < 0.5);
+	/// ```
+	pub fn candidates(&self, s: &str) -> Vec<DateCandidate> {
+		// (candidate, weight)
+		let mut candidates: Vec<(DateCandidate, f64)> = Vec::new();
+
+		let mut run: Vec<Component> = Vec::new();
+		let mut weekday = None;
+		// If only separators were seen since the last component.
+		let mut joined = true;
+		// If the last non-separator token was a word.
+		let mut after_word = false;
+
+		for (token, start, end) in tokenize(s) {
+			match token {
+				Token::Number { .. } | Token::Month(_) => {
+					if !joined {
+						self.read_run(&run, weekday, &mut candidates);
+						run.clear();
+						weekday = None;
+					}
+					let label = after_word && matches!(token, Token::Number { .. });
+					run.push(Component { token, label, start, end });
+					joined = true;
+					after_word = false;
+				},
+				Token::Weekday(w) => {
+					if !run.is_empty() && !joined {
+						self.read_run(&run, weekday, &mut candidates);
+						run.clear();
+					}
+					weekday = Some(w);
+					after_word = false;
+				},
+				Token::Word => {
+					joined = false;
+					after_word = true;
+				},
+				Token::Separator(c) => {
+					let c = if c.is_whitespace() { ' ' } else { c };
+					if (c as u32) >= 128 || self.separators & (1 << c as u32) == 0 {
+						joined = false;
+					}
+					// `No. 12` and `#12` are labels too.
+					if !matches!(c, ' ' | '.' | '#') {
+						after_word = false;
+					}
+				},
+			}
+		}
+		self.read_run(&run, weekday, &mut candidates);
+
+		// Merge identical dates.
+		let mut merged: Vec<(DateCandidate, f64)> = Vec::with_capacity(candidates.len());
+		for (candidate, weight) in candidates {
+			match merged.iter_mut().find(|(c, _)| c.date == candidate.date) {
+				Some((c, w)) => {
+					if weight > *w {
+						c.order = candidate.order;
+					}
+					c.confidence = c.confidence.max(candidate.confidence);
+					*w += weight;
+				},
+				None => merged.push((candidate, weight)),
+			}
+		}
+
+		// Confidence is the share of the weight, times the plausibility.
+		let total: f64 = merged.iter().map(|(_, w)| w).sum();
+		let mut candidates: Vec<DateCandidate> = merged
+			.into_iter()
+			.map(|(c, w)| DateCandidate { confidence: c.confidence * w / total, ..c })
+			.collect();
+
+		candidates.sort_by(|a, b| {
+			b.confidence.total_cmp(&a.confidence).then(a.start.cmp(&b.start))
+		});
+		candidates
+	}
+
+	/// Read every 3 [`Component`]s in a row as dates
+	fn read_run(&self, run: &[Component], weekday: Option<Weekday>, candidates: &mut Vec<(DateCandidate, f64)>) {
+		let long = run.len() > 3;
+		for window in run.windows(3) {
+			let months: Vec<usize> = (0..3).filter(|i| matches!(window[*i].token, Token::Month(_))).collect();
+
+			// (year, month, day) indices.
+			let readings: &[(usize, usize, usize)] = match months.as_slice() {
+				[] => &[(0, 1, 2), (2, 1, 0), (2, 0, 1)],
+				[0] => &[(2, 0, 1), (1, 0, 2)],
+				[1] => &[(2, 1, 0), (0, 1, 2)],
+				[2] => &[(0, 2, 1), (1, 2, 0)],
+				_ => &[],
+			};
+
+			for &(y, m, d) in readings {
+				if let Some(candidate) = self.read(window, (y, m, d), weekday, long) {
+					candidates.push(candidate);
+				}
+			}
+		}
+	}
+
+	/// Read 3 [`Component`]s as a date, with the year, month and day at `positions`
+	fn read(
+		&self,
+		window: &[Component],
+		(y, m, d): (usize, usize, usize),
+		weekday: Option<Weekday>,
+		long: bool,
+	) -> Option<(DateCandidate, f64)> {
+		let mut confidence = 1.0;
+		let mut expansion = None;
+
+		let year = match window[y].token {
+			Token::Number { value, digits: 3.., ordinal: false } => value as i64,
+			Token::Number { value, digits: 2, ordinal: false } => {
+				confidence *= CONFIDENCE_TWO_DIGIT_YEAR;
+				let e = YearExpansion::new(value as u8, self.pivot);
+				expansion = Some(e);
+				e.year().inner() as i64
+			},
+			_ => return None,
+		};
+		if year < self.min_year as i64 || year > self.max_year as i64 {
+			return None;
+		}
+
+		let month = match window[m].token {
+			Token::Month(month) => month,
+			Token::Number { value: value @ 1..=12, digits: ..=2, ordinal: false } => Month::new(value as u8),
+			_ => return None,
+		};
+
+		let day = match window[d].token {
+			Token::Number { value, digits: ..=2, .. } if value >= 1 => value as u8,
+			_ => return None,
+		};
+		if day > days_in_month(year as i128, month).inner() {
+			return None;
+		}
+
+		let date = Date::new(year as i16, month.inner(), day);
+		let order = DateOrder::from_positions(y, m, d);
+
+		let mut weight = if order == self.order { WEIGHT_PREFERRED } else { WEIGHT_OTHER };
+		if weekday.is_some_and(|w| w != date.weekday()) {
+			weight *= WEIGHT_WEEKDAY_MISMATCH;
+		}
+		if window.iter().any(|c| c.label) {
+			confidence *= CONFIDENCE_LABEL;
+		}
+		if long {
+			confidence *= CONFIDENCE_LONG_RUN;
+		}
+
+		let candidate = DateCandidate {
+			date,
+			confidence,
+			order,
+			start: window[0].start,
+			end: window[2].end,
+			expansion,
+		};
+		Some((candidate, weight))
+	}
+}
+
+impl Default for DateParser {
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -0,0 +1,110 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+
+//---------------------------------------------------------------------------------------------------- DateRange
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// An inclusive range of [`Date`]s
+///
+/// ```rust
+/// # use nichi::*;
+/// let range = DateRange::new(Date::new(2024, 2, 27), Date::new(2024, 3, 1));
+/// assert_eq!(range.days(), 4);
+/// assert!(range.contains(Date::new(2024, 2, 29)));
+/// assert!(!range.contains(Date::new(2024, 3, 2)));
+///
+/// let days: Vec<Date> = range.iter().collect();
+/// assert_eq!(days, [
+/// 	Date::new(2024, 2, 27),
+/// 	Date::new(2024, 2, 28),
+/// 	Date::new(2024, 2, 29),
+/// 	Date::new(2024, 3, 1),
+/// ]);
+/// ```
+pub struct DateRange {
+	start: Date,
+	end: Date,
+}
+
+impl DateRange {
+	#[inline]
+	/// Create a new [`DateRange`] from `start` to `end`, inclusive
+	///
+	/// ## Panics
+	/// This function panics if `start > end`.
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// DateRange::new(Date::new(2024, 1, 2), Date::new(2024, 1, 1));
+	/// ```
+	pub const fn new(start: Date, end: Date) -> Self {
+		assert!(start.to_rata_die() <= end.to_rata_die(), "start was after end");
+		Self { start, end }
+	}
+
+	#[inline]
+	/// Every day of `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let range = DateRange::year(Year(2024));
+	/// assert_eq!(range.start(), Date::new(2024, 1, 1));
+	/// assert_eq!(range.end(),   Date::new(2024, 12, 31));
+	/// assert_eq!(range.days(),  366);
+	/// ```
+	pub const fn year(year: Year) -> Self {
+		Self {
+			start: Date::new(year.inner(), 1, 1),
+			end: Date::new(year.inner(), 12, 31),
+		}
+	}
+
+	#[inline]
+	/// The first day of the range
+	pub const fn start(self) -> Date {
+		self.start
+	}
+
+	#[inline]
+	/// The last day of the range
+	pub const fn end(self) -> Date {
+		self.end
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// let range = DateRange::new(Date::new(2024, 1, 1), Date::new(2024, 1, 1));
+	/// assert_eq!(range.inner(), (Date::new(2024, 1, 1), Date::new(2024, 1, 1)));
+	/// ```
+	pub const fn inner(self) -> (Date, Date) {
+		(self.start, self.end)
+	}
+
+	#[inline]
+	/// The amount of days in the range, including both ends
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let date = Date::new(2024, 1, 1);
+	/// assert_eq!(DateRange::new(date, date).days(), 1);
+	/// ```
+	pub const fn days(self) -> u32 {
+		(self.end.to_rata_die() - self.start.to_rata_die()) as u32 + 1
+	}
+
+	#[inline]
+	/// If `date` is within the range
+	pub const fn contains(self, date: Date) -> bool {
+		let date = date.to_rata_die();
+		date >= self.start.to_rata_die() && date <= self.end.to_rata_die()
+	}
+
+	#[inline]
+	/// Iterate over every [`Date`] in the range
+	pub fn iter(self) -> impl DoubleEndedIterator<Item = Date> {
+		(self.start.to_rata_die()..=self.end.to_rata_die()).map(Date::from_rata_die)
+	}
+}
@@ -898,6 +898,7 @@ impl Day {
 	/// assert_eq!(Day::from_str("twentyfirst").unwrap(), Day::TwentyFirst);
 	/// assert_eq!(Day::from_str("TwentyFirst").unwrap(), Day::TwentyFirst);
 	/// assert_eq!(Day::from_str("TWENTYFIRST").unwrap(), Day::TwentyFirst);
+	/// assert_eq!(Day::from_str("twentyfifth").unwrap(), Day::TwentyFifth);
 	/// ```
 	///
 	/// ## Examples
@@ -1014,11 +1015,11 @@ impl Day {
 				b"TwentySecond"  | b"twentysecond"  | b"TWENTYSECOND"  => Some(Self::TwentySecond),
 				b"TwentyThird"   | b"twentythird"   | b"TWENTYTHIRD"   => Some(Self::TwentyThird),
 				b"TwentyFourth"  | b"twentyfourth"  | b"TWENTYFOURTH"  => Some(Self::TwentyFourth),
-				b"TwentyFifth"   | b"Twentyfifth"   | b"TWENTYFIFTH"   => Some(Self::TwentyFifth),
-				b"TwentySixth"   | b"Twentysixth"   | b"TWENTYSIXTH"   => Some(Self::TwentySixth),
-				b"TwentySeventh" | b"Twentyseventh" | b"TWENTYSEVENTH" => Some(Self::TwentySeventh),
-				b"TwentyEighth"  | b"Twentyeighth"  | b"TWENTYEIGHTH"  => Some(Self::TwentyEighth),
-				b"TwentyNinth"   | b"Twentyninth"   | b"TWENTYNINTH"   => Some(Self::TwentyNinth),
+				b"TwentyFifth"   | b"Twentyfifth"   | b"twentyfifth"   | b"TWENTYFIFTH"   => Some(Self::TwentyFifth),
+				b"TwentySixth"   | b"Twentysixth"   | b"twentysixth"   | b"TWENTYSIXTH"   => Some(Self::TwentySixth),
+				b"TwentySeventh" | b"Twentyseventh" | b"twentyseventh" | b"TWENTYSEVENTH" => Some(Self::TwentySeventh),
+				b"TwentyEighth"  | b"Twentyeighth"  | b"twentyeighth"  | b"TWENTYEIGHTH"  => Some(Self::TwentyEighth),
+				b"TwentyNinth"   | b"Twentyninth"   | b"twentyninth"   | b"TWENTYNINTH"   => Some(Self::TwentyNinth),
 				b"Thirtieth"     | b"thirtieth"     | b"THIRTIETH"     => Some(Self::Thirtieth),
 				b"ThirtyFirst"   | b"thirtyfirst"   | b"THIRTYFIRST"   => Some(Self::ThirtyFirst),
 				_ => None,
@@ -0,0 +1,533 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+use crate::month::Month;
+use crate::free::days_in_month;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Thai month names, January to December
+const THAI_MONTHS: [&str; 12] = [
+	"มกราคม",
+	"กุมภาพันธ์",
+	"มีนาคม",
+	"เมษายน",
+	"พฤษภาคม",
+	"มิถุนายน",
+	"กรกฎาคม",
+	"สิงหาคม",
+	"กันยายน",
+	"ตุลาคม",
+	"พฤศจิกายน",
+	"ธันวาคม",
+];
+
+//---------------------------------------------------------------------------------------------------- OffsetEra
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Year numbering that is a fixed offset from the Gregorian [`Year`]
+///
+/// Months and days are the same as the Gregorian calendar.
+///
+/// ```rust
+/// # use nichi::*;
+/// assert_eq!(OffsetEra::Buddhist.from_year(Year(2023)), 2566);
+/// assert_eq!(OffsetEra::Minguo.from_year(Year(2023)),   112);
+/// assert_eq!(OffsetEra::Juche.from_year(Year(2023)),    112);
+///
+/// assert_eq!(OffsetEra::Buddhist.to_year(2566), Some(Year(2023)));
+/// ```
+pub enum OffsetEra {
+	#[default]
+	/// Thai solar calendar, Buddhist Era (พุทธศักราช), `CE + 543`
+	///
+	/// The Thai year has started on January 1st since `2484` (`1941`),
+	/// earlier years are treated the same way.
+	Buddhist = 1,
+	/// Republic of China calendar (民國紀元), `CE - 1911`
+	///
+	/// Years before `民國1年` (`1912`) are counted backwards with `民國前`,
+	/// `1911` is `民國前1年`.
+	Minguo = 2,
+	/// North Korean Juche calendar (주체력), `CE - 1911`
+	Juche = 3,
+}
+
+impl OffsetEra {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(OffsetEra::ALL, [OffsetEra::Buddhist, OffsetEra::Minguo, OffsetEra::Juche]);
+	/// ```
+	pub const ALL: [Self; 3] = [
+		Self::Buddhist,
+		Self::Minguo,
+		Self::Juche,
+	];
+
+	#[inline]
+	/// The difference between the era's year and the Gregorian year
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(OffsetEra::Buddhist.offset(), 543);
+	/// assert_eq!(OffsetEra::Minguo.offset(),   -1911);
+	/// assert_eq!(OffsetEra::Juche.offset(),    -1911);
+	/// ```
+	pub const fn offset(self) -> i32 {
+		match self {
+			Self::Buddhist => 543,
+			Self::Minguo   => -1911,
+			Self::Juche    => -1911,
+		}
+	}
+
+	#[inline]
+	/// Convert a Gregorian [`Year`] into this era's year
+	///
+	/// This can be `0` or negative for years before the era.
+	pub const fn from_year(self, year: Year) -> i32 {
+		year.inner() as i32 + self.offset()
+	}
+
+	#[inline]
+	/// Convert this era's year into a Gregorian [`Year`]
+	///
+	/// Returns [`None`] if the [`Year`] would overflow.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(OffsetEra::Minguo.to_year(112),    Some(Year(2023)));
+	/// assert_eq!(OffsetEra::Minguo.to_year(0),      Some(Year(1911)));
+	/// assert_eq!(OffsetEra::Buddhist.to_year(i32::MAX), None);
+	/// ```
+	pub const fn to_year(self, year: i32) -> Option<Year> {
+		let year = year as i64 - self.offset() as i64;
+		if year > i16::MAX as i64 || year < i16::MIN as i64 {
+			None
+		} else {
+			Some(Year(year as i16))
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(OffsetEra::Buddhist.as_str(), "Buddhist Era");
+	/// assert_eq!(OffsetEra::Minguo.as_str(),   "Minguo");
+	/// assert_eq!(OffsetEra::Juche.as_str(),    "Juche");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Buddhist => "Buddhist Era",
+			Self::Minguo   => "Minguo",
+			Self::Juche    => "Juche",
+		}
+	}
+
+	#[inline]
+	/// The prefix written before the year
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(OffsetEra::Buddhist.as_str_prefix(), "พ.ศ.");
+	/// assert_eq!(OffsetEra::Minguo.as_str_prefix(),   "民國");
+	/// assert_eq!(OffsetEra::Juche.as_str_prefix(),    "주체");
+	/// ```
+	pub const fn as_str_prefix(self) -> &'static str {
+		match self {
+			Self::Buddhist => "พ.ศ.",
+			Self::Minguo   => "民國",
+			Self::Juche    => "주체",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- EraYear
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// A Gregorian [`Year`] numbered by an [`OffsetEra`]
+///
+/// ```rust
+/// # use nichi::*;
+/// let year = EraYear::new(OffsetEra::Buddhist, Year(2023));
+/// assert_eq!(year.year(), 2566);
+/// assert_eq!(year.to_string(), "พ.ศ. 2566");
+/// assert_eq!(EraYear::from_str(OffsetEra::Buddhist, "พ.ศ. 2566"), Some(year));
+///
+/// let year = EraYear::new(OffsetEra::Minguo, Year(1911));
+/// assert_eq!(year.to_string(), "民國前1年");
+/// assert_eq!(EraYear::from_str(OffsetEra::Minguo, "民國前1年"), Some(year));
+/// ```
+pub struct EraYear {
+	era: OffsetEra,
+	year: Year,
+}
+
+impl EraYear {
+	#[inline]
+	/// Create a new [`EraYear`]
+	pub const fn new(era: OffsetEra, year: Year) -> Self {
+		Self { era, year }
+	}
+
+	#[inline]
+	/// The [`OffsetEra`]
+	pub const fn era(self) -> OffsetEra {
+		self.era
+	}
+
+	#[inline]
+	/// The year numbered by the era
+	pub const fn year(self) -> i32 {
+		self.era.from_year(self.year)
+	}
+
+	#[inline]
+	/// The Gregorian [`Year`]
+	pub const fn to_year(self) -> Year {
+		self.year
+	}
+
+	/// Parse a year written in the era's native style
+	///
+	/// | Era                     | Input          |
+	/// |-------------------------|----------------|
+	/// | [`OffsetEra::Buddhist`] | `พ.ศ. 2566`     |
+	/// | [`OffsetEra::Minguo`]   | `民國112年`, `中華民國112年`, `民國前1年` |
+	/// | [`OffsetEra::Juche`]    | `주체112년`     |
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let year = EraYear::new(OffsetEra::Minguo, Year(2023));
+	/// assert_eq!(EraYear::from_str(OffsetEra::Minguo, "民國112年"),     Some(year));
+	/// assert_eq!(EraYear::from_str(OffsetEra::Minguo, "中華民國112年"), Some(year));
+	/// assert_eq!(EraYear::from_str(OffsetEra::Juche,  "주체112년"),     Some(EraYear::new(OffsetEra::Juche, Year(2023))));
+	/// assert_eq!(EraYear::from_str(OffsetEra::Juche,  "주체 112년"),    Some(EraYear::new(OffsetEra::Juche, Year(2023))));
+	/// assert_eq!(EraYear::from_str(OffsetEra::Minguo, "民國 112年"),    Some(year));
+	///
+	/// assert_eq!(EraYear::from_str(OffsetEra::Minguo, "民國112"), None);
+	/// assert_eq!(EraYear::from_str(OffsetEra::Minguo, "主體112年"), None);
+	/// ```
+	pub fn from_str(era: OffsetEra, s: &str) -> Option<Self> {
+		let (year, rest) = parse_era_year(era, s.trim())?;
+		let rest = match era {
+			OffsetEra::Buddhist => rest,
+			OffsetEra::Minguo   => rest.strip_prefix('年')?,
+			OffsetEra::Juche    => rest.strip_prefix('년')?,
+		};
+
+		if !rest.is_empty() {
+			return None;
+		}
+
+		Some(Self { era, year: era.to_year(year)? })
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- EraDate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// A [`Date`] with its year numbered by an [`OffsetEra`]
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = EraDate::new(OffsetEra::Minguo, Date::new(2023, 10, 20));
+/// assert_eq!(date.year(), 112);
+/// assert_eq!(date.to_string(), "民國112年10月20日");
+/// assert_eq!(date.to_string_numeric(), "112/10/20");
+///
+/// let date = EraDate::new(OffsetEra::Buddhist, Date::new(2023, 10, 20));
+/// assert_eq!(date.to_string(), "20 ตุลาคม พ.ศ. 2566");
+/// assert_eq!(date.to_string_numeric(), "20/10/2566");
+///
+/// let date = EraDate::new(OffsetEra::Juche, Date::new(2023, 10, 20));
+/// assert_eq!(date.to_string(), "주체112년 10월 20일");
+/// assert_eq!(date.to_string_numeric(), "112.10.20");
+/// ```
+pub struct EraDate {
+	era: OffsetEra,
+	date: Date,
+}
+
+impl EraDate {
+	#[inline]
+	/// Create a new [`EraDate`]
+	pub const fn new(era: OffsetEra, date: Date) -> Self {
+		Self { era, date }
+	}
+
+	/// Create a new [`EraDate`] from the era's year, a month and a day
+	///
+	/// Returns [`None`] if the date does not exist.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let date = EraDate::from_era_year(OffsetEra::Buddhist, 2567, 2, 29).unwrap();
+	/// assert_eq!(date.to_date(), Date::new(2024, 2, 29));
+	///
+	/// assert!(EraDate::from_era_year(OffsetEra::Buddhist, 2566, 2, 29).is_none());
+	/// ```
+	pub const fn from_era_year(era: OffsetEra, year: i32, month: u8, day: u8) -> Option<Self> {
+		let Some(year) = era.to_year(year) else { return None };
+		match new_date(year, month as i64, day as i64) {
+			Some(date) => Some(Self { era, date }),
+			None => None,
+		}
+	}
+
+	#[inline]
+	/// The [`OffsetEra`]
+	pub const fn era(self) -> OffsetEra {
+		self.era
+	}
+
+	#[inline]
+	/// The year numbered by the era
+	pub const fn year(self) -> i32 {
+		self.era.from_year(self.date.year())
+	}
+
+	#[inline]
+	/// The month, `1..=12`
+	pub const fn month(self) -> u8 {
+		self.date.month().inner()
+	}
+
+	#[inline]
+	/// The day, `1..=31`
+	pub const fn day(self) -> u8 {
+		self.date.day().inner()
+	}
+
+	#[inline]
+	/// The Gregorian [`Date`]
+	pub const fn to_date(self) -> Date {
+		self.date
+	}
+
+	/// Format as numbers only, in the era's usual order
+	///
+	/// | Era                     | Output       |
+	/// |-------------------------|--------------|
+	/// | [`OffsetEra::Buddhist`] | `20/10/2566` |
+	/// | [`OffsetEra::Minguo`]   | `112/10/20`, `前1/01/01` |
+	/// | [`OffsetEra::Juche`]    | `112.10.20`  |
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let date = EraDate::new(OffsetEra::Minguo, Date::new(1911, 1, 1));
+	/// assert_eq!(date.to_string_numeric(), "前1/01/01");
+	/// ```
+	pub fn to_string_numeric(self) -> String {
+		let (month, day) = (self.month(), self.day());
+		match self.era {
+			OffsetEra::Buddhist => format!("{day:02}/{month:02}/{}", self.year()),
+			OffsetEra::Minguo   => {
+				let year = self.year();
+				if year > 0 {
+					format!("{year}/{month:02}/{day:02}")
+				} else {
+					format!("前{}/{month:02}/{day:02}", 1 - year)
+				}
+			},
+			OffsetEra::Juche => format!("{}.{month:02}.{day:02}", self.year()),
+		}
+	}
+
+	/// Parse a date written in the era's native or numeric style
+	///
+	/// All the outputs of [`EraDate::to_string`] and [`EraDate::to_string_numeric`] are accepted,
+	/// along with some variations:
+	///
+	/// | Era                     | Input |
+	/// |-------------------------|-------|
+	/// | [`OffsetEra::Buddhist`] | `20 ตุลาคม พ.ศ. 2566`, `20 ตุลาคม 2566`, `20/10/2566`, `20-10-2566` |
+	/// | [`OffsetEra::Minguo`]   | `民國112年10月20日`, `中華民國112年10月20日`, `民國前1年1月1日`, `112/10/20`, `112-10-20`, `112.10.20`, `前1/1/1` |
+	/// | [`OffsetEra::Juche`]    | `주체112년 10월 20일`, `주체112.10.20`, `112.10.20`, `112-10-20` |
+	///
+	/// Returns [`None`] if the string is malformed or the date does not exist.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let date = EraDate::new(OffsetEra::Minguo, Date::new(2023, 10, 20));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Minguo, "民國112年10月20日"),     Some(date));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Minguo, "中華民國112年10月20日"), Some(date));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Minguo, "民國 112年10月20日"),    Some(date));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Minguo, "112/10/20"),             Some(date));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Minguo, "112-10-20"),             Some(date));
+	///
+	/// let date = EraDate::new(OffsetEra::Minguo, Date::new(1910, 10, 10));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Minguo, "民國前2年10月10日"), Some(date));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Minguo, "民國前 2年10月10日"), Some(date));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Minguo, "前2/10/10"),         Some(date));
+	///
+	/// let date = EraDate::new(OffsetEra::Buddhist, Date::new(2023, 10, 20));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Buddhist, "20 ตุลาคม พ.ศ. 2566"), Some(date));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Buddhist, "20 ตุลาคม 2566"),      Some(date));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Buddhist, "20/10/2566"),          Some(date));
+	///
+	/// let date = EraDate::new(OffsetEra::Juche, Date::new(2023, 10, 20));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Juche, "주체112년 10월 20일"), Some(date));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Juche, "주체 112년 10월 20일"), Some(date));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Juche, "주체 112.10.20"),      Some(date));
+	/// assert_eq!(EraDate::from_str(OffsetEra::Juche, "112.10.20"),           Some(date));
+	///
+	/// assert_eq!(EraDate::from_str(OffsetEra::Minguo, "112/02/30"),   None);
+	/// assert_eq!(EraDate::from_str(OffsetEra::Minguo, "民國0年1月1日"), None);
+	/// assert_eq!(EraDate::from_str(OffsetEra::Minguo, "112/10"),      None);
+	/// assert_eq!(EraDate::from_str(OffsetEra::Buddhist, "20 Oct 2566"), None);
+	/// ```
+	pub fn from_str(era: OffsetEra, s: &str) -> Option<Self> {
+		let s = s.trim();
+
+		let (year, month, day) = match era {
+			OffsetEra::Buddhist => {
+				if let Some([day, month, year]) = parse_numbers(s) {
+					(year, month, day)
+				} else {
+					let mut parts = s.split_whitespace();
+					let day   = parse_number(parts.next()?)?;
+					let month = parts.next()?;
+					let month = THAI_MONTHS.iter().position(|m| *m == month)? as i64 + 1;
+					let year  = match parts.next()? {
+						"พ.ศ." => parts.next()?,
+						year  => year,
+					};
+					if parts.next().is_some() {
+						return None;
+					}
+					(parse_number(year)?, month, day)
+				}
+			},
+
+			OffsetEra::Minguo => {
+				if let Some((year, rest)) = parse_era_year(era, s) {
+					let (month, rest) = rest.strip_prefix('年')?.split_once('月')?;
+					let day = rest.strip_suffix('日')?;
+					(year as i64, parse_number(month)?, parse_number(day)?)
+				} else {
+					// `前` counts backwards from `民國1年`.
+					let (before, rest) = match s.strip_prefix('前') {
+						Some(rest) => (true, rest),
+						None => (false, s),
+					};
+					let [year, month, day] = parse_numbers(rest)?;
+					if year == 0 {
+						return None;
+					}
+					(if before { 1 - year } else { year }, month, day)
+				}
+			},
+
+			OffsetEra::Juche => {
+				let s = s.strip_prefix(era.as_str_prefix()).map_or(s, str::trim_start);
+				if let Some([year, month, day]) = parse_numbers(s) {
+					(year, month, day)
+				} else {
+					let (year, rest)  = s.split_once('년')?;
+					let (month, rest) = rest.split_once('월')?;
+					let day = rest.strip_suffix('일')?;
+					(parse_number(year)?, parse_number(month.trim())?, parse_number(day.trim())?)
+				}
+			},
+		};
+
+		Self::from_era_year(era, i32::try_from(year).ok()?, u8::try_from(month).ok()?, u8::try_from(day).ok()?)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Free
+/// Create a [`Date`], checking that it exists
+const fn new_date(year: Year, month: i64, day: i64) -> Option<Date> {
+	if month < 1 || month > 12 || day < 1 {
+		return None;
+	}
+
+	let month = Month::new(month as u8);
+	if day > days_in_month(year.inner() as i128, month).inner() as i64 {
+		return None;
+	}
+
+	Some(Date::new(year.inner(), month.inner(), day as u8))
+}
+
+/// Parse 1 to 9 ASCII digits
+fn parse_number(s: &str) -> Option<i64> {
+	if s.is_empty() || s.len() > 9 || !s.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+	s.parse().ok()
+}
+
+/// Parse 3 numbers separated by `/`, `-` or `.`
+fn parse_numbers(s: &str) -> Option<[i64; 3]> {
+	let mut parts = s.split(['/', '-', '.']);
+	let numbers = [
+		parse_number(parts.next()?)?,
+		parse_number(parts.next()?)?,
+		parse_number(parts.next()?)?,
+	];
+	if parts.next().is_some() {
+		return None;
+	}
+	Some(numbers)
+}
+
+/// Parse the era's prefix and year, returning the rest of the string
+///
+/// The [`OffsetEra::Minguo`] `民國前` notation is converted into a `0` or negative year.
+fn parse_era_year(era: OffsetEra, s: &str) -> Option<(i32, &str)> {
+	let rest = match era {
+		OffsetEra::Buddhist => s.strip_prefix("พ.ศ.")?.trim_start(),
+		OffsetEra::Minguo   => {
+			let s = s.strip_prefix("中華").unwrap_or(s);
+			s.strip_prefix("民國").or_else(|| s.strip_prefix("民国"))?.trim_start()
+		},
+		OffsetEra::Juche => s.strip_prefix("주체")?.trim_start(),
+	};
+
+	let (before, rest) = match (era, rest.strip_prefix('前')) {
+		(OffsetEra::Minguo, Some(rest)) => (true, rest.trim_start()),
+		_ => (false, rest),
+	};
+
+	let end = rest.bytes().position(|b| !b.is_ascii_digit()).unwrap_or(rest.len());
+	let year = i32::try_from(parse_number(&rest[..end])?).ok()?;
+
+	// There is no `民國0年` or `民國前0年`.
+	if era == OffsetEra::Minguo && year == 0 {
+		return None;
+	}
+
+	Some((if before { 1 - year } else { year }, &rest[end..]))
+}
+
+//---------------------------------------------------------------------------------------------------- Trait
+impl std::fmt::Display for EraYear {
+	/// Formats in the era's native style, e.g, `พ.ศ. 2566`, `民國112年`, `民國前1年`, `주체112년`
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let year = self.year();
+		match self.era {
+			OffsetEra::Buddhist => write!(f, "พ.ศ. {year}"),
+			OffsetEra::Minguo if year > 0 => write!(f, "民國{year}年"),
+			OffsetEra::Minguo => write!(f, "民國前{}年", 1 - year),
+			OffsetEra::Juche => write!(f, "주체{year}년"),
+		}
+	}
+}
+
+impl std::fmt::Display for EraDate {
+	/// Formats in the era's native style, e.g, `20 ตุลาคม พ.ศ. 2566`, `民國112年10月20日`, `주체112년 10월 20일`
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let year = EraYear::new(self.era, self.date.year());
+		let (month, day) = (self.month(), self.day());
+		match self.era {
+			OffsetEra::Buddhist => write!(f, "{day} {} {year}", THAI_MONTHS[month as usize - 1]),
+			OffsetEra::Minguo   => write!(f, "{year}{month}月{day}日"),
+			OffsetEra::Juche    => write!(f, "{year} {month}월 {day}일"),
+		}
+	}
+}
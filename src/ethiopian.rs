@@ -0,0 +1,411 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+use crate::macros::impl_u8_enum;
+use crate::coptic::{
+	alexandrian_is_leap,
+	alexandrian_days_in_month,
+	alexandrian_to_rata_die,
+	alexandrian_from_rata_die,
+};
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Rata Die of `1 Meskerem 1`, `August 29th, 8` (Julian)
+const ETHIOPIAN_EPOCH: i128 = 2796;
+
+/// Years between the Amete Alem and Amete Mihret eras
+const AMETE_ALEM_OFFSET: i32 = 5500;
+
+//---------------------------------------------------------------------------------------------------- EthiopianMonth
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Month in an Ethiopian year
+///
+/// [`EthiopianMonth::Pagume`] is the 5 (or 6 in leap years) epagomenal days at the end of the year.
+#[allow(missing_docs)]
+pub enum EthiopianMonth {
+	#[default]
+	Meskerem = 1,
+	Tikimt   = 2,
+	Hidar    = 3,
+	Tahsas   = 4,
+	Tir      = 5,
+	Yekatit  = 6,
+	Megabit  = 7,
+	Miyazya  = 8,
+	Ginbot   = 9,
+	Sene     = 10,
+	Hamle    = 11,
+	Nehase   = 12,
+	Pagume   = 13,
+}
+
+impl EthiopianMonth {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianMonth::FIRST, EthiopianMonth::Meskerem);
+	/// ```
+	pub const FIRST: Self = Self::Meskerem;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianMonth::LAST, EthiopianMonth::Pagume);
+	/// ```
+	pub const LAST: Self = Self::Pagume;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianMonth::ALL[0],  EthiopianMonth::Meskerem);
+	/// assert_eq!(EthiopianMonth::ALL[12], EthiopianMonth::Pagume);
+	/// ```
+	pub const ALL: [Self; 13] = [
+		Self::Meskerem,
+		Self::Tikimt,
+		Self::Hidar,
+		Self::Tahsas,
+		Self::Tir,
+		Self::Yekatit,
+		Self::Megabit,
+		Self::Miyazya,
+		Self::Ginbot,
+		Self::Sene,
+		Self::Hamle,
+		Self::Nehase,
+		Self::Pagume,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianMonth::new(1),  EthiopianMonth::Meskerem);
+	/// assert_eq!(EthiopianMonth::new(13), EthiopianMonth::Pagume);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// EthiopianMonth::new(14);
+	/// ```
+	pub const fn new(month: u8) -> Self {
+		assert!(month != 0, "month must not be 0");
+		assert!(month < 14, "month must not be > 13");
+		// SAFETY: repr(u8)
+		unsafe { Self::new_unchecked(month) }
+	}
+
+	#[inline]
+	/// ## Safety
+	/// `month` must be `1..=13`.
+	pub const unsafe fn new_unchecked(month: u8) -> Self {
+		debug_assert!(month != 0, "month must not be 0");
+		debug_assert!(month < 14, "month must not be > 13");
+		// SAFETY: repr(u8)
+		std::mem::transmute(month)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianMonth::new_saturating(0),  EthiopianMonth::Meskerem);
+	/// assert_eq!(EthiopianMonth::new_saturating(14), EthiopianMonth::Pagume);
+	/// ```
+	pub const fn new_saturating(month: u8) -> Self {
+		if month == 0 {
+			Self::FIRST
+		} else if month < 14 {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(month) }
+		} else {
+			Self::LAST
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianMonth::new_wrapping(0),  EthiopianMonth::Pagume);
+	/// assert_eq!(EthiopianMonth::new_wrapping(14), EthiopianMonth::Meskerem);
+	/// ```
+	pub const fn new_wrapping(month: u8) -> Self {
+		let month = month % 13;
+		if month == 0 {
+			Self::LAST
+		} else {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(month) }
+		}
+	}
+
+	impl_u8_enum!();
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianMonth::Meskerem.inner(), 1);
+	/// assert_eq!(EthiopianMonth::Pagume.inner(),   13);
+	/// ```
+	pub const fn inner(self) -> u8 {
+		// SAFETY: repr(u8)
+		unsafe { std::mem::transmute(self) }
+	}
+
+	#[inline]
+	/// Transliterated name of the month
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianMonth::Meskerem.as_str(), "Meskerem");
+	/// assert_eq!(EthiopianMonth::Tikimt.as_str(),   "Tikimt");
+	/// assert_eq!(EthiopianMonth::Hidar.as_str(),    "Hidar");
+	/// assert_eq!(EthiopianMonth::Tahsas.as_str(),   "Tahsas");
+	/// assert_eq!(EthiopianMonth::Tir.as_str(),      "Tir");
+	/// assert_eq!(EthiopianMonth::Yekatit.as_str(),  "Yekatit");
+	/// assert_eq!(EthiopianMonth::Megabit.as_str(),  "Megabit");
+	/// assert_eq!(EthiopianMonth::Miyazya.as_str(),  "Miyazya");
+	/// assert_eq!(EthiopianMonth::Ginbot.as_str(),   "Ginbot");
+	/// assert_eq!(EthiopianMonth::Sene.as_str(),     "Sene");
+	/// assert_eq!(EthiopianMonth::Hamle.as_str(),    "Hamle");
+	/// assert_eq!(EthiopianMonth::Nehase.as_str(),   "Nehase");
+	/// assert_eq!(EthiopianMonth::Pagume.as_str(),   "Pagume");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Meskerem => "Meskerem",
+			Self::Tikimt   => "Tikimt",
+			Self::Hidar    => "Hidar",
+			Self::Tahsas   => "Tahsas",
+			Self::Tir      => "Tir",
+			Self::Yekatit  => "Yekatit",
+			Self::Megabit  => "Megabit",
+			Self::Miyazya  => "Miyazya",
+			Self::Ginbot   => "Ginbot",
+			Self::Sene     => "Sene",
+			Self::Hamle    => "Hamle",
+			Self::Nehase   => "Nehase",
+			Self::Pagume   => "Pagume",
+		}
+	}
+
+	#[inline]
+	/// Amharic (Ge'ez script) name of the month
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianMonth::Meskerem.as_str_am(), "መስከረም");
+	/// assert_eq!(EthiopianMonth::Tikimt.as_str_am(),   "ጥቅምት");
+	/// assert_eq!(EthiopianMonth::Hidar.as_str_am(),    "ኅዳር");
+	/// assert_eq!(EthiopianMonth::Tahsas.as_str_am(),   "ታኅሣሥ");
+	/// assert_eq!(EthiopianMonth::Tir.as_str_am(),      "ጥር");
+	/// assert_eq!(EthiopianMonth::Yekatit.as_str_am(),  "የካቲት");
+	/// assert_eq!(EthiopianMonth::Megabit.as_str_am(),  "መጋቢት");
+	/// assert_eq!(EthiopianMonth::Miyazya.as_str_am(),  "ሚያዝያ");
+	/// assert_eq!(EthiopianMonth::Ginbot.as_str_am(),   "ግንቦት");
+	/// assert_eq!(EthiopianMonth::Sene.as_str_am(),     "ሰኔ");
+	/// assert_eq!(EthiopianMonth::Hamle.as_str_am(),    "ሐምሌ");
+	/// assert_eq!(EthiopianMonth::Nehase.as_str_am(),   "ነሐሴ");
+	/// assert_eq!(EthiopianMonth::Pagume.as_str_am(),   "ጳጉሜን");
+	/// ```
+	pub const fn as_str_am(self) -> &'static str {
+		match self {
+			Self::Meskerem => "መስከረም",
+			Self::Tikimt   => "ጥቅምት",
+			Self::Hidar    => "ኅዳር",
+			Self::Tahsas   => "ታኅሣሥ",
+			Self::Tir      => "ጥር",
+			Self::Yekatit  => "የካቲት",
+			Self::Megabit  => "መጋቢት",
+			Self::Miyazya  => "ሚያዝያ",
+			Self::Ginbot   => "ግንቦት",
+			Self::Sene     => "ሰኔ",
+			Self::Hamle    => "ሐምሌ",
+			Self::Nehase   => "ነሐሴ",
+			Self::Pagume   => "ጳጉሜን",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- EthiopianDate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Ethiopian calendar date, Amete Mihret (Era of Mercy)
+///
+/// 12 months of 30 days followed by [`EthiopianMonth::Pagume`],
+/// which has 6 days when `year % 4 == 3`, else 5.
+///
+/// This is the same arithmetic as [`CopticDate`](crate::CopticDate), `276` years later.
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = EthiopianDate::from_date(Date::new(2023, 10, 20));
+/// assert_eq!(date, EthiopianDate::new(2016, 2, 9));
+/// assert_eq!(date.month().as_str(), "Tikimt");
+/// assert_eq!(date.month().as_str_am(), "ጥቅምት");
+/// assert_eq!(date.to_date(), Date::new(2023, 10, 20));
+/// ```
+pub struct EthiopianDate {
+	year: i32,
+	month: EthiopianMonth,
+	day: u8,
+}
+
+impl EthiopianDate {
+	#[inline]
+	/// Create a new [`EthiopianDate`] from numbers
+	///
+	/// ## Panics
+	/// This function panics if:
+	/// - `month` is not `1..=13`
+	/// - `day` is not `1..=30`
+	/// - `day` does not exist in [`EthiopianMonth::Pagume`] of `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// // Leap year, Pagume has 6 days.
+	/// EthiopianDate::new(2015, 13, 6);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// EthiopianDate::new(2016, 13, 6);
+	/// ```
+	pub const fn new(year: i32, month: u8, day: u8) -> Self {
+		let month = EthiopianMonth::new(month);
+		assert!(day != 0, "day was 0");
+		assert!(day <= Self::days_in_month(year, month), "day does not exist in month");
+		Self { year, month, day }
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianDate::new(2016, 2, 9).year(), 2016);
+	/// ```
+	pub const fn year(self) -> i32 {
+		self.year
+	}
+
+	#[inline]
+	/// The year in the Amete Alem (Era of the World) era
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianDate::new(2016, 2, 9).year_amete_alem(), 7516);
+	/// ```
+	pub const fn year_amete_alem(self) -> i32 {
+		self.year + AMETE_ALEM_OFFSET
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianDate::new(2016, 2, 9).month(), EthiopianMonth::Tikimt);
+	/// ```
+	pub const fn month(self) -> EthiopianMonth {
+		self.month
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianDate::new(2016, 2, 9).day(), 9);
+	/// ```
+	pub const fn day(self) -> u8 {
+		self.day
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianDate::new(2016, 2, 9).inner(), (2016, 2, 9));
+	/// ```
+	pub const fn inner(self) -> (i32, u8, u8) {
+		(self.year, self.month.inner(), self.day)
+	}
+
+	#[inline]
+	/// If [`EthiopianMonth::Pagume`] has 6 days in `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert!(EthiopianDate::is_leap(2015));
+	/// assert!(!EthiopianDate::is_leap(2016));
+	/// ```
+	pub const fn is_leap(year: i32) -> bool {
+		alexandrian_is_leap(year)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianDate::days_in_month(2016, EthiopianMonth::Meskerem), 30);
+	/// assert_eq!(EthiopianDate::days_in_month(2015, EthiopianMonth::Pagume),   6);
+	/// assert_eq!(EthiopianDate::days_in_month(2016, EthiopianMonth::Pagume),   5);
+	/// ```
+	pub const fn days_in_month(year: i32, month: EthiopianMonth) -> u8 {
+		alexandrian_days_in_month(year, month.inner())
+	}
+
+	#[inline]
+	/// Convert into a Rata Die
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianDate::new(1, 1, 1).to_rata_die(),    2796);
+	/// assert_eq!(EthiopianDate::new(1938, 3, 3).to_rata_die(), 710347);
+	/// ```
+	pub const fn to_rata_die(self) -> i128 {
+		alexandrian_to_rata_die(ETHIOPIAN_EPOCH, self.year, self.month.inner(), self.day)
+	}
+
+	#[inline]
+	/// Convert a Rata Die into an [`EthiopianDate`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianDate::from_rata_die(710347), EthiopianDate::new(1938, 3, 3));
+	/// ```
+	pub const fn from_rata_die(rata_die: i128) -> Self {
+		let (year, month, day) = alexandrian_from_rata_die(ETHIOPIAN_EPOCH, rata_die);
+		// SAFETY: `month` is `1..=13`
+		Self { year, month: unsafe { EthiopianMonth::new_unchecked(month) }, day }
+	}
+
+	#[inline]
+	/// Convert a [`Date`] into an [`EthiopianDate`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianDate::from_date(Date::new(2023, 9, 11)), EthiopianDate::new(2015, 13, 6));
+	/// ```
+	pub const fn from_date(date: Date) -> Self {
+		Self::from_rata_die(date.to_rata_die())
+	}
+
+	#[inline]
+	/// Convert into a [`Date`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianDate::new(2016, 1, 1).to_date(), Date::new(2023, 9, 12));
+	/// ```
+	pub const fn to_date(self) -> Date {
+		Date::from_rata_die(self.to_rata_die())
+	}
+
+	#[inline]
+	/// The [`Date`] of Enkutatash (`1 Meskerem`) in the Gregorian `year`
+	///
+	/// This is `September 11th`, or `September 12th` before a Gregorian leap year.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(EthiopianDate::new_year(Year(2022)), Date::new(2022, 9, 11));
+	/// assert_eq!(EthiopianDate::new_year(Year(2023)), Date::new(2023, 9, 12));
+	/// assert_eq!(EthiopianDate::new_year(Year(2024)), Date::new(2024, 9, 11));
+	/// ```
+	pub const fn new_year(year: Year) -> Date {
+		Date::from_rata_die(alexandrian_to_rata_die(ETHIOPIAN_EPOCH, year.inner() as i32 - 7, 1, 1))
+	}
+}
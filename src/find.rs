@@ -0,0 +1,359 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::free::days_in_month;
+use std::io::BufRead;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Finds a [`Date`] at exactly a position, returning where it ends
+type Finder = fn(&str, usize) -> Option<(Date, usize)>;
+
+/// The [`Finder`] of each [`FindFormat`], tried in this order
+const FINDERS: [(FindFormat, Finder); 4] = [
+	(FindFormat::Iso,          crate::scan::iso_text),
+	(FindFormat::YearMonthDay, crate::scan::ymd_text),
+	(FindFormat::MonthDayYear, crate::scan::mdy_text),
+	(FindFormat::DayMonthYear, crate::scan::dmy_text),
+];
+
+//---------------------------------------------------------------------------------------------------- FindFormat
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// The shape of a date found by [`DateFinder`]
+pub enum FindFormat {
+	#[default]
+	/// `2010-12-25`, `2010/2/3`, `2010.12.25`, `2010_12_25`, `2010 12 25`
+	Iso = 1,
+	/// `2010 December 25th`, `2010.dec.25`, `2010, Dec, 25`
+	YearMonthDay = 2,
+	/// `December 25th, 2010`, `DEC-25-2010`, `Sept 3 2010`
+	MonthDayYear = 3,
+	/// `25th December 2010`, `25-Dec-2010`, `3 SEPT, 2010`
+	DayMonthYear = 4,
+}
+
+impl FindFormat {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(FindFormat::ALL.len(), 4);
+	/// ```
+	pub const ALL: [Self; 4] = [
+		Self::Iso,
+		Self::YearMonthDay,
+		Self::MonthDayYear,
+		Self::DayMonthYear,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(FindFormat::Iso.as_str(),          "ISO 8601");
+	/// assert_eq!(FindFormat::YearMonthDay.as_str(), "year month day");
+	/// assert_eq!(FindFormat::MonthDayYear.as_str(), "month day year");
+	/// assert_eq!(FindFormat::DayMonthYear.as_str(), "day month year");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Iso          => "ISO 8601",
+			Self::YearMonthDay => "year month day",
+			Self::MonthDayYear => "month day year",
+			Self::DayMonthYear => "day month year",
+		}
+	}
+
+	#[inline]
+	/// The [`FindFormat`]'s bit in [`DateFinder`]
+	const fn bit(self) -> u8 {
+		1 << (self as u8)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- DateMatch
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// A [`Date`] found by [`DateFinder`]
+pub struct DateMatch {
+	date: Date,
+	format: FindFormat,
+	start: usize,
+	end: usize,
+}
+
+impl DateMatch {
+	#[inline]
+	/// The [`Date`]
+	pub const fn date(self) -> Date {
+		self.date
+	}
+
+	#[inline]
+	/// The [`FindFormat`] the [`Date`] was written in
+	pub const fn format(self) -> FindFormat {
+		self.format
+	}
+
+	#[inline]
+	/// The byte range of the input the [`Date`] was found in
+	pub const fn span(self) -> std::ops::Range<usize> {
+		self.start..self.end
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- DateFinder
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// Find every [`Date`] in text
+///
+/// [`Date::from_str`] returns at most 1 [`Date`] and assumes the whole input is a date,
+/// this finds every date in e.g, an email or log file, and where it is.
+///
+/// Dates are found with the same recognition as [`Date::from_str`], with some care for running text:
+/// - Dates must stand on their own, `2010-12-25` is not found within `12010-12-255`
+/// - ISO 8601-like dates need 2 of the same separator, 1 of `-/._` or a space, but not `,`
+/// - Month names are only found next to a day and a 4 digit year, with up to 3 of `-/._,` or spaces between them
+/// - Dates that do not exist, e.g, `2010-02-30`, are skipped
+///
+/// Found dates do not overlap, the search continues after the end of each one.
+///
+/// ```rust
+/// # use nichi::*;
+/// let text = "Shipped 2010-12-20, arrived December 25th, 2010 (ticket #4521).";
+///
+/// let found: Vec<DateMatch> = Date::find_iter(text).collect();
+/// assert_eq!(found.len(), 2);
+///
+/// assert_eq!(found[0].date(),   Date::new(2010, 12, 20));
+/// assert_eq!(found[0].format(), FindFormat::Iso);
+/// assert_eq!(&text[found[0].span()], "2010-12-20");
+///
+/// assert_eq!(found[1].date(),   Date::new(2010, 12, 25));
+/// assert_eq!(found[1].format(), FindFormat::MonthDayYear);
+/// assert_eq!(&text[found[1].span()], "December 25th, 2010");
+///
+/// // `,` separates lists, not ISO 8601-like dates.
+/// assert_eq!(Date::find_iter("2010,12,25").count(), 0);
+/// assert_eq!(Date::find_iter("2010 12 25").count(), 1);
+/// ```
+///
+/// The formats to look for can be restricted:
+/// ```rust
+/// # use nichi::*;
+/// let text = "2010/1/2, 3 Jan 2010, 2010 Jan 4th";
+///
+/// let dates = |finder: DateFinder| -> Vec<Date> {
+/// 	finder.find_iter(text).map(|m| m.date()).collect()
+/// };
+///
+/// assert_eq!(dates(DateFinder::new()), [
+/// 	Date::new(2010, 1, 2),
+/// 	Date::new(2010, 1, 3),
+/// 	Date::new(2010, 1, 4),
+/// ]);
+/// assert_eq!(dates(DateFinder::new().formats(&[FindFormat::Iso])), [
+/// 	Date::new(2010, 1, 2),
+/// ]);
+/// assert_eq!(dates(DateFinder::new().formats(&[FindFormat::DayMonthYear, FindFormat::YearMonthDay])), [
+/// 	Date::new(2010, 1, 3),
+/// 	Date::new(2010, 1, 4),
+/// ]);
+/// ```
+pub struct DateFinder {
+	/// Bit `n` is set if [`FindFormat`] `n` is enabled
+	formats: u8,
+}
+
+impl DateFinder {
+	#[inline]
+	/// Create a new [`DateFinder`] that finds every [`FindFormat`]
+	pub const fn new() -> Self {
+		Self {
+			formats: 0,
+		}.formats(&FindFormat::ALL)
+	}
+
+	#[inline]
+	/// Only find these [`FindFormat`]s
+	pub const fn formats(mut self, formats: &[FindFormat]) -> Self {
+		self.formats = 0;
+		let mut i = 0;
+		while i < formats.len() {
+			self.formats |= formats[i].bit();
+			i += 1;
+		}
+		self
+	}
+
+	#[inline]
+	/// Iterate over every [`Date`] in `text`
+	pub const fn find_iter(self, text: &str) -> DateMatches<'_> {
+		DateMatches {
+			finder: self,
+			text,
+			pos: 0,
+		}
+	}
+
+	#[inline]
+	/// Iterate over every [`Date`] read from `reader`
+	///
+	/// Input is read 1 line at a time, so dates cannot span multiple lines.
+	///
+	/// [`DateMatch::span`] is the byte range within all of the input read, not the line.
+	///
+	/// Reading stops after the first error, this includes lines that are not valid UTF-8.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let log = b"[2010-12-24] start\n[2010-12-25] Dec 25th 2010 done\n";
+	///
+	/// let found: Vec<DateMatch> = DateFinder::new()
+	/// 	.find_read(&log[..])
+	/// 	.collect::<Result<_, _>>()
+	/// 	.unwrap();
+	///
+	/// assert_eq!(found.len(), 3);
+	/// assert_eq!(found[0].date(), Date::new(2010, 12, 24));
+	/// assert_eq!(found[1].date(), Date::new(2010, 12, 25));
+	/// assert_eq!(found[2].date(), Date::new(2010, 12, 25));
+	/// assert_eq!(&log[found[2].span()], b"Dec 25th 2010");
+	///
+	/// // Invalid UTF-8 is an error.
+	/// let mut iter = DateFinder::new().find_read(&b"\xFF 2010-12-25\n2010-12-26"[..]);
+	/// assert!(iter.next().unwrap().is_err());
+	/// assert!(iter.next().is_none());
+	/// ```
+	pub const fn find_read<R: BufRead>(self, reader: R) -> DateMatchesRead<R> {
+		DateMatchesRead {
+			finder: self,
+			reader: Some(reader),
+			line: String::new(),
+			offset: 0,
+			pos: 0,
+		}
+	}
+
+	/// The first [`Date`] starting at or after `pos`
+	fn find_at(self, text: &str, pos: usize) -> Option<DateMatch> {
+		let mut prev = text[..pos].chars().next_back();
+		for (start, c) in text[pos..].char_indices().map(|(i, c)| (i + pos, c)) {
+			let word_start = !prev.is_some_and(|p| p.is_alphanumeric() || p == '_');
+			prev = Some(c);
+			if !word_start || !c.is_ascii_alphanumeric() {
+				continue;
+			}
+
+			for (format, find) in FINDERS {
+				if self.formats & format.bit() == 0 {
+					continue;
+				}
+				let Some((date, end)) = find(text, start) else { continue };
+				let (year, month, day) = date.inner_typed();
+				if day.inner() <= days_in_month(year.inner() as i128, month).inner() {
+					return Some(DateMatch { date, format, start, end });
+				}
+			}
+		}
+
+		None
+	}
+}
+
+impl Default for DateFinder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Iterators
+#[derive(Clone,Debug)]
+/// Iterator over the [`Date`]s in a [`str`], created by [`DateFinder::find_iter`]
+pub struct DateMatches<'a> {
+	finder: DateFinder,
+	text: &'a str,
+	pos: usize,
+}
+
+impl Iterator for DateMatches<'_> {
+	type Item = DateMatch;
+
+	fn next(&mut self) -> Option<DateMatch> {
+		let found = self.finder.find_at(self.text, self.pos)?;
+		self.pos = found.end;
+		Some(found)
+	}
+}
+
+impl std::iter::FusedIterator for DateMatches<'_> {}
+
+#[derive(Debug)]
+/// Iterator over the [`Date`]s in a [`BufRead`], created by [`DateFinder::find_read`]
+pub struct DateMatchesRead<R> {
+	finder: DateFinder,
+	/// `None` after the end or an error
+	reader: Option<R>,
+	line: String,
+	/// Bytes read before `line`
+	offset: usize,
+	pos: usize,
+}
+
+impl<R: BufRead> Iterator for DateMatchesRead<R> {
+	type Item = std::io::Result<DateMatch>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(found) = self.finder.find_at(&self.line, self.pos) {
+				self.pos = found.end;
+				return Some(Ok(DateMatch {
+					start: found.start + self.offset,
+					end: found.end + self.offset,
+					..found
+				}));
+			}
+
+			let reader = self.reader.as_mut()?;
+			self.offset += self.line.len();
+			self.line.clear();
+			self.pos = 0;
+			match reader.read_line(&mut self.line) {
+				Ok(0) => self.reader = None,
+				Ok(_) => (),
+				Err(e) => {
+					self.reader = None;
+					return Some(Err(e));
+				},
+			}
+		}
+	}
+}
+
+impl<R: BufRead> std::iter::FusedIterator for DateMatchesRead<R> {}
+
+//---------------------------------------------------------------------------------------------------- Date
+impl Date {
+	#[inline]
+	/// Iterate over every [`Date`] in `text`, see [`DateFinder`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let text = "Opened 2023-11-10, closed 12 Nov 2023.";
+	/// let dates: Vec<Date> = Date::find_iter(text).map(|m| m.date()).collect();
+	/// assert_eq!(dates, [Date::new(2023, 11, 10), Date::new(2023, 11, 12)]);
+	/// ```
+	pub const fn find_iter(text: &str) -> DateMatches<'_> {
+		DateFinder::new().find_iter(text)
+	}
+
+	#[inline]
+	/// Iterate over every [`Date`] read from `reader`, see [`DateFinder::find_read`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let reader = std::io::BufReader::new(&b"2023-11-10\n2023-11-11\n"[..]);
+	/// assert_eq!(Date::find_read(reader).count(), 2);
+	/// ```
+	pub const fn find_read<R: BufRead>(reader: R) -> DateMatchesRead<R> {
+		DateFinder::new().find_read(reader)
+	}
+}
@@ -0,0 +1,255 @@
+//---------------------------------------------------------------------------------------------------- Use
+use std::fmt::{Display,Formatter,Write};
+use crate::date::Date;
+use crate::locale::{Locale,NameWidth};
+
+//---------------------------------------------------------------------------------------------------- DateFormat
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// A [`Date`] formatted with a `strftime`-style pattern, returned from [`Date::format`]
+///
+/// This implements [`Display`] and does not allocate.
+///
+/// ## Specifiers
+/// | Specifier | Output | Example |
+/// |-----------|--------|---------|
+/// | `%Y` | Year, at least 4 digits | `2023`
+/// | `%C` | Century, at least 2 digits | `20`
+/// | `%y` | Year within the century, 2 digits | `23`
+/// | `%L` | Historical year, never `0` or negative, see [`HistoricalYear`](crate::HistoricalYear) | `2023`
+/// | `%E` | Historical era, `BCE` or `CE` | `CE`
+/// | `%K` | Historical era, `BC` or `AD` | `AD`
+/// | `%m` | Month, 2 digits | `10`
+/// | `%B` | Month name, see [`DateFormat::locale`] | `October`
+/// | `%b`, `%h` | Abbreviated month name | `Oct`
+/// | `%d` | Day, 2 digits | `05`
+/// | `%e` | Day, padded with a space | ` 5`
+/// | `%o` | Day as an ordinal, see [`Locale::ordinal`] | `5th`
+/// | `%A` | Weekday name | `Thursday`
+/// | `%a` | Abbreviated weekday name | `Thu`
+/// | `%u` | Weekday, Monday is `1`, Sunday is `7` | `4`
+/// | `%w` | Weekday, Sunday is `0`, Saturday is `6` | `4`
+/// | `%j` | Day of the year, 3 digits | `278`
+/// | `%U` | Week of the year, starting on the first Sunday, 2 digits | `40`
+/// | `%W` | Week of the year, starting on the first Monday, 2 digits | `40`
+/// | `%G` | ISO 8601 week-based year, at least 4 digits | `2023`
+/// | `%g` | ISO 8601 week-based year within the century, 2 digits | `23`
+/// | `%V` | ISO 8601 week, 2 digits | `40`
+/// | `%F` | Same as `%Y-%m-%d` | `2023-10-05`
+/// | `%D` | Same as `%m/%d/%y` | `10/05/23`
+/// | `%%` | `%` | `%`
+/// | `%n` | Newline | `\n`
+/// | `%t` | Tab | `\t`
+///
+/// ## Modifiers
+/// These go between the `%` and the specifier, e.g, `%-d`.
+///
+/// | Modifier | Output | Example |
+/// |----------|--------|---------|
+/// | `-` | Do not pad numbers | `%-d` = `5`
+/// | `_` | Pad numbers with spaces | `%_m` = ` 1`
+/// | `0` | Pad numbers with zeros | `%0e` = `05`
+/// | `^` | Uppercase names | `%^B` = `OCTOBER`
+/// | `J` | Japanese names, days and months | `%JA` = `木曜日`, `%JB` = `十月`, `%Jd` = `五日`
+///
+/// `%Ja` is the 1 character weekday, e.g, `木`.
+///
+/// Unknown specifiers are written as they are.
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = Date::new(2023, 10, 5);
+/// assert_eq!(date.format("%Y-%m-%d").to_string(),       "2023-10-05");
+/// assert_eq!(date.format("%A, %B %o, %Y").to_string(),  "Thursday, October 5th, 2023");
+/// assert_eq!(date.format("%a %b %e %Y").to_string(),    "Thu Oct  5 2023");
+/// assert_eq!(date.format("%^a %-m/%-d").to_string(),    "THU 10/5");
+/// assert_eq!(date.format("%G-W%V-%u").to_string(),      "2023-W40-4");
+/// assert_eq!(date.format("%j %U %W").to_string(),       "278 40 40");
+/// assert_eq!(date.format("%Y年%-m月%-d日(%Ja)").to_string(), "2023年10月5日(木)");
+/// assert_eq!(date.format("%JB%Jd %JA").to_string(),     "十月五日 木曜日");
+/// assert_eq!(date.format("100%% %Q").to_string(),       "100% %Q");
+///
+/// // Years before 1 are astronomical in `%Y`, historical in `%L`.
+/// let ides = Date::new(-43, 3, 15);
+/// assert_eq!(ides.format("%F").to_string(),             "-0043-03-15");
+/// assert_eq!(ides.format("%B %-d, %L %K").to_string(),  "March 15, 44 BC");
+/// assert_eq!(ides.format("%L %E").to_string(),          "44 BCE");
+/// assert_eq!(Date::new(0, 1, 1).format("%L %E").to_string(), "1 BCE");
+/// assert_eq!(Date::new(14, 8, 19).format("%K %L, %Y").to_string(), "AD 14, 0014");
+///
+/// // No allocation is needed to write it somewhere.
+/// use std::fmt::Write;
+/// let mut buf = String::with_capacity(16);
+/// write!(buf, "{}", date.format("%F")).unwrap();
+/// assert_eq!(buf, "2023-10-05");
+/// ```
+pub struct DateFormat<'a> {
+	date: Date,
+	pattern: &'a str,
+	locale: &'a Locale,
+}
+
+impl<'a> DateFormat<'a> {
+	#[inline]
+	/// Create a new [`DateFormat`], same as [`Date::format`]
+	pub const fn new(date: Date, pattern: &'a str) -> Self {
+		Self { date, pattern, locale: &Locale::EN }
+	}
+
+	#[inline]
+	/// The [`Locale`] of month names, weekday names and ordinals, [`Locale::EN`] by default
+	///
+	/// The `J` modifier always uses Japanese.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let date = Date::new(2023, 10, 1);
+	/// assert_eq!(date.format("%A %-d %B %Y").locale(&Locale::FR).to_string(), "dimanche 1 octobre 2023");
+	/// assert_eq!(date.format("%a, %o %b").locale(&Locale::FR).to_string(),    "dim., 1er oct.");
+	/// assert_eq!(date.format("%A, %-d. %B").locale(&Locale::DE).to_string(),  "Sonntag, 1. Oktober");
+	/// assert_eq!(date.format("%^B").locale(&Locale::ES).to_string(),          "OCTUBRE");
+	/// assert_eq!(date.format("%Y년 %B %-d일 %A").locale(&Locale::KO).to_string(), "2023년 10월 1일 일요일");
+	/// ```
+	pub const fn locale(mut self, locale: &'a Locale) -> Self {
+		self.locale = locale;
+		self
+	}
+
+	#[inline]
+	/// The [`Date`] being formatted
+	pub const fn date(self) -> Date {
+		self.date
+	}
+
+	#[inline]
+	/// The pattern
+	pub const fn pattern(self) -> &'a str {
+		self.pattern
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Pad
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+/// How numbers are padded
+enum Pad {
+	None,
+	Space,
+	Zero,
+}
+
+/// Write `n` padded to `width`, the sign does not count towards the width
+fn write_number(f: &mut Formatter<'_>, n: i64, width: usize, pad: Pad) -> std::fmt::Result {
+	if n < 0 {
+		f.write_char('-')?;
+	}
+	let n = n.unsigned_abs();
+	match pad {
+		Pad::None  => write!(f, "{n}"),
+		Pad::Space => write!(f, "{n:width$}"),
+		Pad::Zero  => write!(f, "{n:0width$}"),
+	}
+}
+
+/// Write `name`, in uppercase if `upper`
+fn write_name(f: &mut Formatter<'_>, name: &str, upper: bool) -> std::fmt::Result {
+	if !upper {
+		return f.write_str(name);
+	}
+	for c in name.chars().flat_map(char::to_uppercase) {
+		f.write_char(c)?;
+	}
+	Ok(())
+}
+
+//---------------------------------------------------------------------------------------------------- Display
+impl Display for DateFormat<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let date = self.date;
+		let (year, month, day) = date.inner_typed();
+		let weekday = date.weekday();
+		let historical = year.to_historical();
+		let year = year.inner() as i64;
+		let locale = self.locale;
+
+		let mut rest = self.pattern;
+		while let Some(index) = rest.find('%') {
+			f.write_str(&rest[..index])?;
+			let spec = &rest[index..];
+
+			// Modifiers.
+			let mut pad = None;
+			let mut upper = false;
+			let mut japanese = false;
+			let mut chars = spec[1..].char_indices();
+			let (end, c) = loop {
+				match chars.next() {
+					Some((_, '-')) => pad = Some(Pad::None),
+					Some((_, '_')) => pad = Some(Pad::Space),
+					Some((_, '0')) => pad = Some(Pad::Zero),
+					Some((_, '^')) => upper = true,
+					Some((_, 'J')) => japanese = true,
+					Some((i, c))   => break (1 + i + c.len_utf8(), Some(c)),
+					None           => break (spec.len(), None),
+				}
+			};
+			rest = &spec[end..];
+
+			let number = |f: &mut Formatter<'_>, n: i64, width: usize, default: Pad| {
+				write_number(f, n, width, pad.unwrap_or(default))
+			};
+
+			match (c, japanese) {
+				(Some('A'), true) => f.write_str(weekday.as_str_jp())?,
+				(Some('a'), true) => f.write_char(weekday.as_str_jp().chars().next().unwrap())?,
+				(Some('B' | 'b' | 'h'), true) => f.write_str(month.as_str_jp())?,
+				(Some('d' | 'e'), true) => f.write_str(day.as_str_jp())?,
+
+				(Some('Y'), _) => number(f, year, 4, Pad::Zero)?,
+				(Some('C'), _) => number(f, year.div_euclid(100), 2, Pad::Zero)?,
+				(Some('y'), _) => number(f, year.rem_euclid(100), 2, Pad::Zero)?,
+				(Some('L'), _) => number(f, historical.year() as i64, 1, Pad::None)?,
+				(Some('E'), _) => f.write_str(historical.era().as_str())?,
+				(Some('K'), _) => f.write_str(historical.era().as_str_anno_domini())?,
+				(Some('m'), _) => number(f, month.inner() as i64, 2, Pad::Zero)?,
+				(Some('B'), _) => write_name(f, locale.month(month, NameWidth::Wide), upper)?,
+				(Some('b' | 'h'), _) => write_name(f, locale.month(month, NameWidth::Abbreviated), upper)?,
+				(Some('d'), _) => number(f, day.inner() as i64, 2, Pad::Zero)?,
+				(Some('e'), _) => number(f, day.inner() as i64, 2, Pad::Space)?,
+				(Some('o'), _) => {
+					let (prefix, suffix) = locale.ordinal_affixes(day.inner() as u32);
+					write_name(f, prefix, upper)?;
+					write!(f, "{}", day.inner())?;
+					write_name(f, suffix, upper)?;
+				},
+				(Some('A'), _) => write_name(f, locale.weekday(weekday, NameWidth::Wide), upper)?,
+				(Some('a'), _) => write_name(f, locale.weekday(weekday, NameWidth::Abbreviated), upper)?,
+				(Some('u'), _) => number(f, date.weekday_iso() as i64, 1, Pad::None)?,
+				(Some('w'), _) => number(f, weekday.inner() as i64 - 1, 1, Pad::None)?,
+				(Some('j'), _) => number(f, date.day_of_year() as i64, 3, Pad::Zero)?,
+				(Some('U'), _) => {
+					let sunday = weekday.inner() as i64 - 1;
+					number(f, (date.day_of_year() as i64 + 6 - sunday) / 7, 2, Pad::Zero)?;
+				},
+				(Some('W'), _) => {
+					let monday = date.weekday_iso() as i64 - 1;
+					number(f, (date.day_of_year() as i64 + 6 - monday) / 7, 2, Pad::Zero)?;
+				},
+				(Some('G'), _) => number(f, date.iso_week().0.inner() as i64, 4, Pad::Zero)?,
+				(Some('g'), _) => number(f, (date.iso_week().0.inner() as i64).rem_euclid(100), 2, Pad::Zero)?,
+				(Some('V'), _) => number(f, date.iso_week().1 as i64, 2, Pad::Zero)?,
+				(Some('F'), _) => {
+					number(f, year, 4, Pad::Zero)?;
+					write!(f, "-{:02}-{:02}", month.inner(), day.inner())?;
+				},
+				(Some('D'), _) => write!(f, "{:02}/{:02}/{:02}", month.inner(), day.inner(), year.rem_euclid(100))?,
+				(Some('%'), _) => f.write_char('%')?,
+				(Some('n'), _) => f.write_char('\n')?,
+				(Some('t'), _) => f.write_char('\t')?,
+
+				// Unknown, write it as is.
+				_ => f.write_str(&spec[..end])?,
+			}
+		}
+
+		f.write_str(rest)
+	}
+}
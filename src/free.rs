@@ -21,12 +21,33 @@ pub const fn is_leap(year: i128) -> bool {
 	year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
 }
 
+#[inline]
+/// If `year` is a leap year in the Revised Julian calendar
+///
+/// Century years are only leap years if `year % 900` is `200` or `600`,
+/// so the first disagreement with [`is_leap`] is `2800`.
+///
+/// ```rust
+/// # use nichi::*;
+/// assert!(is_leap_revised_julian(2000));
+/// assert!(is_leap_revised_julian(2024));
+/// assert!(!is_leap_revised_julian(2100));
+///
+/// assert!(is_leap(2800));
+/// assert!(!is_leap_revised_julian(2800));
+/// assert!(!is_leap(2900));
+/// assert!(is_leap_revised_julian(2900));
+/// ```
+pub const fn is_leap_revised_julian(year: i128) -> bool {
+	year % 4 == 0 && (year % 100 != 0 || matches!(year.rem_euclid(900), 200 | 600))
+}
+
 #[inline]
 /// Get the last day of a month
 ///
 /// Works with any year within `i128` unlike [`Year::days_in_month`]
 ///
-/// A negative `year` represents BCE years, e.g, `-1` is `1 BCE`.
+/// Years use astronomical numbering, `0` is `1 BCE` and `-1` is `2 BCE`, see [`HistoricalYear`](crate::HistoricalYear).
 ///
 /// ```rust
 /// # use nichi::*;
@@ -59,4 +80,121 @@ pub const fn days_in_month(year: i128, month: Month) -> DaysInMonth {
 			DaysInMonth::TwentyEight
 		}
 	}
-}
\ No newline at end of file
+}
+
+#[inline]
+/// Convert a calendar date into days relative to the `UNIX_EPOCH`
+///
+/// Works with any year within `i128` unlike [`Date::as_unix`],
+/// day counts that do not fit in `i128` saturate at `i128::MIN` and `i128::MAX`.
+///
+/// Dates use the proleptic Gregorian calendar and
+/// astronomical year numbering, i.e, there is a year `0`.
+///
+/// This is the reverse of [`civil_from_days`].
+///
+/// ```rust
+/// # use nichi::*;
+/// assert_eq!(days_from_civil(1970, Month::January, Day::First), 0);
+/// assert_eq!(days_from_civil(1970, Month::January, Day::Second), 1);
+/// assert_eq!(days_from_civil(1969, Month::December, Day::ThirtyFirst), -1);
+/// assert_eq!(days_from_civil(2023, Month::October, Day::Twentieth), 19650);
+///
+/// // Far outside of `Year`'s range.
+/// assert_eq!(days_from_civil(1_000_000, Month::January, Day::First), 364_522_972);
+///
+/// // The ends of `i128`.
+/// let (year, month, day) = civil_from_days(i128::MAX);
+/// assert_eq!(days_from_civil(year, month, day), i128::MAX);
+/// assert_eq!(days_from_civil(year + 1, month, day), i128::MAX);
+/// let (year, month, day) = civil_from_days(i128::MIN);
+/// assert_eq!(days_from_civil(year, month, day), i128::MIN);
+/// assert_eq!(days_from_civil(year - 1, month, day), i128::MIN);
+/// assert_eq!(days_from_civil(i128::MAX, Month::December, Day::ThirtyFirst), i128::MAX);
+/// assert_eq!(days_from_civil(i128::MIN, Month::January, Day::First), i128::MIN);
+/// ```
+///
+/// ## Algorithm
+/// <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+pub const fn days_from_civil(year: i128, month: Month, day: Day) -> i128 {
+	let month = month.inner() as i128;
+	let day   = day.inner() as i128;
+
+	// `i128::MIN` in January or February saturates below anyway.
+	let year = if month <= 2 {
+		year.saturating_sub(1)
+	} else {
+		year
+	};
+
+	let era: i128 = year.div_euclid(400);
+	let yoe: i128 = year.rem_euclid(400);
+	let doy: i128 = (153 * if month > 2 { month - 3 } else { month + 9 } + 2) / 5 + day - 1;
+	let doe: i128 = yoe * 365 + yoe/4 - yoe/100 + doy;
+
+	// `era * 146097 + doe - 719468`, ordered so that `era * 146097`
+	// only overflows if the result does.
+	let (era, doe) = if era > 0 {
+		(era - 5, doe + 5 * 146097)
+	} else {
+		(era, doe)
+	};
+	match era.checked_mul(146097) {
+		Some(days) => days.saturating_add(doe - 719468),
+		None if era < 0 => i128::MIN,
+		None => i128::MAX,
+	}
+}
+
+#[inline]
+/// Convert days relative to the `UNIX_EPOCH` into a calendar date
+///
+/// Works with any day within `i128` unlike [`Date::from_unix`].
+///
+/// The returned `(year, month, day)` uses the proleptic Gregorian calendar
+/// and astronomical year numbering, i.e, there is a year `0`.
+///
+/// This is the reverse of [`days_from_civil`].
+///
+/// ```rust
+/// # use nichi::*;
+/// assert_eq!(civil_from_days(0),     (1970, Month::January, Day::First));
+/// assert_eq!(civil_from_days(-1),    (1969, Month::December, Day::ThirtyFirst));
+/// assert_eq!(civil_from_days(19650), (2023, Month::October, Day::Twentieth));
+///
+/// let days = days_from_civil(-1_000_000, Month::February, Day::TwentyNinth);
+/// assert_eq!(civil_from_days(days), (-1_000_000, Month::February, Day::TwentyNinth));
+///
+/// // The ends of `i128`.
+/// assert_eq!(civil_from_days(i128::MAX).0, 465_830_738_373_735_892_541_769_656_367_714_122);
+/// assert_eq!(civil_from_days(i128::MIN).0, -465_830_738_373_735_892_541_769_656_367_710_183);
+/// ```
+///
+/// ## Algorithm
+/// <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+pub const fn civil_from_days(days: i128) -> (i128, Month, Day) {
+	// `z = days + 719468` split into eras first, so it never overflows.
+	let z:   i128 = days.rem_euclid(146097) + 719468;
+	let era: i128 = days.div_euclid(146097) + z.div_euclid(146097);
+	let doe: i128 = z.rem_euclid(146097);
+	let yoe: i128 = (doe - doe/1460 + doe/36524 - doe/146096) / 365;
+	let y:   i128 = yoe + era * 400;
+	let doy: i128 = doe - (365*yoe + yoe/4 - yoe/100);
+	let mp:  i128 = (5*doy + 2)/153;
+	let d:   u8   = (doy - (153*mp+2)/5 + 1) as u8;
+	let m:   u8   = (if mp < 10 { mp + 3 } else { mp - 9 }) as u8;
+
+	debug_assert!(m != 0);
+	debug_assert!(m < 13);
+	debug_assert!(d != 0);
+	debug_assert!(d < 32);
+
+	let y = if m <= 2 {
+		y + 1
+	} else {
+		y
+	};
+
+	// SAFETY: algorithm above is assumed to not produce invalid values
+	unsafe { (y, Month::new_unchecked(m), Day::new_unchecked(d)) }
+}
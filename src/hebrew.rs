@@ -0,0 +1,716 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+use crate::weekday::Weekday;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Rata Die of `1 Tishrei 1 AM`, `October 7th, 3761 BCE` (Julian)
+const HEBREW_EPOCH: i128 = -1373427;
+
+//---------------------------------------------------------------------------------------------------- HebrewMonth
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Month in a Hebrew year
+///
+/// Months are numbered from [`HebrewMonth::Nisan`], although the year starts at [`HebrewMonth::Tishrei`].
+///
+/// In leap years [`HebrewMonth::Adar`] is `Adar I`, and is followed by [`HebrewMonth::AdarII`].
+#[allow(missing_docs)]
+pub enum HebrewMonth {
+	#[default]
+	Nisan    = 1,
+	Iyar     = 2,
+	Sivan    = 3,
+	Tammuz   = 4,
+	Av       = 5,
+	Elul     = 6,
+	Tishrei  = 7,
+	Cheshvan = 8,
+	Kislev   = 9,
+	Tevet    = 10,
+	Shevat   = 11,
+	Adar     = 12,
+	AdarII   = 13,
+}
+
+impl HebrewMonth {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewMonth::ALL[0],  HebrewMonth::Nisan);
+	/// assert_eq!(HebrewMonth::ALL[6],  HebrewMonth::Tishrei);
+	/// assert_eq!(HebrewMonth::ALL[12], HebrewMonth::AdarII);
+	/// ```
+	pub const ALL: [Self; 13] = [
+		Self::Nisan,
+		Self::Iyar,
+		Self::Sivan,
+		Self::Tammuz,
+		Self::Av,
+		Self::Elul,
+		Self::Tishrei,
+		Self::Cheshvan,
+		Self::Kislev,
+		Self::Tevet,
+		Self::Shevat,
+		Self::Adar,
+		Self::AdarII,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewMonth::new(1),  HebrewMonth::Nisan);
+	/// assert_eq!(HebrewMonth::new(7),  HebrewMonth::Tishrei);
+	/// assert_eq!(HebrewMonth::new(13), HebrewMonth::AdarII);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// HebrewMonth::new(14);
+	/// ```
+	pub const fn new(month: u8) -> Self {
+		assert!(month != 0, "month must not be 0");
+		assert!(month < 14, "month must not be > 13");
+		// SAFETY: repr(u8)
+		unsafe { Self::new_unchecked(month) }
+	}
+
+	#[inline]
+	/// ## Safety
+	/// `month` must be `1..=13`.
+	pub const unsafe fn new_unchecked(month: u8) -> Self {
+		debug_assert!(month != 0, "month must not be 0");
+		debug_assert!(month < 14, "month must not be > 13");
+		// SAFETY: repr(u8)
+		std::mem::transmute(month)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewMonth::Nisan.inner(),  1);
+	/// assert_eq!(HebrewMonth::AdarII.inner(), 13);
+	/// ```
+	pub const fn inner(self) -> u8 {
+		// SAFETY: repr(u8)
+		unsafe { std::mem::transmute(self) }
+	}
+
+	#[inline]
+	/// Transliterated name of the month
+	///
+	/// See [`HebrewDate::month_as_str`] for the leap year aware `Adar I`.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewMonth::Nisan.as_str(),    "Nisan");
+	/// assert_eq!(HebrewMonth::Iyar.as_str(),     "Iyar");
+	/// assert_eq!(HebrewMonth::Sivan.as_str(),    "Sivan");
+	/// assert_eq!(HebrewMonth::Tammuz.as_str(),   "Tammuz");
+	/// assert_eq!(HebrewMonth::Av.as_str(),       "Av");
+	/// assert_eq!(HebrewMonth::Elul.as_str(),     "Elul");
+	/// assert_eq!(HebrewMonth::Tishrei.as_str(),  "Tishrei");
+	/// assert_eq!(HebrewMonth::Cheshvan.as_str(), "Cheshvan");
+	/// assert_eq!(HebrewMonth::Kislev.as_str(),   "Kislev");
+	/// assert_eq!(HebrewMonth::Tevet.as_str(),    "Tevet");
+	/// assert_eq!(HebrewMonth::Shevat.as_str(),   "Shevat");
+	/// assert_eq!(HebrewMonth::Adar.as_str(),     "Adar");
+	/// assert_eq!(HebrewMonth::AdarII.as_str(),   "Adar II");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Nisan    => "Nisan",
+			Self::Iyar     => "Iyar",
+			Self::Sivan    => "Sivan",
+			Self::Tammuz   => "Tammuz",
+			Self::Av       => "Av",
+			Self::Elul     => "Elul",
+			Self::Tishrei  => "Tishrei",
+			Self::Cheshvan => "Cheshvan",
+			Self::Kislev   => "Kislev",
+			Self::Tevet    => "Tevet",
+			Self::Shevat   => "Shevat",
+			Self::Adar     => "Adar",
+			Self::AdarII   => "Adar II",
+		}
+	}
+
+	#[inline]
+	/// Hebrew name of the month
+	///
+	/// See [`HebrewDate::month_as_str_he`] for the leap year aware `Adar I`.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewMonth::Nisan.as_str_he(),    "ניסן");
+	/// assert_eq!(HebrewMonth::Iyar.as_str_he(),     "אייר");
+	/// assert_eq!(HebrewMonth::Sivan.as_str_he(),    "סיון");
+	/// assert_eq!(HebrewMonth::Tammuz.as_str_he(),   "תמוז");
+	/// assert_eq!(HebrewMonth::Av.as_str_he(),       "אב");
+	/// assert_eq!(HebrewMonth::Elul.as_str_he(),     "אלול");
+	/// assert_eq!(HebrewMonth::Tishrei.as_str_he(),  "תשרי");
+	/// assert_eq!(HebrewMonth::Cheshvan.as_str_he(), "חשוון");
+	/// assert_eq!(HebrewMonth::Kislev.as_str_he(),   "כסלו");
+	/// assert_eq!(HebrewMonth::Tevet.as_str_he(),    "טבת");
+	/// assert_eq!(HebrewMonth::Shevat.as_str_he(),   "שבט");
+	/// assert_eq!(HebrewMonth::Adar.as_str_he(),     "אדר");
+	/// assert_eq!(HebrewMonth::AdarII.as_str_he(),   "אדר ב׳");
+	/// ```
+	pub const fn as_str_he(self) -> &'static str {
+		match self {
+			Self::Nisan    => "ניסן",
+			Self::Iyar     => "אייר",
+			Self::Sivan    => "סיון",
+			Self::Tammuz   => "תמוז",
+			Self::Av       => "אב",
+			Self::Elul     => "אלול",
+			Self::Tishrei  => "תשרי",
+			Self::Cheshvan => "חשוון",
+			Self::Kislev   => "כסלו",
+			Self::Tevet    => "טבת",
+			Self::Shevat   => "שבט",
+			Self::Adar     => "אדר",
+			Self::AdarII   => "אדר ב׳",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- HebrewYearKind
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Length of a Hebrew year, ignoring the leap month
+pub enum HebrewYearKind {
+	/// `353` or `383` days, [`HebrewMonth::Cheshvan`] and [`HebrewMonth::Kislev`] have `29` days
+	Deficient,
+	#[default]
+	/// `354` or `384` days, [`HebrewMonth::Cheshvan`] has `29` days and [`HebrewMonth::Kislev`] has `30` days
+	Regular,
+	/// `355` or `385` days, [`HebrewMonth::Cheshvan`] and [`HebrewMonth::Kislev`] have `30` days
+	Complete,
+}
+
+//---------------------------------------------------------------------------------------------------- HebrewYear
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Hebrew year, Anno Mundi
+pub struct HebrewYear(pub i32);
+
+impl HebrewYear {
+	#[inline]
+	/// The Hebrew year that starts in the autumn of this [`Year`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewYear::starting_in(Year(2023)), HebrewYear(5784));
+	/// ```
+	pub const fn starting_in(year: Year) -> Self {
+		Self(year.inner() as i32 + 3761)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewYear(5784).inner(), 5784);
+	/// ```
+	pub const fn inner(self) -> i32 {
+		self.0
+	}
+
+	#[inline]
+	/// If this year has 13 months
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert!(!HebrewYear(5783).is_leap());
+	/// assert!(HebrewYear(5784).is_leap());
+	/// assert!(!HebrewYear(5785).is_leap());
+	/// ```
+	pub const fn is_leap(self) -> bool {
+		(7 * self.0 as i64 + 1).rem_euclid(19) < 7
+	}
+
+	#[inline]
+	/// The last month of this year, [`HebrewMonth::AdarII`] in leap years
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewYear(5783).last_month(), HebrewMonth::Adar);
+	/// assert_eq!(HebrewYear(5784).last_month(), HebrewMonth::AdarII);
+	/// ```
+	pub const fn last_month(self) -> HebrewMonth {
+		if self.is_leap() {
+			HebrewMonth::AdarII
+		} else {
+			HebrewMonth::Adar
+		}
+	}
+
+	/// Days from the epoch until the molad of Tishrei, with the first postponement rule.
+	const fn elapsed_days(year: i64) -> i64 {
+		let months = (235 * year - 234).div_euclid(19);
+		let parts  = 12084 + 13753 * months;
+		let days   = 29 * months + parts.div_euclid(25920);
+
+		// Molad Zaken / Lo ADU Rosh.
+		if (3 * (days + 1)).rem_euclid(7) < 3 {
+			days + 1
+		} else {
+			days
+		}
+	}
+
+	/// Further postponements required to keep year lengths valid.
+	const fn year_length_correction(year: i64) -> i64 {
+		let ny0 = Self::elapsed_days(year - 1);
+		let ny1 = Self::elapsed_days(year);
+		let ny2 = Self::elapsed_days(year + 1);
+
+		if ny2 - ny1 == 356 {
+			2
+		} else if ny1 - ny0 == 382 {
+			1
+		} else {
+			0
+		}
+	}
+
+	#[inline]
+	/// Rata Die of `1 Tishrei` (Rosh Hashanah)
+	const fn new_year_rata_die(year: i64) -> i128 {
+		HEBREW_EPOCH + (Self::elapsed_days(year) + Self::year_length_correction(year)) as i128
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewYear(5783).days_in_year(), 355);
+	/// assert_eq!(HebrewYear(5784).days_in_year(), 383);
+	/// assert_eq!(HebrewYear(5785).days_in_year(), 355);
+	/// ```
+	pub const fn days_in_year(self) -> u16 {
+		let year = self.0 as i64;
+		(Self::new_year_rata_die(year + 1) - Self::new_year_rata_die(year)) as u16
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewYear(5783).kind(), HebrewYearKind::Complete);
+	/// assert_eq!(HebrewYear(5784).kind(), HebrewYearKind::Deficient);
+	/// assert_eq!(HebrewYear(5786).kind(), HebrewYearKind::Regular);
+	/// ```
+	pub const fn kind(self) -> HebrewYearKind {
+		match self.days_in_year() % 10 {
+			3 => HebrewYearKind::Deficient,
+			5 => HebrewYearKind::Complete,
+			_ => HebrewYearKind::Regular,
+		}
+	}
+
+	#[inline]
+	/// Days in `month` of this year
+	///
+	/// [`HebrewMonth::AdarII`] returns `0` in non-leap years.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewYear(5784).days_in_month(HebrewMonth::Tishrei),  30);
+	/// assert_eq!(HebrewYear(5784).days_in_month(HebrewMonth::Cheshvan), 29);
+	/// assert_eq!(HebrewYear(5784).days_in_month(HebrewMonth::Kislev),   29);
+	/// assert_eq!(HebrewYear(5784).days_in_month(HebrewMonth::Adar),     30);
+	/// assert_eq!(HebrewYear(5784).days_in_month(HebrewMonth::AdarII),   29);
+	/// assert_eq!(HebrewYear(5783).days_in_month(HebrewMonth::Adar),     29);
+	/// assert_eq!(HebrewYear(5783).days_in_month(HebrewMonth::AdarII),   0);
+	/// ```
+	pub const fn days_in_month(self, month: HebrewMonth) -> u8 {
+		use HebrewMonth as M;
+		match month {
+			M::Iyar | M::Tammuz | M::Elul | M::Tevet => 29,
+			M::AdarII => if self.is_leap() { 29 } else { 0 },
+			M::Adar => if self.is_leap() { 30 } else { 29 },
+			M::Cheshvan => if matches!(self.kind(), HebrewYearKind::Complete) { 30 } else { 29 },
+			M::Kislev => if matches!(self.kind(), HebrewYearKind::Deficient) { 29 } else { 30 },
+			_ => 30,
+		}
+	}
+
+	#[inline]
+	/// The [`Date`] of `1 Tishrei` (Rosh Hashanah) of this year
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewYear(5784).new_year(), Date::new(2023, 9, 16));
+	/// assert_eq!(HebrewYear(5785).new_year(), Date::new(2024, 10, 3));
+	/// ```
+	pub const fn new_year(self) -> Date {
+		Date::from_rata_die(Self::new_year_rata_die(self.0 as i64))
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- HebrewDate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,Eq,Hash)]
+/// Hebrew calendar date
+///
+/// The new year starts on `1 Tishrei` at the molad (mean new moon),
+/// postponed according to the dehiyyot rules.
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = HebrewDate::from_date(Date::new(2023, 10, 20));
+/// assert_eq!(date, HebrewDate::new(5784, HebrewMonth::Cheshvan, 5));
+/// assert_eq!(date.month_as_str(), "Cheshvan");
+/// assert_eq!(date.to_date(), Date::new(2023, 10, 20));
+/// ```
+pub struct HebrewDate {
+	year: HebrewYear,
+	month: HebrewMonth,
+	day: u8,
+}
+
+impl HebrewDate {
+	#[inline]
+	/// Create a new [`HebrewDate`]
+	///
+	/// ## Panics
+	/// This function panics if `day` is not `1..=30`.
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// HebrewDate::new(5784, HebrewMonth::Nisan, 31);
+	/// ```
+	pub const fn new(year: i32, month: HebrewMonth, day: u8) -> Self {
+		assert!(day != 0, "day was 0");
+		assert!(day < 31, "day was greater than 30");
+		Self { year: HebrewYear(year), month, day }
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewDate::new(5784, HebrewMonth::Nisan, 15).year(), HebrewYear(5784));
+	/// ```
+	pub const fn year(self) -> HebrewYear {
+		self.year
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewDate::new(5784, HebrewMonth::Nisan, 15).month(), HebrewMonth::Nisan);
+	/// ```
+	pub const fn month(self) -> HebrewMonth {
+		self.month
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewDate::new(5784, HebrewMonth::Nisan, 15).day(), 15);
+	/// ```
+	pub const fn day(self) -> u8 {
+		self.day
+	}
+
+	#[inline]
+	/// Transliterated month name, `Adar I` for [`HebrewMonth::Adar`] in leap years
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewDate::new(5783, HebrewMonth::Adar, 1).month_as_str(),   "Adar");
+	/// assert_eq!(HebrewDate::new(5784, HebrewMonth::Adar, 1).month_as_str(),   "Adar I");
+	/// assert_eq!(HebrewDate::new(5784, HebrewMonth::AdarII, 1).month_as_str(), "Adar II");
+	/// ```
+	pub const fn month_as_str(self) -> &'static str {
+		match self.month {
+			HebrewMonth::Adar if self.year.is_leap() => "Adar I",
+			m => m.as_str(),
+		}
+	}
+
+	#[inline]
+	/// Hebrew month name, `אדר א׳` for [`HebrewMonth::Adar`] in leap years
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewDate::new(5783, HebrewMonth::Adar, 1).month_as_str_he(), "אדר");
+	/// assert_eq!(HebrewDate::new(5784, HebrewMonth::Adar, 1).month_as_str_he(), "אדר א׳");
+	/// ```
+	pub const fn month_as_str_he(self) -> &'static str {
+		match self.month {
+			HebrewMonth::Adar if self.year.is_leap() => "אדר א׳",
+			m => m.as_str_he(),
+		}
+	}
+
+	/// Convert into a Rata Die
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewDate::new(5706, HebrewMonth::Kislev, 7).to_rata_die(), 710347);
+	/// ```
+	pub const fn to_rata_die(self) -> i128 {
+		let year = self.year;
+		let mut day = HebrewYear::new_year_rata_die(year.0 as i64) + self.day as i128 - 1;
+
+		let month = self.month.inner();
+		let mut m = HebrewMonth::Tishrei.inner();
+		// Months before `Nisan` belong to the start of the year.
+		if month < m {
+			let last = year.last_month().inner();
+			while m <= last {
+				// SAFETY: `m` is `7..=13`
+				day += year.days_in_month(unsafe { HebrewMonth::new_unchecked(m) }) as i128;
+				m += 1;
+			}
+			m = 1;
+		}
+		while m < month {
+			// SAFETY: `m` is `1..=12`
+			day += year.days_in_month(unsafe { HebrewMonth::new_unchecked(m) }) as i128;
+			m += 1;
+		}
+
+		day
+	}
+
+	/// Convert a Rata Die into a [`HebrewDate`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewDate::from_rata_die(710347), HebrewDate::new(5706, HebrewMonth::Kislev, 7));
+	/// ```
+	pub const fn from_rata_die(rata_die: i128) -> Self {
+		// 35975351/98496 is the mean length of a year in days.
+		let approx = ((rata_die - HEBREW_EPOCH) * 98496).div_euclid(35975351) as i64 + 1;
+		let year = if HebrewYear::new_year_rata_die(approx) <= rata_die {
+			approx
+		} else {
+			approx - 1
+		};
+		let year = HebrewYear(year as i32);
+
+		let nisan = Self { year, month: HebrewMonth::Nisan, day: 1 }.to_rata_die();
+		let mut month = if rata_die < nisan {
+			HebrewMonth::Tishrei.inner()
+		} else {
+			HebrewMonth::Nisan.inner()
+		};
+
+		loop {
+			// SAFETY: `month` is `1..=13`
+			let m = unsafe { HebrewMonth::new_unchecked(month) };
+			let first = Self { year, month: m, day: 1 }.to_rata_die();
+			let days = year.days_in_month(m) as i128;
+			if rata_die < first + days {
+				return Self { year, month: m, day: (rata_die - first) as u8 + 1 };
+			}
+			month += 1;
+		}
+	}
+
+	#[inline]
+	/// Convert a [`Date`] into a [`HebrewDate`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(
+	/// 	HebrewDate::from_date(Date::new(2024, 3, 24)),
+	/// 	HebrewDate::new(5784, HebrewMonth::AdarII, 14),
+	/// );
+	/// ```
+	pub const fn from_date(date: Date) -> Self {
+		Self::from_rata_die(date.to_rata_die())
+	}
+
+	#[inline]
+	/// Convert into a [`Date`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(
+	/// 	HebrewDate::new(5784, HebrewMonth::Nisan, 15).to_date(),
+	/// 	Date::new(2024, 4, 23),
+	/// );
+	/// ```
+	pub const fn to_date(self) -> Date {
+		Date::from_rata_die(self.to_rata_die())
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- HebrewHoliday
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Major Jewish holidays
+///
+/// Each holiday's [`Date`] is its first full day, the observance begins at sundown the evening before.
+///
+/// ```rust
+/// # use nichi::*;
+/// let year = Year(2024);
+/// assert_eq!(HebrewHoliday::Purim.date(year),        Date::new(2024, 3, 24));
+/// assert_eq!(HebrewHoliday::Passover.date(year),     Date::new(2024, 4, 23));
+/// assert_eq!(HebrewHoliday::Shavuot.date(year),      Date::new(2024, 6, 12));
+/// assert_eq!(HebrewHoliday::RoshHashanah.date(year), Date::new(2024, 10, 3));
+/// assert_eq!(HebrewHoliday::YomKippur.date(year),    Date::new(2024, 10, 12));
+/// assert_eq!(HebrewHoliday::Hanukkah.date(year),     Date::new(2024, 12, 26));
+/// ```
+pub enum HebrewHoliday {
+	/// `1 Tishrei`
+	RoshHashanah,
+	/// `10 Tishrei`
+	YomKippur,
+	/// `15 Tishrei`
+	Sukkot,
+	/// `22 Tishrei`
+	SheminiAtzeret,
+	/// `23 Tishrei` (outside of Israel)
+	SimchatTorah,
+	/// `25 Kislev`
+	Hanukkah,
+	/// `15 Shevat`
+	TuBiShvat,
+	/// `14 Adar`, `14 Adar II` in leap years
+	Purim,
+	/// `15 Nisan`
+	Passover,
+	/// `6 Sivan`
+	Shavuot,
+	/// `9 Av`, postponed to `10 Av` if on Shabbat
+	TishaBAv,
+}
+
+impl HebrewHoliday {
+	/// All holidays, in order of the Gregorian year
+	pub const ALL: [Self; 11] = [
+		Self::TuBiShvat,
+		Self::Purim,
+		Self::Passover,
+		Self::Shavuot,
+		Self::TishaBAv,
+		Self::RoshHashanah,
+		Self::YomKippur,
+		Self::Sukkot,
+		Self::SheminiAtzeret,
+		Self::SimchatTorah,
+		Self::Hanukkah,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewHoliday::RoshHashanah.as_str(), "Rosh Hashanah");
+	/// assert_eq!(HebrewHoliday::TishaBAv.as_str(),     "Tisha B'Av");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::RoshHashanah   => "Rosh Hashanah",
+			Self::YomKippur      => "Yom Kippur",
+			Self::Sukkot         => "Sukkot",
+			Self::SheminiAtzeret => "Shemini Atzeret",
+			Self::SimchatTorah   => "Simchat Torah",
+			Self::Hanukkah       => "Hanukkah",
+			Self::TuBiShvat      => "Tu BiShvat",
+			Self::Purim          => "Purim",
+			Self::Passover       => "Passover",
+			Self::Shavuot        => "Shavuot",
+			Self::TishaBAv       => "Tisha B'Av",
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewHoliday::RoshHashanah.as_str_he(), "ראש השנה");
+	/// assert_eq!(HebrewHoliday::Passover.as_str_he(),     "פסח");
+	/// ```
+	pub const fn as_str_he(self) -> &'static str {
+		match self {
+			Self::RoshHashanah   => "ראש השנה",
+			Self::YomKippur      => "יום כיפור",
+			Self::Sukkot         => "סוכות",
+			Self::SheminiAtzeret => "שמיני עצרת",
+			Self::SimchatTorah   => "שמחת תורה",
+			Self::Hanukkah       => "חנוכה",
+			Self::TuBiShvat      => "ט״ו בשבט",
+			Self::Purim          => "פורים",
+			Self::Passover       => "פסח",
+			Self::Shavuot        => "שבועות",
+			Self::TishaBAv       => "תשעה באב",
+		}
+	}
+
+	/// The [`HebrewDate`] of this holiday in `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(
+	/// 	HebrewHoliday::Purim.hebrew_date(HebrewYear(5784)),
+	/// 	HebrewDate::new(5784, HebrewMonth::AdarII, 14),
+	/// );
+	///
+	/// // 9 Av 5782 was on Shabbat.
+	/// assert_eq!(
+	/// 	HebrewHoliday::TishaBAv.hebrew_date(HebrewYear(5782)),
+	/// 	HebrewDate::new(5782, HebrewMonth::Av, 10),
+	/// );
+	/// assert_eq!(HebrewHoliday::TishaBAv.date(Year(2022)), Date::new(2022, 8, 7));
+	/// ```
+	pub const fn hebrew_date(self, year: HebrewYear) -> HebrewDate {
+		use HebrewMonth as M;
+		let (month, day) = match self {
+			Self::RoshHashanah   => (M::Tishrei, 1),
+			Self::YomKippur      => (M::Tishrei, 10),
+			Self::Sukkot         => (M::Tishrei, 15),
+			Self::SheminiAtzeret => (M::Tishrei, 22),
+			Self::SimchatTorah   => (M::Tishrei, 23),
+			Self::Hanukkah       => (M::Kislev, 25),
+			Self::TuBiShvat      => (M::Shevat, 15),
+			Self::Purim          => (year.last_month(), 14),
+			Self::Passover       => (M::Nisan, 15),
+			Self::Shavuot        => (M::Sivan, 6),
+			Self::TishaBAv       => (M::Av, 9),
+		};
+
+		let date = HebrewDate { year, month, day };
+
+		if matches!(self, Self::TishaBAv)
+			&& matches!(Date::from_rata_die(date.to_rata_die()).weekday(), Weekday::Saturday)
+		{
+			HebrewDate { year, month, day: day + 1 }
+		} else {
+			date
+		}
+	}
+
+	#[inline]
+	/// The [`Date`] of this holiday in the Gregorian `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HebrewHoliday::RoshHashanah.date(Year(2023)), Date::new(2023, 9, 16));
+	/// assert_eq!(HebrewHoliday::YomKippur.date(Year(2023)),    Date::new(2023, 9, 25));
+	/// assert_eq!(HebrewHoliday::Hanukkah.date(Year(2023)),     Date::new(2023, 12, 8));
+	/// assert_eq!(HebrewHoliday::TishaBAv.date(Year(2024)),     Date::new(2024, 8, 13));
+	/// ```
+	pub const fn date(self, year: Year) -> Date {
+		let hebrew_year = match self {
+			// These fall in the autumn, after the new year.
+			Self::RoshHashanah   |
+			Self::YomKippur      |
+			Self::Sukkot         |
+			Self::SheminiAtzeret |
+			Self::SimchatTorah   |
+			Self::Hanukkah       => HebrewYear::starting_in(year),
+			_ => HebrewYear(HebrewYear::starting_in(year).0 - 1),
+		};
+
+		self.hebrew_date(hebrew_year).to_date()
+	}
+}
@@ -0,0 +1,260 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::year::Year;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Common Era designators, `BCE` then `CE` alternating, longest first
+pub(crate) const COMMON_ERA_NAMES: [&str; 4] = ["B.C.E.", "C.E.", "BCE", "CE"];
+
+/// Anno Domini designators, `BC` then `AD` alternating, longest first
+pub(crate) const ANNO_DOMINI_NAMES: [&str; 4] = ["B.C.", "A.D.", "BC", "AD"];
+
+//---------------------------------------------------------------------------------------------------- HistoricalEra
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Before or after the start of the Common Era
+pub enum HistoricalEra {
+	/// Before the Common Era, also known as BC
+	Bce = 1,
+	#[default]
+	/// The Common Era, also known as AD
+	Ce = 2,
+}
+
+impl HistoricalEra {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HistoricalEra::ALL.len(), 2);
+	/// ```
+	pub const ALL: [Self; 2] = [
+		Self::Bce,
+		Self::Ce,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HistoricalEra::Bce.as_str(), "BCE");
+	/// assert_eq!(HistoricalEra::Ce.as_str(),  "CE");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Bce => "BCE",
+			Self::Ce  => "CE",
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HistoricalEra::Bce.as_str_anno_domini(), "BC");
+	/// assert_eq!(HistoricalEra::Ce.as_str_anno_domini(),  "AD");
+	/// ```
+	pub const fn as_str_anno_domini(self) -> &'static str {
+		match self {
+			Self::Bce => "BC",
+			Self::Ce  => "AD",
+		}
+	}
+
+	#[inline]
+	/// Parse `BCE`, `CE`, `BC`, `AD`, ignoring ASCII case and periods, e.g, `b.c.`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HistoricalEra::from_str("BCE"),    Some(HistoricalEra::Bce));
+	/// assert_eq!(HistoricalEra::from_str("b.c."),   Some(HistoricalEra::Bce));
+	/// assert_eq!(HistoricalEra::from_str("A.D."),   Some(HistoricalEra::Ce));
+	/// assert_eq!(HistoricalEra::from_str("ce"),     Some(HistoricalEra::Ce));
+	/// assert_eq!(HistoricalEra::from_str("BCX"),    None);
+	/// ```
+	pub fn from_str(s: &str) -> Option<Self> {
+		let names = COMMON_ERA_NAMES.iter().chain(&ANNO_DOMINI_NAMES).enumerate();
+		names
+			.filter(|(_, name)| name.eq_ignore_ascii_case(s))
+			.map(|(i, _)| Self::ALL[i % 2])
+			.next()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- HistoricalYear
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// A year in historical numbering, e.g, `44 BCE`, `14 CE`
+///
+/// [`Year`] and the rest of this crate use astronomical year numbering, which has a year `0`:
+///
+/// | Astronomical | Historical |
+/// |--------------|------------|
+/// | `2`  | `2 CE`
+/// | `1`  | `1 CE`
+/// | `0`  | `1 BCE`
+/// | `-1` | `2 BCE`
+/// | `-43` | `44 BCE`
+///
+/// Historical numbering has no year `0`, `1 BCE` is followed by `1 CE`.
+///
+/// ```rust
+/// # use nichi::*;
+/// let year = HistoricalYear::from_year(Year(-43));
+/// assert_eq!(year.year(), 44);
+/// assert_eq!(year.era(),  HistoricalEra::Bce);
+/// assert_eq!(year.to_string(), "44 BCE");
+/// assert_eq!(year.to_string_anno_domini(), "44 BC");
+/// assert_eq!(year.to_year(), Year(-43));
+///
+/// let year = HistoricalYear::from_year(Year(14));
+/// assert_eq!(year.to_string(), "14 CE");
+/// assert_eq!(year.to_string_anno_domini(), "AD 14");
+///
+/// // The ides of March.
+/// let date = Date::new(-43, 3, 15);
+/// assert_eq!(date.format("%B %-d, %L %E").to_string(), "March 15, 44 BCE");
+/// assert_eq!(date.format("%Y-%m-%d").to_string(),      "-0043-03-15");
+/// ```
+pub struct HistoricalYear {
+	year: u16,
+	era: HistoricalEra,
+}
+
+impl HistoricalYear {
+	#[inline]
+	/// Create a new [`HistoricalYear`]
+	///
+	/// Returns [`None`] if `year` is `0`, or would not fit in a [`Year`],
+	/// i.e, after `32767 CE` or before `32769 BCE`.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert!(HistoricalYear::new(753, HistoricalEra::Bce).is_some());
+	/// assert!(HistoricalYear::new(0, HistoricalEra::Ce).is_none());
+	/// assert!(HistoricalYear::new(32769, HistoricalEra::Bce).is_some());
+	/// assert!(HistoricalYear::new(32768, HistoricalEra::Ce).is_none());
+	/// ```
+	pub const fn new(year: u16, era: HistoricalEra) -> Option<Self> {
+		let max = match era {
+			HistoricalEra::Bce => 32769,
+			HistoricalEra::Ce  => 32767,
+		};
+		if year == 0 || year > max {
+			None
+		} else {
+			Some(Self { year, era })
+		}
+	}
+
+	#[inline]
+	/// Convert an astronomical [`Year`]
+	pub const fn from_year(year: Year) -> Self {
+		let year = year.inner() as i32;
+		if year <= 0 {
+			Self { year: (1 - year) as u16, era: HistoricalEra::Bce }
+		} else {
+			Self { year: year as u16, era: HistoricalEra::Ce }
+		}
+	}
+
+	#[inline]
+	/// Convert into an astronomical [`Year`]
+	pub const fn to_year(self) -> Year {
+		match self.era {
+			HistoricalEra::Bce => Year((1 - self.year as i32) as i16),
+			HistoricalEra::Ce  => Year(self.year as i16),
+		}
+	}
+
+	#[inline]
+	/// The year within the era, never `0`
+	pub const fn year(self) -> u16 {
+		self.year
+	}
+
+	#[inline]
+	/// The [`HistoricalEra`]
+	pub const fn era(self) -> HistoricalEra {
+		self.era
+	}
+
+	/// Same as [`Display`](std::fmt::Display), but with `BC` and `AD`, e.g, `44 BC`, `AD 14`
+	pub fn to_string_anno_domini(self) -> String {
+		match self.era {
+			HistoricalEra::Bce => format!("{} BC", self.year),
+			HistoricalEra::Ce  => format!("AD {}", self.year),
+		}
+	}
+
+	/// Parse a historical or astronomical year
+	///
+	/// - A number and an era in either order, e.g, `44 BC`, `753 B.C.E.`, `AD 14`, `14ce`
+	/// - A number without an era is CE, e.g, `14`, `0014`
+	/// - A signed number is astronomical, e.g, ISO 8601's expanded `-0043` is `44 BCE`, `+0000` is `1 BCE`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let bce = |year| HistoricalYear::new(year, HistoricalEra::Bce);
+	/// let ce  = |year| HistoricalYear::new(year, HistoricalEra::Ce);
+	///
+	/// assert_eq!(HistoricalYear::from_str("44 BC"),      bce(44));
+	/// assert_eq!(HistoricalYear::from_str("753 B.C.E."), bce(753));
+	/// assert_eq!(HistoricalYear::from_str("AD 14"),      ce(14));
+	/// assert_eq!(HistoricalYear::from_str("14 ce"),      ce(14));
+	/// assert_eq!(HistoricalYear::from_str("0014"),       ce(14));
+	/// assert_eq!(HistoricalYear::from_str("-0043"),      bce(44));
+	/// assert_eq!(HistoricalYear::from_str("+0000"),      bce(1));
+	///
+	/// assert_eq!(HistoricalYear::from_str("0 BC"),       None);
+	/// assert_eq!(HistoricalYear::from_str("0"),          None);
+	/// assert_eq!(HistoricalYear::from_str("-43 BC"),     None);
+	/// assert_eq!(HistoricalYear::from_str("44 BC AD"),   None);
+	/// ```
+	pub fn from_str(s: &str) -> Option<Self> {
+		let s = s.trim();
+
+		// Astronomical.
+		if let Some(sign) = s.strip_prefix(['-', '+']) {
+			if sign.is_empty() || !sign.bytes().all(|b| b.is_ascii_digit()) {
+				return None;
+			}
+			let year: i32 = s.parse().ok()?;
+			let year = i16::try_from(year).ok()?;
+			return Some(Self::from_year(Year(year)));
+		}
+
+		// The number, and the era before or after it.
+		let start = s.find(|c: char| c.is_ascii_digit())?;
+		let end = start + s[start..].find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len() - start);
+		let year: u16 = s[start..end].parse().ok()?;
+		let era = match (s[..start].trim(), s[end..].trim()) {
+			("", "") => HistoricalEra::Ce,
+			(era, "") | ("", era) => HistoricalEra::from_str(era)?,
+			_ => return None,
+		};
+
+		Self::new(year, era)
+	}
+}
+
+impl std::fmt::Display for HistoricalYear {
+	/// The year and `BCE` or `CE`, e.g, `44 BCE`, `14 CE`
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} {}", self.year, self.era.as_str())
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Year
+impl Year {
+	#[inline]
+	/// Convert into a [`HistoricalYear`], e.g, `Year(0)` is `1 BCE`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Year(0).to_historical().to_string(),   "1 BCE");
+	/// assert_eq!(Year(1).to_historical().to_string(),   "1 CE");
+	/// assert_eq!(Year(-752).to_historical().to_string(), "753 BCE");
+	/// ```
+	pub const fn to_historical(self) -> HistoricalYear {
+		HistoricalYear::from_year(self)
+	}
+}
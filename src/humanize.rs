@@ -0,0 +1,449 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::weekday::Weekday;
+use crate::relative::{RelativeDate,RelativeUnit};
+
+//---------------------------------------------------------------------------------------------------- RelativeStyle
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// How long the output of [`RelativeFormat`] is
+pub enum RelativeStyle {
+	#[default]
+	/// `in 3 days`, `on Tuesday`, `来週の火曜日`
+	Long = 1,
+	/// `in 3d`, `Tue`, `来週火曜`
+	Short = 2,
+}
+
+impl RelativeStyle {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RelativeStyle::ALL, [RelativeStyle::Long, RelativeStyle::Short]);
+	/// ```
+	pub const ALL: [Self; 2] = [
+		Self::Long,
+		Self::Short,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RelativeStyle::Long.as_str(),  "long");
+	/// assert_eq!(RelativeStyle::Short.as_str(), "short");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Long  => "long",
+			Self::Short => "short",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Phrase
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+/// What [`RelativeFormat`] decided to say, before it is put into words
+enum Phrase {
+	/// An amount of a unit, negative is in the past
+	Offset(i32, RelativeUnit),
+	/// A weekday within a week of the anchor
+	Weekday {
+		weekday: Weekday,
+		/// The week relative to the anchor's week, `-1..=1`
+		week: i32,
+		/// If the date is after the anchor
+		ahead: bool,
+	},
+}
+
+//---------------------------------------------------------------------------------------------------- RelativeFormat
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// Describe a [`Date`] relative to another, e.g, `3 days ago` or `来週の火曜日`
+///
+/// This is the inverse of [`RelativeParser`](crate::RelativeParser),
+/// the [`RelativeStyle::Long`] output parses back into a [`Date`] close to the original.
+///
+/// The difference is rounded towards the anchor into the largest unit that fits:
+///
+/// | Difference | Output |
+/// |------------|--------|
+/// | `0` to `1` days | `today`, `tomorrow`, `yesterday`
+/// | Less than [`RelativeFormat::max_days`] | `on Tuesday`, `next Tuesday`, `last Tuesday`, or `in 3 days` without [`RelativeFormat::weekdays`]
+/// | Less than [`RelativeFormat::max_weeks`], or less than a month | `next week`, `in 2 weeks`
+/// | Less than [`RelativeFormat::max_months`] | `last month`, `3 months ago`
+/// | Anything else | `next year`, `in 10 years`
+///
+/// Units smaller than [`RelativeFormat::granularity`] are not used,
+/// instead the difference is counted in calendar weeks, months or years,
+/// e.g, `this week` or `next month`.
+///
+/// Weeks start on Monday.
+///
+/// ```rust
+/// # use nichi::*;
+/// let format = RelativeFormat::new();
+///
+/// // Friday.
+/// let anchor = Date::new(2023, 10, 20);
+/// let en = |y, m, d| format.format(Date::new(y, m, d), anchor);
+/// let jp = |y, m, d| format.format_jp(Date::new(y, m, d), anchor);
+///
+/// assert_eq!(en(2023, 10, 20), "today");
+/// assert_eq!(en(2023, 10, 21), "tomorrow");
+/// assert_eq!(en(2023, 10, 19), "yesterday");
+/// assert_eq!(en(2023, 10, 22), "on Sunday");
+/// assert_eq!(en(2023, 10, 24), "next Tuesday");
+/// assert_eq!(en(2023, 10, 17), "last Tuesday");
+/// assert_eq!(en(2023, 10, 27), "next week");
+/// assert_eq!(en(2023, 11, 3),  "in 2 weeks");
+/// assert_eq!(en(2023, 9, 20),  "last month");
+/// assert_eq!(en(2024, 2, 1),   "in 3 months");
+/// assert_eq!(en(2022, 10, 20), "last year");
+/// assert_eq!(en(2013, 1, 1),   "10 years ago");
+///
+/// assert_eq!(jp(2023, 10, 20), "今日");
+/// assert_eq!(jp(2023, 10, 21), "明日");
+/// assert_eq!(jp(2023, 10, 19), "昨日");
+/// assert_eq!(jp(2023, 10, 22), "日曜日");
+/// assert_eq!(jp(2023, 10, 24), "来週の火曜日");
+/// assert_eq!(jp(2023, 10, 17), "今週の火曜日");
+/// assert_eq!(jp(2023, 10, 15), "先週の日曜日");
+/// assert_eq!(jp(2023, 11, 3),  "2週間後");
+/// assert_eq!(jp(2023, 9, 20),  "先月");
+/// assert_eq!(jp(2013, 1, 1),   "10年前");
+/// ```
+///
+/// ## Configuration
+/// ```rust
+/// # use nichi::*;
+/// let anchor = Date::new(2023, 10, 20);
+/// let date = Date::new(2023, 10, 24);
+///
+/// let format = RelativeFormat::new().style(RelativeStyle::Short);
+/// assert_eq!(format.format(date, anchor),    "next Tue");
+/// assert_eq!(format.format_jp(date, anchor), "来週火曜");
+/// assert_eq!(format.format(Date::new(2023, 8, 1), anchor), "2mo ago");
+///
+/// let format = RelativeFormat::new().weekdays(false);
+/// assert_eq!(format.format(date, anchor),    "in 4 days");
+/// assert_eq!(format.format_jp(date, anchor), "4日後");
+///
+/// let format = RelativeFormat::new().granularity(RelativeUnit::Week);
+/// assert_eq!(format.format(Date::new(2023, 10, 16), anchor), "this week");
+/// assert_eq!(format.format(date, anchor),                   "next week");
+///
+/// let format = RelativeFormat::new().granularity(RelativeUnit::Month);
+/// assert_eq!(format.format(Date::new(2023, 11, 1), anchor), "next month");
+///
+/// let format = RelativeFormat::new().max_days(30).max_months(24);
+/// assert_eq!(format.format(Date::new(2023, 11, 10), anchor), "in 21 days");
+/// assert_eq!(format.format(Date::new(2024, 11, 10), anchor), "in 12 months");
+/// ```
+///
+/// ## Inverse
+/// ```rust
+/// # use nichi::*;
+/// let format = RelativeFormat::new();
+/// let parser = RelativeParser::new();
+/// let anchor = Date::new(2023, 10, 20);
+///
+/// // Within a week, the exact date comes back.
+/// for day in 14..=26 {
+/// 	let date = Date::new(2023, 10, day);
+/// 	assert_eq!(parser.resolve(&format.format(date, anchor), anchor),    Some(date));
+/// 	assert_eq!(parser.resolve(&format.format_jp(date, anchor), anchor), Some(date));
+/// }
+///
+/// // Further away, it is rounded.
+/// let date = Date::new(2023, 11, 5);
+/// assert_eq!(format.format(date, anchor), "in 2 weeks");
+/// assert_eq!(parser.resolve("in 2 weeks", anchor), Some(Date::new(2023, 11, 3)));
+/// ```
+pub struct RelativeFormat {
+	style: RelativeStyle,
+	granularity: RelativeUnit,
+	weekdays: bool,
+	max_days: u32,
+	max_weeks: u32,
+	max_months: u32,
+}
+
+impl RelativeFormat {
+	#[inline]
+	/// Create a new [`RelativeFormat`]
+	///
+	/// | Setting | Default |
+	/// |---------|---------|
+	/// | [`RelativeFormat::style`] | [`RelativeStyle::Long`] |
+	/// | [`RelativeFormat::granularity`] | [`RelativeUnit::Day`] |
+	/// | [`RelativeFormat::weekdays`] | `true` |
+	/// | [`RelativeFormat::max_days`] | `7` |
+	/// | [`RelativeFormat::max_weeks`] | `4` |
+	/// | [`RelativeFormat::max_months`] | `12` |
+	pub const fn new() -> Self {
+		Self {
+			style: RelativeStyle::Long,
+			granularity: RelativeUnit::Day,
+			weekdays: true,
+			max_days: 7,
+			max_weeks: 4,
+			max_months: 12,
+		}
+	}
+
+	#[inline]
+	/// The [`RelativeStyle`] of the output
+	pub const fn style(mut self, style: RelativeStyle) -> Self {
+		self.style = style;
+		self
+	}
+
+	#[inline]
+	/// The smallest [`RelativeUnit`] to use
+	pub const fn granularity(mut self, granularity: RelativeUnit) -> Self {
+		self.granularity = granularity;
+		self
+	}
+
+	#[inline]
+	/// Name the weekday for dates less than [`RelativeFormat::max_days`] away, e.g, `next Tuesday`
+	///
+	/// This only applies to dates in the anchor's week or the weeks next to it.
+	pub const fn weekdays(mut self, weekdays: bool) -> Self {
+		self.weekdays = weekdays;
+		self
+	}
+
+	#[inline]
+	/// Use days for differences of less than this many days
+	pub const fn max_days(mut self, max_days: u32) -> Self {
+		self.max_days = max_days;
+		self
+	}
+
+	#[inline]
+	/// Use weeks for differences of less than this many weeks
+	pub const fn max_weeks(mut self, max_weeks: u32) -> Self {
+		self.max_weeks = max_weeks;
+		self
+	}
+
+	#[inline]
+	/// Use months for differences of less than this many months, then years
+	pub const fn max_months(mut self, max_months: u32) -> Self {
+		self.max_months = max_months;
+		self
+	}
+
+	/// The [`RelativeDate`] that describes `date` relative to `anchor`
+	///
+	/// This is what [`RelativeFormat::format`] puts into words.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let format = RelativeFormat::new();
+	/// let anchor = Date::new(2023, 10, 20);
+	/// assert_eq!(format.relative(Date::new(2023, 10, 24), anchor), RelativeDate::WeekdayOfWeek(Weekday::Tuesday, 1));
+	/// assert_eq!(format.relative(Date::new(2023, 11, 3), anchor),  RelativeDate::Offset(2, RelativeUnit::Week));
+	/// ```
+	pub fn relative(self, date: Date, anchor: Date) -> RelativeDate {
+		match self.phrase(date, anchor) {
+			Phrase::Offset(amount, unit) => RelativeDate::Offset(amount, unit),
+			Phrase::Weekday { weekday, week: 0, ahead: true } => RelativeDate::Weekday(weekday, 0),
+			Phrase::Weekday { weekday, week, .. } => RelativeDate::WeekdayOfWeek(weekday, week),
+		}
+	}
+
+	/// Describe `date` relative to `anchor` in English
+	pub fn format(self, date: Date, anchor: Date) -> String {
+		let short = self.style == RelativeStyle::Short;
+
+		match self.phrase(date, anchor) {
+			Phrase::Offset(0, RelativeUnit::Day)  => "today".into(),
+			Phrase::Offset(1, RelativeUnit::Day)  => "tomorrow".into(),
+			Phrase::Offset(-1, RelativeUnit::Day) => "yesterday".into(),
+			Phrase::Offset(0, unit) => format!("this {}", unit.as_str()),
+			Phrase::Offset(1, unit) if !short => format!("next {}", unit.as_str()),
+			Phrase::Offset(-1, unit) if !short => format!("last {}", unit.as_str()),
+			Phrase::Offset(amount, unit) => {
+				let n = amount.unsigned_abs();
+				let unit = match (short, unit) {
+					(true, RelativeUnit::Day)   => "d".into(),
+					(true, RelativeUnit::Week)  => "w".into(),
+					(true, RelativeUnit::Month) => "mo".into(),
+					(true, RelativeUnit::Year)  => "y".into(),
+					(false, unit) if n == 1 => format!(" {}", unit.as_str()),
+					(false, unit) => format!(" {}s", unit.as_str()),
+				};
+				if amount > 0 {
+					format!("in {n}{unit}")
+				} else {
+					format!("{n}{unit} ago")
+				}
+			},
+			Phrase::Weekday { weekday, week, ahead } => {
+				let name = if short { weekday.as_str_short() } else { weekday.as_str() };
+				match (week, ahead, short) {
+					(0, true, true) => name.into(),
+					(0, true, false) => format!("on {name}"),
+					(1, ..) => format!("next {name}"),
+					_ => format!("last {name}"),
+				}
+			},
+		}
+	}
+
+	/// Describe `date` relative to `anchor` in Japanese
+	pub fn format_jp(self, date: Date, anchor: Date) -> String {
+		let short = self.style == RelativeStyle::Short;
+
+		match self.phrase(date, anchor) {
+			Phrase::Offset(amount, RelativeUnit::Day) => match amount {
+				0  => "今日".into(),
+				1  => "明日".into(),
+				2  => "明後日".into(),
+				-1 => "昨日".into(),
+				-2 => "一昨日".into(),
+				_  => offset_jp(amount, "日"),
+			},
+			Phrase::Offset(amount, RelativeUnit::Week) => match amount {
+				0  => "今週".into(),
+				1  => "来週".into(),
+				-1 => "先週".into(),
+				_  => offset_jp(amount, if short { "週" } else { "週間" }),
+			},
+			Phrase::Offset(amount, RelativeUnit::Month) => match amount {
+				0  => "今月".into(),
+				1  => "来月".into(),
+				-1 => "先月".into(),
+				_  => offset_jp(amount, "ヶ月"),
+			},
+			Phrase::Offset(amount, RelativeUnit::Year) => match amount {
+				0  => "今年".into(),
+				1  => "来年".into(),
+				-1 => "去年".into(),
+				_  => offset_jp(amount, "年"),
+			},
+			Phrase::Weekday { weekday, week, ahead } => {
+				let name = weekday.as_str_jp();
+				let name = if short { name.trim_end_matches('日') } else { name };
+				let week = match (week, ahead) {
+					(0, true) => return name.into(),
+					(0, false) => "今週",
+					(1, _) => "来週",
+					_ => "先週",
+				};
+				if short {
+					format!("{week}{name}")
+				} else {
+					format!("{week}の{name}")
+				}
+			},
+		}
+	}
+
+	/// Decide what to say
+	fn phrase(self, date: Date, anchor: Date) -> Phrase {
+		let days = (date.to_rata_die() - anchor.to_rata_die()) as i64;
+		let weeks = days / 7;
+		let calendar_weeks = (monday(date) - monday(anchor)) / 7;
+
+		// Whole months, rounded towards the anchor.
+		let (date_year, date_month, date_day) = date.inner();
+		let (anchor_year, anchor_month, anchor_day) = anchor.inner();
+		let calendar_months = (date_year as i64 * 12 + date_month as i64) - (anchor_year as i64 * 12 + anchor_month as i64);
+		let months = if calendar_months > 0 && date_day < anchor_day {
+			calendar_months - 1
+		} else if calendar_months < 0 && date_day > anchor_day {
+			calendar_months + 1
+		} else {
+			calendar_months
+		};
+
+		let unit = if days.unsigned_abs() < self.max_days as u64 {
+			RelativeUnit::Day
+		} else if months == 0 || weeks.unsigned_abs() < self.max_weeks as u64 {
+			RelativeUnit::Week
+		} else if months.unsigned_abs() < self.max_months as u64 {
+			RelativeUnit::Month
+		} else {
+			RelativeUnit::Year
+		};
+
+		// Too fine, count calendar periods instead.
+		if unit < self.granularity {
+			let amount = match self.granularity {
+				RelativeUnit::Day => days,
+				RelativeUnit::Week => calendar_weeks,
+				RelativeUnit::Month => calendar_months,
+				RelativeUnit::Year => date_year as i64 - anchor_year as i64,
+			};
+			return Phrase::Offset(saturate(amount), self.granularity);
+		}
+
+		match unit {
+			RelativeUnit::Day => {
+				if self.weekdays && days.abs() >= 2 && calendar_weeks.abs() <= 1 {
+					Phrase::Weekday { weekday: date.weekday(), week: calendar_weeks as i32, ahead: days > 0 }
+				} else {
+					Phrase::Offset(saturate(days), RelativeUnit::Day)
+				}
+			},
+			RelativeUnit::Week  => Phrase::Offset(saturate(weeks), RelativeUnit::Week),
+			RelativeUnit::Month => Phrase::Offset(saturate(months), RelativeUnit::Month),
+			RelativeUnit::Year  => Phrase::Offset(saturate(months / 12), RelativeUnit::Year),
+		}
+	}
+}
+
+impl Default for RelativeFormat {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Free
+#[inline]
+/// Clamp to an `i32`
+fn saturate(n: i64) -> i32 {
+	n.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+#[inline]
+/// The Rata Die of the Monday of the week `date` is in
+fn monday(date: Date) -> i64 {
+	date.to_rata_die() as i64 - (date.weekday_iso() as i64 - 1)
+}
+
+/// `3日後` or `3日前`
+fn offset_jp(amount: i32, unit: &str) -> String {
+	let direction = if amount > 0 { "後" } else { "前" };
+	format!("{}{unit}{direction}", amount.unsigned_abs())
+}
+
+//---------------------------------------------------------------------------------------------------- Date
+impl Date {
+	#[inline]
+	/// Describe this [`Date`] relative to `anchor` in English, e.g, `3 days ago`
+	///
+	/// This is [`RelativeFormat::format`] with the default [`RelativeFormat`].
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let anchor = Date::new(2023, 10, 20);
+	/// assert_eq!(Date::new(2023, 10, 17).humanize(anchor),    "last Tuesday");
+	/// assert_eq!(Date::new(2023, 10, 17).humanize_jp(anchor), "今週の火曜日");
+	/// ```
+	pub fn humanize(self, anchor: Date) -> String {
+		RelativeFormat::new().format(self, anchor)
+	}
+
+	#[inline]
+	/// Describe this [`Date`] relative to `anchor` in Japanese, e.g, `3日前`
+	///
+	/// This is [`RelativeFormat::format_jp`] with the default [`RelativeFormat`].
+	pub fn humanize_jp(self, anchor: Date) -> String {
+		RelativeFormat::new().format_jp(self, anchor)
+	}
+}
@@ -0,0 +1,447 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::month::Month;
+use crate::day::Day;
+use crate::weekday::Weekday;
+use crate::date_parser::DateOrder;
+use crate::parse::ParseError;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Samples with more pieces that could be read more than 1 way than this are not read
+const MAX_AMBIGUOUS_PIECES: usize = 6;
+
+//---------------------------------------------------------------------------------------------------- Piece
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+/// A date field a specifier fills
+enum Field {
+	Year,
+	Month,
+	Day,
+	Weekday,
+}
+
+impl Field {
+	#[inline]
+	/// The [`Field`]'s bit in a set of used fields
+	const fn bit(self) -> u8 {
+		1 << (self as u8)
+	}
+}
+
+#[derive(Clone,Debug,PartialEq,Eq)]
+/// A way to read a piece of a sample
+enum Choice {
+	/// A specifier and the [`Field`] it fills
+	Spec(&'static str, Field),
+	/// Text that is copied into the pattern as is, already escaped
+	Literal(String),
+}
+
+/// Split `sample` into pieces and every way each piece can be read
+///
+/// - Numbers of 1 or 2 digits are a day, month or 2 digit year
+/// - Numbers of 4 digits are a year
+/// - Numbers of 6 or 8 digits are a compact year, month and day, e.g, `231020`, `20231020`
+/// - Numbers with an ordinal suffix are a day, e.g, `3rd`
+/// - Month and weekday names are full or abbreviated names
+/// - Everything else is literal, whitespace is collapsed to 1 space
+fn pieces(sample: &str) -> Vec<Vec<Choice>> {
+	let s = sample.trim();
+	let b = s.as_bytes();
+	let mut pieces = Vec::new();
+	let mut i = 0;
+
+	let run = |i: usize, f: fn(&u8) -> bool| i + b[i..].iter().take_while(|b| f(b)).count();
+	let literal = |s: &str| vec![Choice::Literal(s.replace('%', "%%"))];
+
+	while i < b.len() {
+		let c = b[i];
+
+		if c.is_ascii_digit() {
+			let end = run(i, u8::is_ascii_digit);
+			let letters = run(end, u8::is_ascii_alphabetic);
+			let digits = &s[i..end];
+
+			// Ordinal day, e.g, `3rd`.
+			if letters == end + 2 && digits.len() <= 2 {
+				let ordinal = s[i..letters].to_ascii_lowercase();
+				if Day::from_bytes(ordinal.as_bytes()).is_some() {
+					pieces.push(vec![Choice::Spec("%o", Field::Day)]);
+					i = letters;
+					continue;
+				}
+			}
+
+			// Compact year, month and day, 1 piece each.
+			if let 6 | 8 = digits.len() {
+				let year = if digits.len() == 8 { "%Y" } else { "%y" };
+				pieces.push(vec![Choice::Spec(year, Field::Year)]);
+				pieces.push(vec![Choice::Spec("%m", Field::Month)]);
+				pieces.push(vec![Choice::Spec("%d", Field::Day)]);
+				i = end;
+				continue;
+			}
+
+			let value = digits.parse::<u8>().unwrap_or(u8::MAX);
+			let choices = match digits.len() {
+				4 => vec![Choice::Spec("%Y", Field::Year)],
+				1 | 2 => {
+					let mut choices = vec![Choice::Spec("%y", Field::Year)];
+					if (1..=12).contains(&value) {
+						choices.push(Choice::Spec("%m", Field::Month));
+					}
+					if (1..=31).contains(&value) {
+						choices.push(Choice::Spec("%d", Field::Day));
+					}
+					choices
+				},
+				_ => literal(digits),
+			};
+			pieces.push(choices);
+			i = end;
+		} else if c.is_ascii_alphabetic() {
+			let end = run(i, u8::is_ascii_alphabetic);
+			let word = s[i..end].to_ascii_lowercase();
+			let abbreviated = word.len() == 3;
+
+			let choices = if let Some(month) = Month::from_bytes(word.as_bytes()) {
+				match (abbreviated, month) {
+					(true, Month::May) => vec![Choice::Spec("%b", Field::Month), Choice::Spec("%B", Field::Month)],
+					(true, _)          => vec![Choice::Spec("%b", Field::Month)],
+					(false, _)         => vec![Choice::Spec("%B", Field::Month)],
+				}
+			} else if Weekday::from_bytes(word.as_bytes()).is_some() {
+				vec![Choice::Spec(if abbreviated { "%a" } else { "%A" }, Field::Weekday)]
+			} else {
+				literal(&s[i..end])
+			};
+			pieces.push(choices);
+			i = end;
+		} else if c.is_ascii_whitespace() {
+			i = run(i, u8::is_ascii_whitespace);
+			pieces.push(literal(" "));
+		} else {
+			let len = s[i..].chars().next().map_or(1, char::len_utf8);
+			pieces.push(literal(&s[i..i + len]));
+			i += len;
+		}
+	}
+
+	pieces
+}
+
+/// Every pattern `pieces` can be read as, with the [`DateOrder`] of its fields
+///
+/// Each reading uses exactly 1 year, month and day, and at most 1 weekday.
+fn readings(pieces: &[Vec<Choice>]) -> Vec<(String, DateOrder)> {
+	fn walk(
+		pieces: &[Vec<Choice>],
+		i: usize,
+		used: u8,
+		positions: [usize; 3],
+		pattern: &mut String,
+		out: &mut Vec<(String, DateOrder)>,
+	) {
+		let Some(choices) = pieces.get(i) else {
+			let all = Field::Year.bit() | Field::Month.bit() | Field::Day.bit();
+			if used & all == all {
+				let [year, month, day] = positions;
+				out.push((pattern.clone(), DateOrder::from_positions(year, month, day)));
+			}
+			return;
+		};
+
+		let len = pattern.len();
+		for choice in choices {
+			match choice {
+				Choice::Literal(s) => {
+					pattern.push_str(s);
+					walk(pieces, i + 1, used, positions, pattern, out);
+				},
+				Choice::Spec(spec, field) => {
+					if used & field.bit() != 0 {
+						continue;
+					}
+					let mut positions = positions;
+					if let Some(p) = positions.get_mut(*field as usize) {
+						*p = i;
+					}
+					pattern.push_str(spec);
+					walk(pieces, i + 1, used | field.bit(), positions, pattern, out);
+				},
+			}
+			pattern.truncate(len);
+		}
+	}
+
+	let mut out = Vec::new();
+	if pieces.iter().filter(|p| p.len() > 1).count() <= MAX_AMBIGUOUS_PIECES {
+		walk(pieces, 0, 0, [0; 3], &mut String::new(), &mut out);
+	}
+	out
+}
+
+//---------------------------------------------------------------------------------------------------- InferredFormat
+#[derive(Clone,Debug,PartialEq,Eq,Hash)]
+/// A pattern inferred from samples by [`FormatInference`]
+pub struct InferredFormat {
+	pattern: String,
+	order: DateOrder,
+	matched: usize,
+	total: usize,
+	conflicts: Vec<(usize, ParseError)>,
+	alternatives: Vec<String>,
+}
+
+impl InferredFormat {
+	#[inline]
+	/// The `strftime`-style pattern, e.g, `%d/%m/%Y`
+	///
+	/// This is meant for [`Date::parse_with_format_lenient`],
+	/// which accepts numbers with any padding and any amount of whitespace.
+	pub fn pattern(&self) -> &str {
+		&self.pattern
+	}
+
+	#[inline]
+	/// The [`DateOrder`] of the year, month and day in [`InferredFormat::pattern`]
+	pub const fn order(&self) -> DateOrder {
+		self.order
+	}
+
+	#[inline]
+	/// How many samples [`InferredFormat::pattern`] parses
+	pub const fn matched(&self) -> usize {
+		self.matched
+	}
+
+	#[inline]
+	/// How many samples there were
+	pub const fn total(&self) -> usize {
+		self.total
+	}
+
+	#[inline]
+	/// The samples [`InferredFormat::pattern`] does not parse, their index and why
+	pub fn conflicts(&self) -> &[(usize, ParseError)] {
+		&self.conflicts
+	}
+
+	#[inline]
+	/// Other patterns that parse as many samples, but into different [`Date`]s
+	///
+	/// If this is not empty, the samples do not decide the format,
+	/// [`FormatInference::order`] did.
+	pub fn alternatives(&self) -> &[String] {
+		&self.alternatives
+	}
+
+	#[inline]
+	/// If [`InferredFormat::alternatives`] is not empty
+	pub fn is_ambiguous(&self) -> bool {
+		!self.alternatives.is_empty()
+	}
+
+	#[inline]
+	/// Parse `input` with [`InferredFormat::pattern`], see [`Date::parse_with_format_lenient`]
+	pub fn parse(&self, input: &str) -> Result<Date, ParseError> {
+		Date::parse_with_format_lenient(input, &self.pattern)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- FormatInference
+#[derive(Copy,Clone,Debug,PartialEq)]
+/// Infer the format of a column of date strings
+///
+/// Every sample is split into numbers, names and literals,
+/// and every way of reading it as a year, month and day becomes a candidate pattern.
+/// The pattern that parses the most samples wins.
+///
+/// - Numbers of 1 or 2 digits are a day, month or 2 digit year, numbers of 4 digits are a year
+/// - Numbers of 6 or 8 digits are a compact `%y%m%d` or `%Y%m%d`
+/// - Every number must be part of the date, e.g, times are not skipped
+/// - Month and weekday names, and ordinal days are recognized, e.g, `Apr`, `Monday`, `3rd`
+/// - Weekdays must agree with the date
+///
+/// Samples that the winner cannot parse are reported as [`InferredFormat::conflicts`],
+/// patterns that tie with it as [`InferredFormat::alternatives`].
+///
+/// ```rust
+/// # use nichi::*;
+/// // `13/04/2023` can only be DMY.
+/// let format = FormatInference::new()
+/// 	.infer(["03/04/2023", "13/04/2023", "04/05/2023"])
+/// 	.unwrap();
+///
+/// assert_eq!(format.pattern(), "%d/%m/%Y");
+/// assert_eq!(format.order(),   DateOrder::DMY);
+/// assert_eq!(format.matched(), 3);
+/// assert!(!format.is_ambiguous());
+///
+/// // The format can be reused.
+/// assert_eq!(format.parse("25/12/2023"), Ok(Date::new(2023, 12, 25)));
+///
+/// // Without `13/04/2023` the samples are ambiguous, the preferred order decides.
+/// let samples = ["03/04/2023", "04/05/2023"];
+/// let format = FormatInference::new().order(DateOrder::MDY).infer(samples).unwrap();
+/// assert_eq!(format.pattern(),      "%m/%d/%Y");
+/// assert_eq!(format.alternatives(), ["%d/%m/%Y"]);
+///
+/// // Samples that do not fit are reported.
+/// let format = FormatInference::new()
+/// 	.infer(["2023-04-03", "2023-04-13", "oops", "2023-02-30"])
+/// 	.unwrap();
+/// assert_eq!(format.pattern(), "%Y-%m-%d");
+/// assert_eq!(format.matched(), 2);
+///
+/// let conflicts: Vec<usize> = format.conflicts().iter().map(|c| c.0).collect();
+/// assert_eq!(conflicts, [2, 3]);
+/// assert_eq!(format.conflicts()[1].1.kind(), ParseErrorKind::InvalidDate);
+/// ```
+///
+/// Names and weekdays:
+/// ```rust
+/// # use nichi::*;
+/// let format = Date::infer_format(["Mon, Apr 3 2023", "Fri, Dec 1 2023"]).unwrap();
+/// assert_eq!(format.pattern(), "%a, %b %d %Y");
+///
+/// // The weekday rules out YY/MM/DD.
+/// let format = Date::infer_format(["Monday 23/04/03"]).unwrap();
+/// assert_eq!(format.pattern(), "%A %y/%m/%d");
+/// ```
+///
+/// Compact dates:
+/// ```rust
+/// # use nichi::*;
+/// let format = Date::infer_format(["20231020", "20231021"]).unwrap();
+/// assert_eq!(format.pattern(), "%Y%m%d");
+/// assert_eq!(format.parse("20240229"), Ok(Date::new(2024, 2, 29)));
+///
+/// let format = Date::infer_format(["231020", "231021"]).unwrap();
+/// assert_eq!(format.pattern(), "%y%m%d");
+///
+/// // Compact dates that do not exist are conflicts.
+/// let format = Date::infer_format(["20231020", "20231320"]).unwrap();
+/// assert_eq!(format.matched(), 1);
+/// ```
+pub struct FormatInference {
+	order: DateOrder,
+	min_share: f64,
+}
+
+impl FormatInference {
+	#[inline]
+	/// Create a new [`FormatInference`]
+	///
+	/// | Setting | Default |
+	/// |---------|---------|
+	/// | [`FormatInference::order`] | [`DateOrder::YMD`] |
+	/// | [`FormatInference::min_share`] | `0.5` |
+	pub const fn new() -> Self {
+		Self {
+			order: DateOrder::YMD,
+			min_share: 0.5,
+		}
+	}
+
+	#[inline]
+	/// The preferred [`DateOrder`], which wins ties
+	pub const fn order(mut self, order: DateOrder) -> Self {
+		self.order = order;
+		self
+	}
+
+	#[inline]
+	/// The share of samples, `0.0..=1.0`, the winning pattern must parse
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let samples = ["2023-04-03", "n/a", "n/a"];
+	/// assert!(FormatInference::new().infer(samples).is_none());
+	/// assert!(FormatInference::new().min_share(0.3).infer(samples).is_some());
+	/// ```
+	pub const fn min_share(mut self, min_share: f64) -> Self {
+		self.min_share = min_share;
+		self
+	}
+
+	/// Infer the format of `samples`
+	///
+	/// Returns [`None`] if no pattern parses at least [`FormatInference::min_share`] of them.
+	pub fn infer<'s, I>(&self, samples: I) -> Option<InferredFormat>
+	where
+		I: IntoIterator<Item = &'s str>,
+	{
+		let samples: Vec<&str> = samples.into_iter().collect();
+
+		// Count the samples each pattern reads, in the order first seen.
+		let mut counts: Vec<(String, DateOrder, usize)> = Vec::new();
+		for sample in &samples {
+			let mut seen: Vec<String> = Vec::new();
+			for (pattern, order) in readings(&pieces(sample)) {
+				if seen.contains(&pattern) || Date::parse_with_format_lenient(sample, &pattern).is_err() {
+					continue;
+				}
+				match counts.iter_mut().find(|c| c.0 == pattern) {
+					Some(count) => count.2 += 1,
+					None => counts.push((pattern.clone(), order, 1)),
+				}
+				seen.push(pattern);
+			}
+		}
+
+		// The most samples wins, then the preferred order, then the first seen.
+		let max = counts.iter().map(|c| c.2).max()?;
+		let (pattern, order, count) = counts.iter()
+			.filter(|c| c.2 == max)
+			.find(|c| c.1 == self.order)
+			.or_else(|| counts.iter().find(|c| c.2 == max))?
+			.clone();
+		if (count as f64) < self.min_share * samples.len() as f64 {
+			return None;
+		}
+
+		let parse = |pattern: &str| -> Vec<Result<Date, ParseError>> {
+			samples.iter().map(|s| Date::parse_with_format_lenient(s, pattern)).collect()
+		};
+		let dates = parse(&pattern);
+
+		let conflicts = dates.iter()
+			.enumerate()
+			.filter_map(|(i, date)| date.err().map(|e| (i, e)))
+			.collect();
+
+		let alternatives = counts.into_iter()
+			.filter(|c| c.2 == max && c.0 != pattern)
+			.filter(|c| parse(&c.0) != dates)
+			.map(|c| c.0)
+			.collect();
+
+		Some(InferredFormat {
+			matched: dates.iter().filter(|d| d.is_ok()).count(),
+			total: samples.len(),
+			pattern,
+			order,
+			conflicts,
+			alternatives,
+		})
+	}
+}
+
+impl Default for FormatInference {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Date
+impl Date {
+	#[inline]
+	/// Infer the format of `samples` with the default [`FormatInference`]
+	pub fn infer_format<'s, I>(samples: I) -> Option<InferredFormat>
+	where
+		I: IntoIterator<Item = &'s str>,
+	{
+		FormatInference::new().infer(samples)
+	}
+}
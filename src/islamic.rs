@@ -0,0 +1,720 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::macros::impl_u8_enum;
+
+//---------------------------------------------------------------------------------------------------- IslamicMonth
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Month in an Islamic (Hijri) year
+#[allow(missing_docs)]
+pub enum IslamicMonth {
+	#[default]
+	Muharram        = 1,
+	Safar           = 2,
+	RabiAlAwwal     = 3,
+	RabiAlThani     = 4,
+	JumadaAlUla     = 5,
+	JumadaAlAkhirah = 6,
+	Rajab           = 7,
+	Shaban          = 8,
+	Ramadan         = 9,
+	Shawwal         = 10,
+	DhuAlQadah      = 11,
+	DhuAlHijjah     = 12,
+}
+
+impl IslamicMonth {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicMonth::FIRST, IslamicMonth::Muharram);
+	/// ```
+	pub const FIRST: Self = Self::Muharram;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicMonth::LAST, IslamicMonth::DhuAlHijjah);
+	/// ```
+	pub const LAST: Self = Self::DhuAlHijjah;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicMonth::ALL[0],  IslamicMonth::Muharram);
+	/// assert_eq!(IslamicMonth::ALL[8],  IslamicMonth::Ramadan);
+	/// assert_eq!(IslamicMonth::ALL[11], IslamicMonth::DhuAlHijjah);
+	/// ```
+	pub const ALL: [Self; 12] = [
+		Self::Muharram,
+		Self::Safar,
+		Self::RabiAlAwwal,
+		Self::RabiAlThani,
+		Self::JumadaAlUla,
+		Self::JumadaAlAkhirah,
+		Self::Rajab,
+		Self::Shaban,
+		Self::Ramadan,
+		Self::Shawwal,
+		Self::DhuAlQadah,
+		Self::DhuAlHijjah,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicMonth::new(1),  IslamicMonth::Muharram);
+	/// assert_eq!(IslamicMonth::new(9),  IslamicMonth::Ramadan);
+	/// assert_eq!(IslamicMonth::new(12), IslamicMonth::DhuAlHijjah);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// IslamicMonth::new(0);
+	/// ```
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// IslamicMonth::new(13);
+	/// ```
+	pub const fn new(month: u8) -> Self {
+		assert!(month != 0, "month must not be 0");
+		assert!(month < 13, "month must not be > 12");
+		// SAFETY: repr(u8)
+		unsafe { Self::new_unchecked(month) }
+	}
+
+	#[inline]
+	/// ## Safety
+	/// `month` must be `1..=12`.
+	pub const unsafe fn new_unchecked(month: u8) -> Self {
+		debug_assert!(month != 0, "month must not be 0");
+		debug_assert!(month < 13, "month must not be > 12");
+		// SAFETY: repr(u8)
+		std::mem::transmute(month)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicMonth::new_saturating(0),  IslamicMonth::Muharram);
+	/// assert_eq!(IslamicMonth::new_saturating(9),  IslamicMonth::Ramadan);
+	/// assert_eq!(IslamicMonth::new_saturating(13), IslamicMonth::DhuAlHijjah);
+	/// ```
+	pub const fn new_saturating(month: u8) -> Self {
+		if month == 0 {
+			Self::FIRST
+		} else if month < 13 {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(month) }
+		} else {
+			Self::LAST
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicMonth::new_wrapping(0),  IslamicMonth::DhuAlHijjah);
+	/// assert_eq!(IslamicMonth::new_wrapping(9),  IslamicMonth::Ramadan);
+	/// assert_eq!(IslamicMonth::new_wrapping(13), IslamicMonth::Muharram);
+	/// ```
+	pub const fn new_wrapping(month: u8) -> Self {
+		let month = month % 12;
+		if month == 0 {
+			Self::LAST
+		} else {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(month) }
+		}
+	}
+
+	impl_u8_enum!();
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicMonth::Muharram.inner(),    1);
+	/// assert_eq!(IslamicMonth::DhuAlHijjah.inner(), 12);
+	/// ```
+	pub const fn inner(self) -> u8 {
+		// SAFETY: repr(u8)
+		unsafe { std::mem::transmute(self) }
+	}
+
+	#[inline]
+	/// Transliterated name of the month
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicMonth::Muharram.as_str(),        "Muharram");
+	/// assert_eq!(IslamicMonth::Safar.as_str(),           "Safar");
+	/// assert_eq!(IslamicMonth::RabiAlAwwal.as_str(),     "Rabi' al-Awwal");
+	/// assert_eq!(IslamicMonth::RabiAlThani.as_str(),     "Rabi' al-Thani");
+	/// assert_eq!(IslamicMonth::JumadaAlUla.as_str(),     "Jumada al-Ula");
+	/// assert_eq!(IslamicMonth::JumadaAlAkhirah.as_str(), "Jumada al-Akhirah");
+	/// assert_eq!(IslamicMonth::Rajab.as_str(),           "Rajab");
+	/// assert_eq!(IslamicMonth::Shaban.as_str(),          "Sha'ban");
+	/// assert_eq!(IslamicMonth::Ramadan.as_str(),         "Ramadan");
+	/// assert_eq!(IslamicMonth::Shawwal.as_str(),         "Shawwal");
+	/// assert_eq!(IslamicMonth::DhuAlQadah.as_str(),      "Dhu al-Qa'dah");
+	/// assert_eq!(IslamicMonth::DhuAlHijjah.as_str(),     "Dhu al-Hijjah");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Muharram        => "Muharram",
+			Self::Safar           => "Safar",
+			Self::RabiAlAwwal     => "Rabi' al-Awwal",
+			Self::RabiAlThani     => "Rabi' al-Thani",
+			Self::JumadaAlUla     => "Jumada al-Ula",
+			Self::JumadaAlAkhirah => "Jumada al-Akhirah",
+			Self::Rajab           => "Rajab",
+			Self::Shaban          => "Sha'ban",
+			Self::Ramadan         => "Ramadan",
+			Self::Shawwal         => "Shawwal",
+			Self::DhuAlQadah      => "Dhu al-Qa'dah",
+			Self::DhuAlHijjah     => "Dhu al-Hijjah",
+		}
+	}
+
+	#[inline]
+	/// Arabic name of the month
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicMonth::Muharram.as_str_ar(),        "محرم");
+	/// assert_eq!(IslamicMonth::Safar.as_str_ar(),           "صفر");
+	/// assert_eq!(IslamicMonth::RabiAlAwwal.as_str_ar(),     "ربيع الأول");
+	/// assert_eq!(IslamicMonth::RabiAlThani.as_str_ar(),     "ربيع الآخر");
+	/// assert_eq!(IslamicMonth::JumadaAlUla.as_str_ar(),     "جمادى الأولى");
+	/// assert_eq!(IslamicMonth::JumadaAlAkhirah.as_str_ar(), "جمادى الآخرة");
+	/// assert_eq!(IslamicMonth::Rajab.as_str_ar(),           "رجب");
+	/// assert_eq!(IslamicMonth::Shaban.as_str_ar(),          "شعبان");
+	/// assert_eq!(IslamicMonth::Ramadan.as_str_ar(),         "رمضان");
+	/// assert_eq!(IslamicMonth::Shawwal.as_str_ar(),         "شوال");
+	/// assert_eq!(IslamicMonth::DhuAlQadah.as_str_ar(),      "ذو القعدة");
+	/// assert_eq!(IslamicMonth::DhuAlHijjah.as_str_ar(),     "ذو الحجة");
+	/// ```
+	pub const fn as_str_ar(self) -> &'static str {
+		match self {
+			Self::Muharram        => "محرم",
+			Self::Safar           => "صفر",
+			Self::RabiAlAwwal     => "ربيع الأول",
+			Self::RabiAlThani     => "ربيع الآخر",
+			Self::JumadaAlUla     => "جمادى الأولى",
+			Self::JumadaAlAkhirah => "جمادى الآخرة",
+			Self::Rajab           => "رجب",
+			Self::Shaban          => "شعبان",
+			Self::Ramadan         => "رمضان",
+			Self::Shawwal         => "شوال",
+			Self::DhuAlQadah      => "ذو القعدة",
+			Self::DhuAlHijjah     => "ذو الحجة",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- IslamicLeapPattern
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Which years of the 30-year cycle are leap years
+///
+/// A leap year adds a 30th day to [`IslamicMonth::DhuAlHijjah`].
+pub enum IslamicLeapPattern {
+	/// Years `2, 5, 7, 10, 13, 15, 18, 21, 24, 26, 29`
+	Base15,
+	#[default]
+	/// Years `2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29`
+	///
+	/// This is the most common pattern.
+	Base16,
+	/// Years `2, 5, 8, 10, 13, 16, 19, 21, 24, 27, 29`
+	///
+	/// Also known as the Misri or Bohra pattern.
+	Fatimid,
+	/// Years `2, 5, 8, 11, 13, 16, 19, 21, 24, 27, 30`
+	HabashAlHasib,
+}
+
+impl IslamicLeapPattern {
+	#[inline]
+	/// `c` in `(11 * year + c) % 30 < 11`, which is true for leap years.
+	const fn offset(self) -> i64 {
+		match self {
+			Self::Base15        => 15,
+			Self::Base16        => 14,
+			Self::Fatimid       => 11,
+			Self::HabashAlHasib => 9,
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- IslamicEpoch
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// The day `1 Muharram 1 AH` falls on
+pub enum IslamicEpoch {
+	#[default]
+	/// Friday, `July 16th, 622` (Julian)
+	Civil,
+	/// Thursday, `July 15th, 622` (Julian)
+	Astronomical,
+}
+
+impl IslamicEpoch {
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicEpoch::Civil.to_rata_die(),        227015);
+	/// assert_eq!(IslamicEpoch::Astronomical.to_rata_die(), 227014);
+	/// ```
+	pub const fn to_rata_die(self) -> i128 {
+		match self {
+			Self::Civil        => 227015,
+			Self::Astronomical => 227014,
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- IslamicTable
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// User supplied month lengths, e.g, Umm al-Qura data
+///
+/// Observational calendars cannot be computed arithmetically,
+/// so the data must come from an authority.
+///
+/// Each [`u16`] in `months` describes 1 year starting at `first_year`,
+/// where bit `0` is [`IslamicMonth::Muharram`] and bit `11` is [`IslamicMonth::DhuAlHijjah`].
+///
+/// A set bit means the month has `30` days, else `29`.
+///
+/// Years outside of the table fall back to the arithmetic calendar,
+/// so the table should start and end on the same days the arithmetic calendar does.
+///
+/// ```rust
+/// # use nichi::*;
+/// // Month lengths for 1444 AH (example values), starting on `July 30th, 2022`.
+/// const MONTHS: [u16; 1] = [0b0100_1010_1010];
+/// let table = IslamicTable::new(1444, Date::new(2022, 7, 30).to_rata_die(), &MONTHS);
+///
+/// let calendar = IslamicCalendar::DEFAULT.with_table(table);
+/// assert_eq!(calendar.to_date(IslamicDate::new(1444, 9, 1)), Date::new(2023, 3, 23));
+/// assert_eq!(calendar.from_date(Date::new(2023, 3, 22)), IslamicDate::new(1444, 8, 30));
+///
+/// // Outside of the table.
+/// assert_eq!(calendar.to_date(IslamicDate::new(1440, 1, 1)), Date::new(2018, 9, 12));
+/// ```
+pub struct IslamicTable<'a> {
+	first_year: i32,
+	first_day: i128,
+	months: &'a [u16],
+}
+
+impl<'a> IslamicTable<'a> {
+	#[inline]
+	/// Create a new [`IslamicTable`]
+	///
+	/// `first_day` is the Rata Die (see [`Date::to_rata_die`]) of `1 Muharram` of `first_year`.
+	pub const fn new(first_year: i32, first_day: i128, months: &'a [u16]) -> Self {
+		Self { first_year, first_day, months }
+	}
+
+	#[inline]
+	/// The range of years this table covers, `None` if empty
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let table = IslamicTable::new(1444, 738366, &[0, 0]);
+	/// assert_eq!(table.years(), Some((1444, 1445)));
+	///
+	/// let table = IslamicTable::new(1444, 738366, &[]);
+	/// assert_eq!(table.years(), None);
+	/// ```
+	pub const fn years(&self) -> Option<(i32, i32)> {
+		if self.months.is_empty() {
+			None
+		} else {
+			Some((self.first_year, self.first_year + self.months.len() as i32 - 1))
+		}
+	}
+
+	#[inline]
+	/// Days in `month` of `year`, `None` if outside of the table
+	const fn days_in_month(&self, year: i32, month: IslamicMonth) -> Option<u8> {
+		let index = year as i64 - self.first_year as i64;
+		if index < 0 || index >= self.months.len() as i64 {
+			return None;
+		}
+
+		if self.months[index as usize] & (1 << (month.inner() - 1)) == 0 {
+			Some(29)
+		} else {
+			Some(30)
+		}
+	}
+
+	#[inline]
+	/// Days in `year` from index `i`
+	const fn days_in_year_index(&self, i: usize) -> i128 {
+		348 + (self.months[i] & 0b1111_1111_1111).count_ones() as i128
+	}
+
+	/// Rata Die of `1 Muharram` of `year`, `None` if outside of the table
+	const fn year_start(&self, year: i32) -> Option<i128> {
+		let index = year as i64 - self.first_year as i64;
+		if index < 0 || index >= self.months.len() as i64 {
+			return None;
+		}
+
+		let mut day = self.first_day;
+		let mut i = 0;
+		while i < index as usize {
+			day += self.days_in_year_index(i);
+			i += 1;
+		}
+		Some(day)
+	}
+
+	/// Rata Die of the day after the table ends
+	const fn end(&self) -> i128 {
+		let mut day = self.first_day;
+		let mut i = 0;
+		while i < self.months.len() {
+			day += self.days_in_year_index(i);
+			i += 1;
+		}
+		day
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- IslamicCalendar
+#[derive(Copy,Clone,Debug,Default,PartialEq,Eq,Hash)]
+/// Tabular Islamic calendar rules
+///
+/// This is the arithmetic calendar defined by a [`IslamicLeapPattern`] and [`IslamicEpoch`],
+/// optionally overridden by an [`IslamicTable`] for the years it covers.
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = Date::new(2023, 10, 20);
+///
+/// let civil = IslamicCalendar::DEFAULT;
+/// assert_eq!(civil.from_date(date), IslamicDate::new(1445, 4, 5));
+///
+/// let astronomical = IslamicCalendar::new(IslamicLeapPattern::Base16, IslamicEpoch::Astronomical);
+/// assert_eq!(astronomical.from_date(date), IslamicDate::new(1445, 4, 6));
+/// ```
+pub struct IslamicCalendar<'a> {
+	pattern: IslamicLeapPattern,
+	epoch: IslamicEpoch,
+	table: Option<IslamicTable<'a>>,
+}
+
+impl<'a> IslamicCalendar<'a> {
+	/// [`IslamicLeapPattern::Base16`] with the [`IslamicEpoch::Civil`] epoch and no table
+	pub const DEFAULT: IslamicCalendar<'static> = IslamicCalendar {
+		pattern: IslamicLeapPattern::Base16,
+		epoch: IslamicEpoch::Civil,
+		table: None,
+	};
+
+	#[inline]
+	/// Create a new arithmetic [`IslamicCalendar`]
+	pub const fn new(pattern: IslamicLeapPattern, epoch: IslamicEpoch) -> Self {
+		Self { pattern, epoch, table: None }
+	}
+
+	#[inline]
+	/// Use `table` for the years it covers
+	pub const fn with_table<'b>(self, table: IslamicTable<'b>) -> IslamicCalendar<'b> {
+		IslamicCalendar { pattern: self.pattern, epoch: self.epoch, table: Some(table) }
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// let calendar = IslamicCalendar::DEFAULT;
+	/// assert_eq!(calendar.pattern(), IslamicLeapPattern::Base16);
+	/// ```
+	pub const fn pattern(&self) -> IslamicLeapPattern {
+		self.pattern
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// let calendar = IslamicCalendar::DEFAULT;
+	/// assert_eq!(calendar.epoch(), IslamicEpoch::Civil);
+	/// ```
+	pub const fn epoch(&self) -> IslamicEpoch {
+		self.epoch
+	}
+
+	#[inline]
+	/// The [`IslamicTable`] in use, if any
+	pub const fn table(&self) -> Option<&IslamicTable<'a>> {
+		self.table.as_ref()
+	}
+
+	#[inline]
+	/// If `year` is a leap year in the arithmetic calendar
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let calendar = IslamicCalendar::DEFAULT;
+	/// assert!(calendar.is_leap(1442));
+	/// assert!(!calendar.is_leap(1443));
+	/// assert!(!calendar.is_leap(1444));
+	/// assert!(calendar.is_leap(1445));
+	///
+	/// let calendar = IslamicCalendar::new(IslamicLeapPattern::Base15, IslamicEpoch::Civil);
+	/// assert!(calendar.is_leap(15));
+	/// assert!(!calendar.is_leap(16));
+	/// ```
+	pub const fn is_leap(&self, year: i32) -> bool {
+		(11 * year as i64 + self.pattern.offset()).rem_euclid(30) < 11
+	}
+
+	#[inline]
+	/// Days in `month` of `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let calendar = IslamicCalendar::DEFAULT;
+	/// assert_eq!(calendar.days_in_month(1445, IslamicMonth::Muharram),    30);
+	/// assert_eq!(calendar.days_in_month(1445, IslamicMonth::Safar),       29);
+	/// assert_eq!(calendar.days_in_month(1445, IslamicMonth::DhuAlHijjah), 30);
+	/// assert_eq!(calendar.days_in_month(1444, IslamicMonth::DhuAlHijjah), 29);
+	/// ```
+	pub const fn days_in_month(&self, year: i32, month: IslamicMonth) -> u8 {
+		if let Some(table) = &self.table {
+			if let Some(days) = table.days_in_month(year, month) {
+				return days;
+			}
+		}
+
+		match month {
+			IslamicMonth::DhuAlHijjah => if self.is_leap(year) { 30 } else { 29 },
+			_ => if month.inner() % 2 == 1 { 30 } else { 29 },
+		}
+	}
+
+	#[inline]
+	/// Days in `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let calendar = IslamicCalendar::DEFAULT;
+	/// assert_eq!(calendar.days_in_year(1444), 354);
+	/// assert_eq!(calendar.days_in_year(1445), 355);
+	/// ```
+	pub const fn days_in_year(&self, year: i32) -> u16 {
+		(self.year_start(year as i64 + 1) - self.year_start(year as i64)) as u16
+	}
+
+	/// Rata Die of `1 Muharram` of `year`
+	const fn year_start(&self, year: i64) -> i128 {
+		if let Some(table) = &self.table {
+			if year >= i32::MIN as i64 && year <= i32::MAX as i64 {
+				if let Some(day) = table.year_start(year as i32) {
+					return day;
+				}
+			}
+			// The year right after the table.
+			if let Some((_, last)) = table.years() {
+				if year == last as i64 + 1 {
+					return table.end();
+				}
+			}
+		}
+
+		let leaps = (11 * (year - 1) + self.pattern.offset()).div_euclid(30);
+		self.epoch.to_rata_die() + (year as i128 - 1) * 354 + leaps as i128
+	}
+
+	/// Convert an [`IslamicDate`] into a Rata Die
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let calendar = IslamicCalendar::DEFAULT;
+	/// assert_eq!(calendar.to_rata_die(IslamicDate::new(1, 1, 1)), 227015);
+	/// assert_eq!(calendar.to_rata_die(IslamicDate::new(1364, 12, 6)), 710347);
+	/// ```
+	pub const fn to_rata_die(&self, date: IslamicDate) -> i128 {
+		let mut day = self.year_start(date.year as i64);
+		let mut m = 1;
+		while m < date.month.inner() {
+			// SAFETY: `m` is `1..12`
+			day += self.days_in_month(date.year, unsafe { IslamicMonth::new_unchecked(m) }) as i128;
+			m += 1;
+		}
+		day + date.day as i128 - 1
+	}
+
+	/// Convert a Rata Die into an [`IslamicDate`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let calendar = IslamicCalendar::DEFAULT;
+	/// assert_eq!(calendar.from_rata_die(227015), IslamicDate::new(1, 1, 1));
+	/// assert_eq!(calendar.from_rata_die(710347), IslamicDate::new(1364, 12, 6));
+	/// ```
+	pub const fn from_rata_die(&self, rata_die: i128) -> IslamicDate {
+		// Estimate, then correct.
+		let mut year = ((rata_die - self.epoch.to_rata_die()) * 30).div_euclid(10631) as i64 + 1;
+		while self.year_start(year + 1) <= rata_die {
+			year += 1;
+		}
+		while self.year_start(year) > rata_die {
+			year -= 1;
+		}
+
+		let year = year as i32;
+		let mut day = rata_die - self.year_start(year as i64);
+		let mut month = IslamicMonth::Muharram;
+		loop {
+			let days = self.days_in_month(year, month) as i128;
+			if day < days || month.inner() == 12 {
+				break;
+			}
+			day -= days;
+			month = month.next_saturating();
+		}
+
+		IslamicDate { year, month, day: day as u8 + 1 }
+	}
+
+	#[inline]
+	/// Convert an [`IslamicDate`] into a [`Date`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let calendar = IslamicCalendar::DEFAULT;
+	/// assert_eq!(calendar.to_date(IslamicDate::new(1445, 1, 1)), Date::new(2023, 7, 19));
+	/// ```
+	pub const fn to_date(&self, date: IslamicDate) -> Date {
+		Date::from_rata_die(self.to_rata_die(date))
+	}
+
+	#[inline]
+	/// Convert a [`Date`] into an [`IslamicDate`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let calendar = IslamicCalendar::DEFAULT;
+	/// assert_eq!(calendar.from_date(Date::new(2023, 7, 19)), IslamicDate::new(1445, 1, 1));
+	/// ```
+	pub const fn from_date(&self, date: Date) -> IslamicDate {
+		self.from_rata_die(date.to_rata_die())
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- IslamicDate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Islamic (Hijri) calendar date
+///
+/// The conversion rules live in [`IslamicCalendar`],
+/// [`IslamicDate::from_date`] and [`IslamicDate::to_date`] use [`IslamicCalendar::DEFAULT`].
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = IslamicDate::from_date(Date::new(2023, 10, 20));
+/// assert_eq!(date, IslamicDate::new(1445, 4, 5));
+/// assert_eq!(date.month().as_str(), "Rabi' al-Thani");
+/// assert_eq!(date.to_date(), Date::new(2023, 10, 20));
+/// ```
+pub struct IslamicDate {
+	year: i32,
+	month: IslamicMonth,
+	day: u8,
+}
+
+impl IslamicDate {
+	#[inline]
+	/// Create a new [`IslamicDate`] from numbers
+	///
+	/// ## Panics
+	/// This function panics if:
+	/// - `month` is not `1..=12`
+	/// - `day` is not `1..=30`
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// IslamicDate::new(1445, 1, 31);
+	/// ```
+	pub const fn new(year: i32, month: u8, day: u8) -> Self {
+		assert!(day != 0, "day was 0");
+		assert!(day < 31, "day was greater than 30");
+		Self { year, month: IslamicMonth::new(month), day }
+	}
+
+	#[inline]
+	/// Create a new [`IslamicDate`] from a typed [`IslamicMonth`]
+	///
+	/// ## Panics
+	/// This function panics if `day` is not `1..=30`.
+	pub const fn new_typed(year: i32, month: IslamicMonth, day: u8) -> Self {
+		assert!(day != 0, "day was 0");
+		assert!(day < 31, "day was greater than 30");
+		Self { year, month, day }
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicDate::new(1445, 4, 5).year(), 1445);
+	/// ```
+	pub const fn year(self) -> i32 {
+		self.year
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicDate::new(1445, 4, 5).month(), IslamicMonth::RabiAlThani);
+	/// ```
+	pub const fn month(self) -> IslamicMonth {
+		self.month
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicDate::new(1445, 4, 5).day(), 5);
+	/// ```
+	pub const fn day(self) -> u8 {
+		self.day
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicDate::new(1445, 4, 5).inner(), (1445, 4, 5));
+	/// ```
+	pub const fn inner(self) -> (i32, u8, u8) {
+		(self.year, self.month.inner(), self.day)
+	}
+
+	#[inline]
+	/// Convert a [`Date`] using [`IslamicCalendar::DEFAULT`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicDate::from_date(Date::new(2023, 3, 23)), IslamicDate::new(1444, 9, 1));
+	/// ```
+	pub const fn from_date(date: Date) -> Self {
+		IslamicCalendar::DEFAULT.from_date(date)
+	}
+
+	#[inline]
+	/// Convert into a [`Date`] using [`IslamicCalendar::DEFAULT`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(IslamicDate::new(1444, 9, 1).to_date(), Date::new(2023, 3, 23));
+	/// ```
+	pub const fn to_date(self) -> Date {
+		IslamicCalendar::DEFAULT.to_date(self)
+	}
+}
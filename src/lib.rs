@@ -66,4 +66,82 @@ mod free;
 pub use free::*;
 
 mod days_in_month;
-pub use days_in_month::*;
\ No newline at end of file
+pub use days_in_month::*;
+
+mod islamic;
+pub use islamic::*;
+
+mod hebrew;
+pub use hebrew::*;
+
+mod persian;
+pub use persian::*;
+
+mod coptic;
+pub use coptic::*;
+
+mod ethiopian;
+pub use ethiopian::*;
+
+mod chinese;
+pub use chinese::*;
+
+mod astronomy;
+
+mod scan;
+
+mod unicode;
+
+mod solar_term;
+pub use solar_term::*;
+
+mod date_range;
+pub use date_range::*;
+
+mod moon;
+pub use moon::*;
+
+mod era;
+pub use era::*;
+
+mod revised_julian;
+pub use revised_julian::*;
+
+mod mayan;
+pub use mayan::*;
+
+mod format;
+pub use format::*;
+
+mod parse;
+pub use parse::*;
+
+mod date_parser;
+pub use date_parser::*;
+
+mod relative;
+pub use relative::*;
+
+mod humanize;
+pub use humanize::*;
+
+mod locale;
+pub use locale::*;
+
+mod matcher;
+pub use matcher::*;
+
+mod find;
+pub use find::*;
+
+mod infer;
+pub use infer::*;
+
+mod period;
+pub use period::*;
+
+mod pivot;
+pub use pivot::*;
+
+mod historical;
+pub use historical::*;
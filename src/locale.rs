@@ -0,0 +1,610 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::month::Month;
+use crate::weekday::Weekday;
+
+//---------------------------------------------------------------------------------------------------- NameWidth
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// How long a month or weekday name from a [`Locale`] is
+pub enum NameWidth {
+	#[default]
+	/// `January`, `Sunday`
+	Wide = 1,
+	/// `Jan`, `Sun`
+	Abbreviated = 2,
+	/// `J`, `S`, these are not unique
+	Narrow = 3,
+}
+
+impl NameWidth {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(NameWidth::ALL, [NameWidth::Wide, NameWidth::Abbreviated, NameWidth::Narrow]);
+	/// ```
+	pub const ALL: [Self; 3] = [
+		Self::Wide,
+		Self::Abbreviated,
+		Self::Narrow,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(NameWidth::Wide.as_str(),        "wide");
+	/// assert_eq!(NameWidth::Abbreviated.as_str(), "abbreviated");
+	/// assert_eq!(NameWidth::Narrow.as_str(),      "narrow");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Wide        => "wide",
+			Self::Abbreviated => "abbreviated",
+			Self::Narrow      => "narrow",
+		}
+	}
+
+	#[inline]
+	/// The index into the tables of a [`Locale`]
+	const fn index(self) -> usize {
+		self as usize - 1
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Ordinals
+/// `1st`, `2nd`, `3rd`, `4th`, `11th`
+fn ordinal_en(n: u32) -> (&'static str, &'static str) {
+	let suffix = match (n % 10, n % 100) {
+		(_, 11..=13) => "th",
+		(1, _) => "st",
+		(2, _) => "nd",
+		(3, _) => "rd",
+		_ => "th",
+	};
+	("", suffix)
+}
+
+/// `第1`
+fn ordinal_cjk(_: u32) -> (&'static str, &'static str) {
+	("第", "")
+}
+
+/// `1er`, `2e`
+fn ordinal_fr(n: u32) -> (&'static str, &'static str) {
+	("", if n == 1 { "er" } else { "e" })
+}
+
+/// `1.`
+fn ordinal_de(_: u32) -> (&'static str, &'static str) {
+	("", ".")
+}
+
+/// `1.º`
+fn ordinal_es(_: u32) -> (&'static str, &'static str) {
+	("", ".º")
+}
+
+/// `1º`
+fn ordinal_pt(_: u32) -> (&'static str, &'static str) {
+	("", "º")
+}
+
+/// `1번째`
+fn ordinal_ko(_: u32) -> (&'static str, &'static str) {
+	("", "번째")
+}
+
+//---------------------------------------------------------------------------------------------------- Tables
+const NARROW_MONTHS: [&str; 12] = ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"];
+const NUMBER_MONTHS: [&str; 12] = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"];
+const KANJI_MONTHS: [&str; 12] = ["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"];
+const JA_MONTHS: [&str; 12] = ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"];
+const KO_MONTHS: [&str; 12] = ["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"];
+
+const EN_MONTHS: [[&str; 12]; 3] = [
+	["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"],
+	["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+	NARROW_MONTHS,
+];
+const EN_WEEKDAYS: [[&str; 7]; 3] = [
+	["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"],
+	["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+	["S", "M", "T", "W", "T", "F", "S"],
+];
+
+const JA_MONTHS_ALL: [[&str; 12]; 3] = [KANJI_MONTHS, JA_MONTHS, NUMBER_MONTHS];
+const JA_WEEKDAYS: [[&str; 7]; 3] = [
+	["日曜日", "月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日"],
+	["日", "月", "火", "水", "木", "金", "土"],
+	["日", "月", "火", "水", "木", "金", "土"],
+];
+
+const FR_MONTHS: [[&str; 12]; 3] = [
+	["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"],
+	["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."],
+	NARROW_MONTHS,
+];
+const FR_WEEKDAYS: [[&str; 7]; 3] = [
+	["dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi"],
+	["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+	["D", "L", "M", "M", "J", "V", "S"],
+];
+
+const DE_MONTHS: [[&str; 12]; 3] = [
+	["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"],
+	["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez."],
+	NARROW_MONTHS,
+];
+const DE_MONTHS_STANDALONE: [[&str; 12]; 3] = [
+	DE_MONTHS[0],
+	["Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"],
+	NARROW_MONTHS,
+];
+const DE_WEEKDAYS: [[&str; 7]; 3] = [
+	["Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag"],
+	["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+	["S", "M", "D", "M", "D", "F", "S"],
+];
+const DE_WEEKDAYS_STANDALONE: [[&str; 7]; 3] = [
+	DE_WEEKDAYS[0],
+	["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+	DE_WEEKDAYS[2],
+];
+
+const ES_MONTHS: [[&str; 12]; 3] = [
+	["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"],
+	["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"],
+	["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+];
+const ES_WEEKDAYS: [[&str; 7]; 3] = [
+	["domingo", "lunes", "martes", "miércoles", "jueves", "viernes", "sábado"],
+	["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+	["D", "L", "M", "X", "J", "V", "S"],
+];
+
+const PT_MONTHS: [[&str; 12]; 3] = [
+	["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"],
+	["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."],
+	NARROW_MONTHS,
+];
+const PT_WEEKDAYS: [[&str; 7]; 3] = [
+	["domingo", "segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira", "sábado"],
+	["dom.", "seg.", "ter.", "qua.", "qui.", "sex.", "sáb."],
+	["D", "S", "T", "Q", "Q", "S", "S"],
+];
+
+const ZH_MONTHS: [[&str; 12]; 3] = [KANJI_MONTHS, JA_MONTHS, NUMBER_MONTHS];
+const ZH_WEEKDAYS: [[&str; 7]; 3] = [
+	["星期日", "星期一", "星期二", "星期三", "星期四", "星期五", "星期六"],
+	["周日", "周一", "周二", "周三", "周四", "周五", "周六"],
+	["日", "一", "二", "三", "四", "五", "六"],
+];
+
+const KO_MONTHS_ALL: [[&str; 12]; 3] = [KO_MONTHS, KO_MONTHS, KO_MONTHS];
+const KO_WEEKDAYS: [[&str; 7]; 3] = [
+	["일요일", "월요일", "화요일", "수요일", "목요일", "금요일", "토요일"],
+	["일", "월", "화", "수", "목", "금", "토"],
+	["일", "월", "화", "수", "목", "금", "토"],
+];
+
+//---------------------------------------------------------------------------------------------------- Locale
+#[derive(Copy,Clone,Debug)]
+/// Month and weekday names, ordinals and the first day of the week for a language
+///
+/// There are built-in [`Locale`]s for
+/// [English](Locale::EN), [Japanese](Locale::JA), [French](Locale::FR), [German](Locale::DE),
+/// [Spanish](Locale::ES), [Portuguese](Locale::PT), [Chinese](Locale::ZH) and [Korean](Locale::KO).
+///
+/// Other languages can be added by creating a [`Locale`] with the fields filled in.
+///
+/// [`Locale`]s are equal if their [`Locale::code`] is.
+///
+/// ## Format and standalone
+/// Some languages change a name when it is on its own, e.g, a calendar heading,
+/// compared to within a date. [`Locale::month`] is the name within a date,
+/// [`Locale::month_standalone`] is the name on its own.
+///
+/// ```rust
+/// # use nichi::*;
+/// let de = Locale::DE;
+/// assert_eq!(de.month(Month::March, NameWidth::Wide),                   "März");
+/// assert_eq!(de.month(Month::March, NameWidth::Abbreviated),            "März");
+/// assert_eq!(de.month_standalone(Month::March, NameWidth::Abbreviated), "Mär");
+/// assert_eq!(de.weekday(Weekday::Monday, NameWidth::Abbreviated),       "Mo.");
+/// assert_eq!(de.weekday_standalone(Weekday::Monday, NameWidth::Abbreviated), "Mo");
+/// ```
+///
+/// ## Examples
+/// ```rust
+/// # use nichi::*;
+/// let month = Month::August;
+/// let weekday = Weekday::Tuesday;
+///
+/// assert_eq!(Locale::EN.month(month, NameWidth::Wide), "August");
+/// assert_eq!(Locale::JA.month(month, NameWidth::Wide), "八月");
+/// assert_eq!(Locale::FR.month(month, NameWidth::Wide), "août");
+/// assert_eq!(Locale::DE.month(month, NameWidth::Wide), "August");
+/// assert_eq!(Locale::ES.month(month, NameWidth::Wide), "agosto");
+/// assert_eq!(Locale::PT.month(month, NameWidth::Wide), "agosto");
+/// assert_eq!(Locale::ZH.month(month, NameWidth::Wide), "八月");
+/// assert_eq!(Locale::KO.month(month, NameWidth::Wide), "8월");
+///
+/// assert_eq!(Locale::EN.weekday(weekday, NameWidth::Abbreviated), "Tue");
+/// assert_eq!(Locale::JA.weekday(weekday, NameWidth::Abbreviated), "火");
+/// assert_eq!(Locale::FR.weekday(weekday, NameWidth::Abbreviated), "mar.");
+/// assert_eq!(Locale::DE.weekday(weekday, NameWidth::Abbreviated), "Di.");
+/// assert_eq!(Locale::ES.weekday(weekday, NameWidth::Abbreviated), "mar");
+/// assert_eq!(Locale::PT.weekday(weekday, NameWidth::Abbreviated), "ter.");
+/// assert_eq!(Locale::ZH.weekday(weekday, NameWidth::Abbreviated), "周二");
+/// assert_eq!(Locale::KO.weekday(weekday, NameWidth::Abbreviated), "화");
+///
+/// assert_eq!(Locale::EN.ordinal(22), "22nd");
+/// assert_eq!(Locale::FR.ordinal(1),  "1er");
+/// assert_eq!(Locale::DE.ordinal(3),  "3.");
+/// assert_eq!(Locale::ZH.ordinal(3),  "第3");
+/// assert_eq!(Locale::KO.ordinal(3),  "3번째");
+///
+/// assert_eq!(Locale::EN.week_start, Weekday::Sunday);
+/// assert_eq!(Locale::FR.week_start, Weekday::Monday);
+/// ```
+///
+/// ## Custom
+/// ```rust
+/// # use nichi::*;
+/// const MONTHS: [[&str; 12]; 3] = [
+/// 	["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"],
+/// 	["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"],
+/// 	["G", "F", "M", "A", "M", "G", "L", "A", "S", "O", "N", "D"],
+/// ];
+/// const WEEKDAYS: [[&str; 7]; 3] = [
+/// 	["domenica", "lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato"],
+/// 	["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+/// 	["D", "L", "M", "M", "G", "V", "S"],
+/// ];
+///
+/// const IT: Locale = Locale {
+/// 	code: "it",
+/// 	name: "Italian",
+/// 	months: &MONTHS,
+/// 	months_standalone: &MONTHS,
+/// 	weekdays: &WEEKDAYS,
+/// 	weekdays_standalone: &WEEKDAYS,
+/// 	ordinal: |_| ("", "º"),
+/// 	week_start: Weekday::Monday,
+/// };
+///
+/// assert_eq!(IT.month(Month::May, NameWidth::Wide), "maggio");
+/// assert_eq!(IT.parse_weekday("Giovedì"), Some(Weekday::Thursday));
+/// assert_eq!(Date::new(2023, 5, 4).format("%A %-d %B").locale(&IT).to_string(), "giovedì 4 maggio");
+/// ```
+pub struct Locale {
+	/// The language code, e.g, `fr`
+	pub code: &'static str,
+	/// The name of the language in English, e.g, `French`
+	pub name: &'static str,
+	/// Month names within a date, indexed by [`NameWidth`], January first
+	pub months: &'static [[&'static str; 12]; 3],
+	/// Month names on their own, indexed by [`NameWidth`], January first
+	pub months_standalone: &'static [[&'static str; 12]; 3],
+	/// Weekday names within a date, indexed by [`NameWidth`], Sunday first
+	pub weekdays: &'static [[&'static str; 7]; 3],
+	/// Weekday names on their own, indexed by [`NameWidth`], Sunday first
+	pub weekdays_standalone: &'static [[&'static str; 7]; 3],
+	/// The prefix and suffix around an ordinal number, e.g, `("", "st")` for `1`
+	pub ordinal: fn(u32) -> (&'static str, &'static str),
+	/// The first day of the week
+	pub week_start: Weekday,
+}
+
+impl Locale {
+	/// English (United States)
+	pub const EN: Self = Self {
+		code: "en",
+		name: "English",
+		months: &EN_MONTHS,
+		months_standalone: &EN_MONTHS,
+		weekdays: &EN_WEEKDAYS,
+		weekdays_standalone: &EN_WEEKDAYS,
+		ordinal: ordinal_en,
+		week_start: Weekday::Sunday,
+	};
+
+	/// Japanese, the same names as [`Month::as_str_jp`] and [`Weekday::as_str_jp`]
+	pub const JA: Self = Self {
+		code: "ja",
+		name: "Japanese",
+		months: &JA_MONTHS_ALL,
+		months_standalone: &JA_MONTHS_ALL,
+		weekdays: &JA_WEEKDAYS,
+		weekdays_standalone: &JA_WEEKDAYS,
+		ordinal: ordinal_cjk,
+		week_start: Weekday::Sunday,
+	};
+
+	/// French
+	pub const FR: Self = Self {
+		code: "fr",
+		name: "French",
+		months: &FR_MONTHS,
+		months_standalone: &FR_MONTHS,
+		weekdays: &FR_WEEKDAYS,
+		weekdays_standalone: &FR_WEEKDAYS,
+		ordinal: ordinal_fr,
+		week_start: Weekday::Monday,
+	};
+
+	/// German
+	pub const DE: Self = Self {
+		code: "de",
+		name: "German",
+		months: &DE_MONTHS,
+		months_standalone: &DE_MONTHS_STANDALONE,
+		weekdays: &DE_WEEKDAYS,
+		weekdays_standalone: &DE_WEEKDAYS_STANDALONE,
+		ordinal: ordinal_de,
+		week_start: Weekday::Monday,
+	};
+
+	/// Spanish
+	pub const ES: Self = Self {
+		code: "es",
+		name: "Spanish",
+		months: &ES_MONTHS,
+		months_standalone: &ES_MONTHS,
+		weekdays: &ES_WEEKDAYS,
+		weekdays_standalone: &ES_WEEKDAYS,
+		ordinal: ordinal_es,
+		week_start: Weekday::Monday,
+	};
+
+	/// Portuguese (Brazil)
+	pub const PT: Self = Self {
+		code: "pt",
+		name: "Portuguese",
+		months: &PT_MONTHS,
+		months_standalone: &PT_MONTHS,
+		weekdays: &PT_WEEKDAYS,
+		weekdays_standalone: &PT_WEEKDAYS,
+		ordinal: ordinal_pt,
+		week_start: Weekday::Sunday,
+	};
+
+	/// Chinese (Simplified)
+	pub const ZH: Self = Self {
+		code: "zh",
+		name: "Chinese",
+		months: &ZH_MONTHS,
+		months_standalone: &ZH_MONTHS,
+		weekdays: &ZH_WEEKDAYS,
+		weekdays_standalone: &ZH_WEEKDAYS,
+		ordinal: ordinal_cjk,
+		week_start: Weekday::Monday,
+	};
+
+	/// Korean
+	pub const KO: Self = Self {
+		code: "ko",
+		name: "Korean",
+		months: &KO_MONTHS_ALL,
+		months_standalone: &KO_MONTHS_ALL,
+		weekdays: &KO_WEEKDAYS,
+		weekdays_standalone: &KO_WEEKDAYS,
+		ordinal: ordinal_ko,
+		week_start: Weekday::Sunday,
+	};
+
+	/// Every built-in [`Locale`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Locale::ALL.len(), 8);
+	/// ```
+	pub const ALL: [Self; 8] = [
+		Self::EN,
+		Self::JA,
+		Self::FR,
+		Self::DE,
+		Self::ES,
+		Self::PT,
+		Self::ZH,
+		Self::KO,
+	];
+
+	/// Find a built-in [`Locale`] by its language code, ignoring case and any region
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Locale::from_code("fr"),    Some(&Locale::FR));
+	/// assert_eq!(Locale::from_code("pt-BR"), Some(&Locale::PT));
+	/// assert_eq!(Locale::from_code("ZH_cn"), Some(&Locale::ZH));
+	/// assert_eq!(Locale::from_code("xx"),    None);
+	/// ```
+	pub fn from_code(code: &str) -> Option<&'static Self> {
+		let language = code.split(['-', '_']).next()?;
+		match language.to_ascii_lowercase().as_str() {
+			"en" => Some(&Self::EN),
+			"ja" => Some(&Self::JA),
+			"fr" => Some(&Self::FR),
+			"de" => Some(&Self::DE),
+			"es" => Some(&Self::ES),
+			"pt" => Some(&Self::PT),
+			"zh" => Some(&Self::ZH),
+			"ko" => Some(&Self::KO),
+			_ => None,
+		}
+	}
+
+	#[inline]
+	/// The name of `month` within a date
+	pub const fn month(&self, month: Month, width: NameWidth) -> &'static str {
+		self.months[width.index()][month.inner() as usize - 1]
+	}
+
+	#[inline]
+	/// The name of `month` on its own
+	pub const fn month_standalone(&self, month: Month, width: NameWidth) -> &'static str {
+		self.months_standalone[width.index()][month.inner() as usize - 1]
+	}
+
+	#[inline]
+	/// The name of `weekday` within a date
+	pub const fn weekday(&self, weekday: Weekday, width: NameWidth) -> &'static str {
+		self.weekdays[width.index()][weekday.inner() as usize - 1]
+	}
+
+	#[inline]
+	/// The name of `weekday` on its own
+	pub const fn weekday_standalone(&self, weekday: Weekday, width: NameWidth) -> &'static str {
+		self.weekdays_standalone[width.index()][weekday.inner() as usize - 1]
+	}
+
+	#[inline]
+	/// The prefix and suffix around the ordinal `n`
+	pub fn ordinal_affixes(&self, n: u32) -> (&'static str, &'static str) {
+		(self.ordinal)(n)
+	}
+
+	/// The ordinal `n`, e.g, `1st`
+	pub fn ordinal(&self, n: u32) -> String {
+		let (prefix, suffix) = self.ordinal_affixes(n);
+		format!("{prefix}{n}{suffix}")
+	}
+
+	/// The days of the week, starting at [`Locale::week_start`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Locale::DE.week()[0], Weekday::Monday);
+	/// assert_eq!(Locale::DE.week()[6], Weekday::Sunday);
+	/// ```
+	pub const fn week(&self) -> [Weekday; 7] {
+		let start = self.week_start.inner() as usize - 1;
+		let mut week = [Weekday::Sunday; 7];
+		let mut i = 0;
+		while i < 7 {
+			week[i] = Weekday::ALL[(start + i) % 7];
+			i += 1;
+		}
+		week
+	}
+
+	/// Parse a wide or abbreviated month name, ignoring case and a trailing `.`
+	///
+	/// Standalone names are accepted too, narrow names are not since they are not unique.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Locale::FR.parse_month("Août"),  Some(Month::August));
+	/// assert_eq!(Locale::FR.parse_month("janv"),  Some(Month::January));
+	/// assert_eq!(Locale::DE.parse_month("mär"),   Some(Month::March));
+	/// assert_eq!(Locale::ZH.parse_month("十二月"), Some(Month::December));
+	/// assert_eq!(Locale::KO.parse_month("12월"),  Some(Month::December));
+	/// assert_eq!(Locale::FR.parse_month("J"),     None);
+	/// ```
+	pub fn parse_month(&self, s: &str) -> Option<Month> {
+		find_name(s, [self.months, self.months_standalone]).map(|i| Month::new(i as u8 + 1))
+	}
+
+	/// Parse a wide or abbreviated weekday name, ignoring case and a trailing `.`
+	///
+	/// Standalone names are accepted too, narrow names are not since they are not unique.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Locale::ES.parse_weekday("Miércoles"), Some(Weekday::Wednesday));
+	/// assert_eq!(Locale::PT.parse_weekday("seg."),      Some(Weekday::Monday));
+	/// assert_eq!(Locale::ZH.parse_weekday("星期五"),    Some(Weekday::Friday));
+	/// assert_eq!(Locale::ES.parse_weekday("X"),         None);
+	/// ```
+	pub fn parse_weekday(&self, s: &str) -> Option<Weekday> {
+		find_name(s, [self.weekdays, self.weekdays_standalone]).map(|i| Weekday::new(i as u8 + 1))
+	}
+}
+
+impl Default for Locale {
+	fn default() -> Self {
+		Self::EN
+	}
+}
+
+impl PartialEq for Locale {
+	fn eq(&self, other: &Self) -> bool {
+		self.code == other.code
+	}
+}
+
+impl Eq for Locale {}
+
+impl std::hash::Hash for Locale {
+	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+		self.code.hash(state);
+	}
+}
+
+/// The index of `s` in the wide or abbreviated names of `tables`
+fn find_name<const N: usize>(s: &str, tables: [&[[&str; N]; 3]; 2]) -> Option<usize> {
+	let s = s.trim();
+	let s = s.strip_suffix('.').unwrap_or(s).to_lowercase();
+	if s.is_empty() {
+		return None;
+	}
+
+	tables.into_iter()
+		.flat_map(|table| &table[..2])
+		.find_map(|names| {
+			names.iter().position(|name| {
+				let name = name.strip_suffix('.').unwrap_or(name);
+				name.to_lowercase() == s
+			})
+		})
+}
+
+//---------------------------------------------------------------------------------------------------- Month, Weekday
+impl Month {
+	#[inline]
+	/// The wide name of this [`Month`] in `locale`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Month::March.as_str_locale(&Locale::ES), "marzo");
+	/// ```
+	pub const fn as_str_locale(self, locale: &Locale) -> &'static str {
+		locale.month(self, NameWidth::Wide)
+	}
+
+	#[inline]
+	/// Parse a wide or abbreviated month name in `locale`, see [`Locale::parse_month`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Month::from_str_locale("marzo", &Locale::ES), Some(Month::March));
+	/// assert_eq!(Month::from_str_locale("marzo", &Locale::EN), None);
+	/// ```
+	pub fn from_str_locale(s: &str, locale: &Locale) -> Option<Self> {
+		locale.parse_month(s)
+	}
+}
+
+impl Weekday {
+	#[inline]
+	/// The wide name of this [`Weekday`] in `locale`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Weekday::Monday.as_str_locale(&Locale::KO), "월요일");
+	/// ```
+	pub const fn as_str_locale(self, locale: &Locale) -> &'static str {
+		locale.weekday(self, NameWidth::Wide)
+	}
+
+	#[inline]
+	/// Parse a wide or abbreviated weekday name in `locale`, see [`Locale::parse_weekday`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Weekday::from_str_locale("Montag", &Locale::DE), Some(Weekday::Monday));
+	/// ```
+	pub fn from_str_locale(s: &str, locale: &Locale) -> Option<Self> {
+		locale.parse_weekday(s)
+	}
+}
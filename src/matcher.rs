@@ -0,0 +1,370 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::month::Month;
+use crate::weekday::Weekday;
+use crate::day::Day;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Month names other than the full and 3 letter ones
+const MONTH_ALIASES: [(&str, Month); 1] = [
+	("sept", Month::September),
+];
+
+/// Weekday names other than the full and 3 letter ones
+const WEEKDAY_ALIASES: [(&str, Weekday); 4] = [
+	("tues", Weekday::Tuesday),
+	("weds", Weekday::Wednesday),
+	("thur", Weekday::Thursday),
+	("thurs", Weekday::Thursday),
+];
+
+//---------------------------------------------------------------------------------------------------- MatchKind
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// How a [`NameMatch`] was found, from most to least certain
+pub enum MatchKind {
+	#[default]
+	/// The input is accepted by the exact `from_str`, e.g, `January`
+	Exact = 1,
+	/// The input matched after ignoring case, periods, hyphens and spaces, e.g, `jAn.`
+	Folded = 2,
+	/// The input is the start of only 1 name, e.g, `Septem`
+	Prefix = 3,
+	/// The input is a misspelling of only 1 name, e.g, `Febuary`
+	Corrected = 4,
+}
+
+impl MatchKind {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(MatchKind::ALL.len(), 4);
+	/// ```
+	pub const ALL: [Self; 4] = [
+		Self::Exact,
+		Self::Folded,
+		Self::Prefix,
+		Self::Corrected,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(MatchKind::Exact.as_str(),     "exact");
+	/// assert_eq!(MatchKind::Folded.as_str(),    "folded");
+	/// assert_eq!(MatchKind::Prefix.as_str(),    "prefix");
+	/// assert_eq!(MatchKind::Corrected.as_str(), "corrected");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Exact     => "exact",
+			Self::Folded    => "folded",
+			Self::Prefix    => "prefix",
+			Self::Corrected => "corrected",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- NameMatch
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd)]
+/// A name found by [`NameMatcher`]
+pub struct NameMatch<T> {
+	value: T,
+	kind: MatchKind,
+	confidence: f64,
+}
+
+impl<T: Copy> NameMatch<T> {
+	#[inline]
+	/// The [`Month`], [`Weekday`] or [`Day`]
+	pub const fn value(&self) -> T {
+		self.value
+	}
+
+	#[inline]
+	/// How the name was found
+	pub const fn kind(&self) -> MatchKind {
+		self.kind
+	}
+
+	#[inline]
+	/// How likely this is the intended name, `0.0..=1.0`
+	///
+	/// [`MatchKind::Exact`] and [`MatchKind::Folded`] are `1.0`.
+	///
+	/// [`MatchKind::Prefix`] is higher the more of the name was typed.
+	///
+	/// [`MatchKind::Corrected`] is lower the more edits the name needed.
+	pub const fn confidence(&self) -> f64 {
+		self.confidence
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- NameMatcher
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// Forgiving [`Month`], [`Weekday`] and [`Day`] name parsing
+///
+/// The exact `from_str` functions only accept a few casings of a few forms,
+/// this tries, in order:
+///
+/// 1. The exact `const` path, e.g, [`Month::from_str`]
+/// 1. Ignoring ASCII case, a trailing `.`, and `-`, `_` or spaces, e.g, `jAn.`, `twenty-fifth`
+/// 1. Other common abbreviations, e.g, `Sept`, `Tues`, `Weds`, `Thurs`
+/// 1. The start of only 1 name, at least [`NameMatcher::min_prefix`] long, e.g, `Septem`, `Wedn`
+/// 1. The only name within [`NameMatcher::max_distance`] edits, e.g, `Febuary`, `Wendsday`
+///
+/// An edit is inserting, removing or replacing a letter, or swapping 2 letters next to each other.
+/// Inputs allow 1 edit per 3 letters, up to [`NameMatcher::max_distance`].
+///
+/// If 2 different names are equally close, nothing is returned.
+///
+/// ```rust
+/// # use nichi::*;
+/// let matcher = NameMatcher::new();
+///
+/// assert_eq!(matcher.month("jAnUaRy").unwrap().value(), Month::January);
+/// assert_eq!(matcher.month("Sept").unwrap().value(),    Month::September);
+/// assert_eq!(matcher.month("Jan.").unwrap().value(),    Month::January);
+/// assert_eq!(matcher.month("Septem").unwrap().value(),  Month::September);
+/// assert_eq!(matcher.month("Febuary").unwrap().value(), Month::February);
+/// assert_eq!(matcher.month("Ju"),                       None); // June or July?
+///
+/// assert_eq!(matcher.weekday("Weds").unwrap().value(),     Weekday::Wednesday);
+/// assert_eq!(matcher.weekday("wendsday").unwrap().value(), Weekday::Wednesday);
+/// assert_eq!(matcher.weekday("T"),                         None);
+///
+/// assert_eq!(matcher.day("twenty-fifth").unwrap().value(), Day::TwentyFifth);
+/// assert_eq!(matcher.day("Twenty Fifth").unwrap().value(), Day::TwentyFifth);
+/// assert_eq!(matcher.day("25Th").unwrap().value(),         Day::TwentyFifth);
+/// assert_eq!(matcher.day("twentyfith").unwrap().value(),   Day::TwentyFifth);
+///
+/// // How the name was found is reported.
+/// let m = matcher.month("January").unwrap();
+/// assert_eq!((m.kind(), m.confidence()), (MatchKind::Exact, 1.0));
+///
+/// let m = matcher.month("JANUARY.").unwrap();
+/// assert_eq!((m.kind(), m.confidence()), (MatchKind::Folded, 1.0));
+///
+/// let m = matcher.month("Septem").unwrap();
+/// assert_eq!(m.kind(), MatchKind::Prefix);
+/// assert!((m.confidence() - 0.833).abs() < 0.001);
+///
+/// let m = matcher.month("Febuary").unwrap();
+/// assert_eq!(m.kind(), MatchKind::Corrected);
+/// assert_eq!(m.confidence(), 0.875);
+///
+/// // Corrections can be turned off.
+/// assert_eq!(matcher.max_distance(0).month("Febuary"), None);
+/// ```
+pub struct NameMatcher {
+	min_prefix: u8,
+	max_distance: u8,
+}
+
+impl NameMatcher {
+	#[inline]
+	/// Create a new [`NameMatcher`]
+	///
+	/// | Setting | Default |
+	/// |---------|---------|
+	/// | [`NameMatcher::min_prefix`] | `3` |
+	/// | [`NameMatcher::max_distance`] | `2` |
+	pub const fn new() -> Self {
+		Self {
+			min_prefix: 3,
+			max_distance: 2,
+		}
+	}
+
+	#[inline]
+	/// The shortest input to match as the start of a name, `0` turns prefixes off
+	pub const fn min_prefix(mut self, min_prefix: u8) -> Self {
+		self.min_prefix = min_prefix;
+		self
+	}
+
+	#[inline]
+	/// The most edits to correct a misspelling with, `0` turns corrections off
+	pub const fn max_distance(mut self, max_distance: u8) -> Self {
+		self.max_distance = max_distance;
+		self
+	}
+
+	/// Match a [`Month`] name
+	pub fn month(&self, s: &str) -> Option<NameMatch<Month>> {
+		let names = Month::ALL.iter()
+			.flat_map(|m| [(m.as_str_lower(), *m), (m.as_str_short_lower(), *m)])
+			.chain(MONTH_ALIASES);
+		self.find(s, Month::from_bytes, names)
+	}
+
+	/// Match a [`Weekday`] name
+	pub fn weekday(&self, s: &str) -> Option<NameMatch<Weekday>> {
+		let names = Weekday::ALL.iter()
+			.flat_map(|w| [(w.as_str_lower(), *w), (w.as_str_short_lower(), *w)])
+			.chain(WEEKDAY_ALIASES);
+		self.find(s, Weekday::from_bytes, names)
+	}
+
+	/// Match a [`Day`] name, e.g, `twenty-fifth`, or number, e.g, `25Th`
+	pub fn day(&self, s: &str) -> Option<NameMatch<Day>> {
+		let names = Day::ALL.iter().map(|d| (d.as_str_ordinal_lower(), *d));
+		self.find(s, Day::from_bytes, names)
+	}
+
+	fn find<T, I>(&self, s: &str, exact: fn(&[u8]) -> Option<T>, names: I) -> Option<NameMatch<T>>
+	where
+		T: Copy + PartialEq,
+		I: Iterator<Item = (&'static str, T)> + Clone,
+	{
+		let found = |value, kind, confidence| Some(NameMatch { value, kind, confidence });
+
+		// Exact.
+		if let Some(value) = exact(s.as_bytes()) {
+			return found(value, MatchKind::Exact, 1.0);
+		}
+
+		// Folded.
+		let s = s.trim();
+		let s = s.strip_suffix('.').unwrap_or(s);
+		let folded: String = s.chars()
+			.filter(|c| !matches!(c, '-' | '_' | ' '))
+			.map(|c| c.to_ascii_lowercase())
+			.collect();
+		if folded.is_empty() {
+			return None;
+		}
+		if let Some(value) = exact(folded.as_bytes()) {
+			return found(value, MatchKind::Folded, 1.0);
+		}
+		if let Some((_, value)) = names.clone().find(|(name, _)| *name == folded) {
+			return found(value, MatchKind::Folded, 1.0);
+		}
+
+		// Only the best unique `(score, value)` is kept, ties between different values are ambiguous.
+		fn unique<T: Copy + PartialEq>(candidates: impl Iterator<Item = (usize, usize, T)>) -> Option<(usize, usize, T)> {
+			let mut best: Option<(usize, usize, T)> = None;
+			let mut ambiguous = false;
+			for candidate in candidates {
+				match best {
+					Some(b) if candidate.0 > b.0 => continue,
+					Some(b) if candidate.0 == b.0 => {
+						if candidate.2 != b.2 {
+							ambiguous = true;
+						}
+						continue;
+					},
+					_ => {
+						best = Some(candidate);
+						ambiguous = false;
+					},
+				}
+			}
+			if ambiguous { None } else { best }
+		}
+
+		// Prefix.
+		let len = folded.chars().count();
+		if self.min_prefix != 0 && len >= self.min_prefix as usize {
+			let prefixes = names.clone()
+				.filter(|(name, _)| name.starts_with(&folded))
+				.map(|(name, value)| (0, name.len(), value));
+			if let Some((_, name_len, value)) = unique(prefixes) {
+				return found(value, MatchKind::Prefix, 0.5 + 0.5 * len as f64 / name_len as f64);
+			}
+		}
+
+		// Corrected.
+		let max = (self.max_distance as usize).min(len / 3);
+		if max == 0 {
+			return None;
+		}
+		let corrections = names
+			.map(|(name, value)| (distance(&folded, name), name.len(), value))
+			.filter(|(distance, _, _)| *distance <= max);
+		let (distance, name_len, value) = unique(corrections)?;
+		found(value, MatchKind::Corrected, 1.0 - distance as f64 / name_len.max(len) as f64)
+	}
+}
+
+impl Default for NameMatcher {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Free
+/// The edit distance between `a` and `b`, counting swaps of adjacent characters as 1 edit
+fn distance(a: &str, b: &str) -> usize {
+	let a: Vec<char> = a.chars().collect();
+	let b: Vec<char> = b.chars().collect();
+
+	// `rows[i][j]` is the distance between `a[..i]` and `b[..j]`.
+	let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+	for (i, row) in rows.iter_mut().enumerate() {
+		row[0] = i;
+	}
+	for (j, cell) in rows[0].iter_mut().enumerate() {
+		*cell = j;
+	}
+
+	for i in 1..=a.len() {
+		for j in 1..=b.len() {
+			let cost = usize::from(a[i - 1] != b[j - 1]);
+			let mut d = (rows[i - 1][j] + 1)
+				.min(rows[i][j - 1] + 1)
+				.min(rows[i - 1][j - 1] + cost);
+			if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+				d = d.min(rows[i - 2][j - 2] + 1);
+			}
+			rows[i][j] = d;
+		}
+	}
+
+	rows[a.len()][b.len()]
+}
+
+//---------------------------------------------------------------------------------------------------- Month, Weekday, Day
+impl Month {
+	#[inline]
+	/// Parse a [`Month`] forgivingly with the default [`NameMatcher`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Month::from_str_fuzzy("Febuary"), Some(Month::February));
+	/// assert_eq!(Month::from_str("Febuary"),       None);
+	/// ```
+	pub fn from_str_fuzzy(s: &str) -> Option<Self> {
+		NameMatcher::new().month(s).map(|m| m.value())
+	}
+}
+
+impl Weekday {
+	#[inline]
+	/// Parse a [`Weekday`] forgivingly with the default [`NameMatcher`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Weekday::from_str_fuzzy("Weds"), Some(Weekday::Wednesday));
+	/// assert_eq!(Weekday::from_str("Weds"),       None);
+	/// ```
+	pub fn from_str_fuzzy(s: &str) -> Option<Self> {
+		NameMatcher::new().weekday(s).map(|m| m.value())
+	}
+}
+
+impl Day {
+	#[inline]
+	/// Parse a [`Day`] forgivingly with the default [`NameMatcher`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Day::from_str_fuzzy("twenty-fifth"), Some(Day::TwentyFifth));
+	/// assert_eq!(Day::from_str("twenty-fifth"),       None);
+	/// ```
+	pub fn from_str_fuzzy(s: &str) -> Option<Self> {
+		NameMatcher::new().day(s).map(|m| m.value())
+	}
+}
@@ -0,0 +1,893 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::macros::impl_u8_enum;
+
+//---------------------------------------------------------------------------------------------------- Free
+/// If `input` is `name`, ignoring ASCII case and apostrophes
+fn name_matches(name: &str, input: &str) -> bool {
+	let filter = |c: &char| *c != '\'' && *c != '’';
+	name.chars().filter(filter).map(|c| c.to_ascii_lowercase())
+		.eq(input.chars().filter(filter).map(|c| c.to_ascii_lowercase()))
+}
+
+/// Parse `"<number> <name>"`
+fn split_number_name(s: &str) -> Option<(u8, &str)> {
+	let (number, name) = s.trim().split_once(' ')?;
+	if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+	Some((number.parse().ok()?, name.trim()))
+}
+
+//---------------------------------------------------------------------------------------------------- MayanCorrelation
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Correlation constant between the Mayan calendars and the Julian Day Number
+///
+/// This is the Julian Day Number of the Long Count `0.0.0.0.0`,
+/// see [`Date::to_julian_day`].
+///
+/// The default is [`MayanCorrelation::GMT`].
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = Date::new(2012, 12, 21);
+/// assert_eq!(LongCount::from_date(date, MayanCorrelation::GMT).unwrap().to_string(),       "13.0.0.0.0");
+/// assert_eq!(LongCount::from_date(date, MayanCorrelation::LOUNSBURY).unwrap().to_string(), "12.19.19.17.18");
+/// ```
+pub struct MayanCorrelation(pub u32);
+
+impl MayanCorrelation {
+	/// Goodman-Martinez-Thompson, `August 11th, 3114 BCE` (proleptic Gregorian)
+	pub const GMT: Self = Self(584283);
+	/// Lounsbury, 2 days after [`MayanCorrelation::GMT`]
+	pub const LOUNSBURY: Self = Self(584285);
+	/// Spinden, about 260 years before [`MayanCorrelation::GMT`]
+	pub const SPINDEN: Self = Self(489384);
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(MayanCorrelation::GMT.inner(), 584283);
+	/// ```
+	pub const fn inner(self) -> u32 {
+		self.0
+	}
+
+	#[inline]
+	/// Days since the Long Count `0.0.0.0.0`, negative before it
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(MayanCorrelation::GMT.days(Date::new(2012, 12, 21)), 1_872_000);
+	/// ```
+	pub const fn days(self, date: Date) -> i128 {
+		date.to_julian_day() - self.0 as i128
+	}
+}
+
+impl Default for MayanCorrelation {
+	fn default() -> Self {
+		Self::GMT
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- LongCount
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Mayan Long Count, e.g, `13.0.10.17.8`
+///
+/// | Unit     | Days    | Range     |
+/// |----------|---------|-----------|
+/// | b'ak'tun | 144,000 | `0..=255` |
+/// | k'atun   | 7,200   | `0..=19`  |
+/// | tun      | 360     | `0..=19`  |
+/// | winal    | 20      | `0..=17`  |
+/// | k'in     | 1       | `0..=19`  |
+///
+/// ```rust
+/// # use nichi::*;
+/// let long_count = LongCount::from_date(Date::new(2023, 10, 13), MayanCorrelation::GMT).unwrap();
+/// assert_eq!(long_count, LongCount::new(13, 0, 10, 17, 8));
+/// assert_eq!(long_count.to_string(), "13.0.10.17.8");
+/// assert_eq!(LongCount::from_str("13.0.10.17.8"), Some(long_count));
+/// assert_eq!(long_count.to_date(MayanCorrelation::GMT), Date::new(2023, 10, 13));
+///
+/// assert_eq!(long_count.tzolkin().to_string(), "13 Lamat");
+/// assert_eq!(long_count.haab().to_string(),    "16 Yax");
+/// ```
+pub struct LongCount {
+	baktun: u8,
+	katun: u8,
+	tun: u8,
+	winal: u8,
+	kin: u8,
+}
+
+impl LongCount {
+	#[inline]
+	/// Create a new [`LongCount`]
+	///
+	/// ## Panics
+	/// This function panics if `katun`, `tun` or `kin` is `>= 20` or if `winal >= 18`.
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// LongCount::new(13, 0, 0, 18, 0);
+	/// ```
+	pub const fn new(baktun: u8, katun: u8, tun: u8, winal: u8, kin: u8) -> Self {
+		assert!(katun < 20, "katun must be < 20");
+		assert!(tun < 20, "tun must be < 20");
+		assert!(winal < 18, "winal must be < 18");
+		assert!(kin < 20, "kin must be < 20");
+		Self { baktun, katun, tun, winal, kin }
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(LongCount::new(13, 0, 10, 17, 8).inner(), (13, 0, 10, 17, 8));
+	/// ```
+	pub const fn inner(self) -> (u8, u8, u8, u8, u8) {
+		(self.baktun, self.katun, self.tun, self.winal, self.kin)
+	}
+
+	#[inline]
+	/// Days since `0.0.0.0.0`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(LongCount::new(13, 0, 0, 0, 0).days(), 1_872_000);
+	/// ```
+	pub const fn days(self) -> u32 {
+		self.baktun as u32 * 144_000
+			+ self.katun as u32 * 7_200
+			+ self.tun as u32 * 360
+			+ self.winal as u32 * 20
+			+ self.kin as u32
+	}
+
+	#[inline]
+	/// Create a [`LongCount`] from days since `0.0.0.0.0`
+	///
+	/// Returns [`None`] if `days` is negative or past `255.19.19.17.19`.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(LongCount::from_days(1_872_000), Some(LongCount::new(13, 0, 0, 0, 0)));
+	/// assert_eq!(LongCount::from_days(-1), None);
+	/// ```
+	pub const fn from_days(days: i128) -> Option<Self> {
+		if days < 0 || days >= 256 * 144_000 {
+			return None;
+		}
+
+		Some(Self {
+			baktun: (days / 144_000) as u8,
+			katun: (days % 144_000 / 7_200) as u8,
+			tun: (days % 7_200 / 360) as u8,
+			winal: (days % 360 / 20) as u8,
+			kin: (days % 20) as u8,
+		})
+	}
+
+	#[inline]
+	/// Convert a [`Date`] into a [`LongCount`]
+	///
+	/// Returns [`None`] if `date` is before `0.0.0.0.0`.
+	pub const fn from_date(date: Date, correlation: MayanCorrelation) -> Option<Self> {
+		Self::from_days(correlation.days(date))
+	}
+
+	#[inline]
+	/// Convert into a [`Date`]
+	pub const fn to_date(self, correlation: MayanCorrelation) -> Date {
+		Date::from_julian_day(correlation.0 as i128 + self.days() as i128)
+	}
+
+	#[inline]
+	/// The [`Tzolkin`] date of this day
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(LongCount::new(0, 0, 0, 0, 0).tzolkin(), Tzolkin::new(4, TzolkinName::Ajaw));
+	/// ```
+	pub const fn tzolkin(self) -> Tzolkin {
+		Tzolkin::from_days(self.days() as i128)
+	}
+
+	#[inline]
+	/// The [`Haab`] date of this day
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(LongCount::new(0, 0, 0, 0, 0).haab(), Haab::new(8, HaabMonth::Kumku));
+	/// ```
+	pub const fn haab(self) -> Haab {
+		Haab::from_days(self.days() as i128)
+	}
+
+	/// Parse a [`LongCount`] written as `b.k.t.w.k`
+	///
+	/// Returns [`None`] if the string is malformed or a unit is out of range.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(LongCount::from_str("9.12.11.5.18"), Some(LongCount::new(9, 12, 11, 5, 18)));
+	/// assert_eq!(LongCount::from_str(" 13.0.0.0.0 "), Some(LongCount::new(13, 0, 0, 0, 0)));
+	///
+	/// assert_eq!(LongCount::from_str("13.0.0.0"),    None);
+	/// assert_eq!(LongCount::from_str("13.0.0.18.0"), None);
+	/// assert_eq!(LongCount::from_str("13.0.0.0.+0"), None);
+	/// ```
+	pub fn from_str(s: &str) -> Option<Self> {
+		let mut units = [0_u8; 5];
+		let mut parts = s.trim().split('.');
+
+		for unit in units.iter_mut() {
+			let part = parts.next()?;
+			if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+				return None;
+			}
+			*unit = part.parse().ok()?;
+		}
+
+		let [baktun, katun, tun, winal, kin] = units;
+		if parts.next().is_some() || katun >= 20 || tun >= 20 || winal >= 18 || kin >= 20 {
+			return None;
+		}
+
+		Some(Self { baktun, katun, tun, winal, kin })
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- TzolkinName
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Day name in the Tzolk'in
+#[allow(missing_docs)]
+pub enum TzolkinName {
+	#[default]
+	Imix     = 1,
+	Ik       = 2,
+	Akbal    = 3,
+	Kan      = 4,
+	Chikchan = 5,
+	Kimi     = 6,
+	Manik    = 7,
+	Lamat    = 8,
+	Muluk    = 9,
+	Ok       = 10,
+	Chuwen   = 11,
+	Eb       = 12,
+	Ben      = 13,
+	Ix       = 14,
+	Men      = 15,
+	Kib      = 16,
+	Kaban    = 17,
+	Etznab   = 18,
+	Kawak    = 19,
+	Ajaw     = 20,
+}
+
+impl TzolkinName {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(TzolkinName::FIRST, TzolkinName::Imix);
+	/// ```
+	pub const FIRST: Self = Self::Imix;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(TzolkinName::LAST, TzolkinName::Ajaw);
+	/// ```
+	pub const LAST: Self = Self::Ajaw;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(TzolkinName::ALL[0],  TzolkinName::Imix);
+	/// assert_eq!(TzolkinName::ALL[19], TzolkinName::Ajaw);
+	/// ```
+	pub const ALL: [Self; 20] = [
+		Self::Imix,
+		Self::Ik,
+		Self::Akbal,
+		Self::Kan,
+		Self::Chikchan,
+		Self::Kimi,
+		Self::Manik,
+		Self::Lamat,
+		Self::Muluk,
+		Self::Ok,
+		Self::Chuwen,
+		Self::Eb,
+		Self::Ben,
+		Self::Ix,
+		Self::Men,
+		Self::Kib,
+		Self::Kaban,
+		Self::Etznab,
+		Self::Kawak,
+		Self::Ajaw,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(TzolkinName::new(1),  TzolkinName::Imix);
+	/// assert_eq!(TzolkinName::new(20), TzolkinName::Ajaw);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// TzolkinName::new(21);
+	/// ```
+	pub const fn new(name: u8) -> Self {
+		assert!(name != 0, "name must not be 0");
+		assert!(name < 21, "name must not be > 20");
+		// SAFETY: repr(u8)
+		unsafe { Self::new_unchecked(name) }
+	}
+
+	#[inline]
+	/// ## Safety
+	/// `name` must be `1..=20`.
+	pub const unsafe fn new_unchecked(name: u8) -> Self {
+		debug_assert!(name != 0, "name must not be 0");
+		debug_assert!(name < 21, "name must not be > 20");
+		// SAFETY: repr(u8)
+		std::mem::transmute(name)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(TzolkinName::new_saturating(0),  TzolkinName::Imix);
+	/// assert_eq!(TzolkinName::new_saturating(21), TzolkinName::Ajaw);
+	/// ```
+	pub const fn new_saturating(name: u8) -> Self {
+		if name == 0 {
+			Self::FIRST
+		} else if name < 21 {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(name) }
+		} else {
+			Self::LAST
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(TzolkinName::new_wrapping(0),  TzolkinName::Ajaw);
+	/// assert_eq!(TzolkinName::new_wrapping(21), TzolkinName::Imix);
+	/// ```
+	pub const fn new_wrapping(name: u8) -> Self {
+		let name = name % 20;
+		if name == 0 {
+			Self::LAST
+		} else {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(name) }
+		}
+	}
+
+	impl_u8_enum!();
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(TzolkinName::Imix.inner(), 1);
+	/// assert_eq!(TzolkinName::Ajaw.inner(), 20);
+	/// ```
+	pub const fn inner(self) -> u8 {
+		// SAFETY: repr(u8)
+		unsafe { std::mem::transmute(self) }
+	}
+
+	#[inline]
+	/// Yukatek name in the modern orthography
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(TzolkinName::Imix.as_str(),     "Imix");
+	/// assert_eq!(TzolkinName::Ik.as_str(),       "Ik'");
+	/// assert_eq!(TzolkinName::Akbal.as_str(),    "Ak'b'al");
+	/// assert_eq!(TzolkinName::Kan.as_str(),      "K'an");
+	/// assert_eq!(TzolkinName::Chikchan.as_str(), "Chikchan");
+	/// assert_eq!(TzolkinName::Kimi.as_str(),     "Kimi");
+	/// assert_eq!(TzolkinName::Manik.as_str(),    "Manik'");
+	/// assert_eq!(TzolkinName::Lamat.as_str(),    "Lamat");
+	/// assert_eq!(TzolkinName::Muluk.as_str(),    "Muluk");
+	/// assert_eq!(TzolkinName::Ok.as_str(),       "Ok");
+	/// assert_eq!(TzolkinName::Chuwen.as_str(),   "Chuwen");
+	/// assert_eq!(TzolkinName::Eb.as_str(),       "Eb'");
+	/// assert_eq!(TzolkinName::Ben.as_str(),      "B'en");
+	/// assert_eq!(TzolkinName::Ix.as_str(),       "Ix");
+	/// assert_eq!(TzolkinName::Men.as_str(),      "Men");
+	/// assert_eq!(TzolkinName::Kib.as_str(),      "K'ib'");
+	/// assert_eq!(TzolkinName::Kaban.as_str(),    "Kab'an");
+	/// assert_eq!(TzolkinName::Etznab.as_str(),   "Etz'nab'");
+	/// assert_eq!(TzolkinName::Kawak.as_str(),    "Kawak");
+	/// assert_eq!(TzolkinName::Ajaw.as_str(),     "Ajaw");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Imix     => "Imix",
+			Self::Ik       => "Ik'",
+			Self::Akbal    => "Ak'b'al",
+			Self::Kan      => "K'an",
+			Self::Chikchan => "Chikchan",
+			Self::Kimi     => "Kimi",
+			Self::Manik    => "Manik'",
+			Self::Lamat    => "Lamat",
+			Self::Muluk    => "Muluk",
+			Self::Ok       => "Ok",
+			Self::Chuwen   => "Chuwen",
+			Self::Eb       => "Eb'",
+			Self::Ben      => "B'en",
+			Self::Ix       => "Ix",
+			Self::Men      => "Men",
+			Self::Kib      => "K'ib'",
+			Self::Kaban    => "Kab'an",
+			Self::Etznab   => "Etz'nab'",
+			Self::Kawak    => "Kawak",
+			Self::Ajaw     => "Ajaw",
+		}
+	}
+
+	#[inline]
+	/// Parse a name, ignoring ASCII case and apostrophes
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(TzolkinName::from_str("Ak'b'al"), Some(TzolkinName::Akbal));
+	/// assert_eq!(TzolkinName::from_str("akbal"),   Some(TzolkinName::Akbal));
+	/// assert_eq!(TzolkinName::from_str("Pop"),     None);
+	/// ```
+	pub fn from_str(s: &str) -> Option<Self> {
+		Self::ALL.into_iter().find(|name| name_matches(name.as_str(), s))
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tzolkin
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// The 260 day Tzolk'in cycle, a number `1..=13` and a [`TzolkinName`]
+///
+/// ```rust
+/// # use nichi::*;
+/// let tzolkin = Tzolkin::from_date(Date::new(2012, 12, 21), MayanCorrelation::GMT);
+/// assert_eq!(tzolkin, Tzolkin::new(4, TzolkinName::Ajaw));
+/// assert_eq!(tzolkin.to_string(), "4 Ajaw");
+/// assert_eq!(Tzolkin::from_str("4 Ajaw"), Some(tzolkin));
+/// ```
+pub struct Tzolkin {
+	number: u8,
+	name: TzolkinName,
+}
+
+impl Tzolkin {
+	#[inline]
+	/// Create a new [`Tzolkin`]
+	///
+	/// ## Panics
+	/// This function panics if `number` is not `1..=13`.
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// Tzolkin::new(14, TzolkinName::Imix);
+	/// ```
+	pub const fn new(number: u8, name: TzolkinName) -> Self {
+		assert!(number != 0, "number must not be 0");
+		assert!(number < 14, "number must not be > 13");
+		Self { number, name }
+	}
+
+	#[inline]
+	/// The number, `1..=13`
+	pub const fn number(self) -> u8 {
+		self.number
+	}
+
+	#[inline]
+	/// The [`TzolkinName`]
+	pub const fn name(self) -> TzolkinName {
+		self.name
+	}
+
+	#[inline]
+	/// The position in the 260 day cycle, `1..=260`, starting at `1 Imix`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Tzolkin::new(1, TzolkinName::Imix).ordinal(),  1);
+	/// assert_eq!(Tzolkin::new(13, TzolkinName::Ajaw).ordinal(), 260);
+	/// ```
+	pub const fn ordinal(self) -> u16 {
+		let number = self.number as u16 - 1;
+		let name = self.name.inner() as u16 - 1;
+		// The `x` where `x % 13 == number` and `x % 20 == name`.
+		(40 * number + 221 * name) % 260 + 1
+	}
+
+	#[inline]
+	/// The [`Tzolkin`] of days since `0.0.0.0.0`, which is `4 Ajaw`
+	const fn from_days(days: i128) -> Self {
+		Self {
+			number: ((days + 3).rem_euclid(13) + 1) as u8,
+			// SAFETY: `1..=20`
+			name: unsafe { TzolkinName::new_unchecked(((days + 19).rem_euclid(20) + 1) as u8) },
+		}
+	}
+
+	#[inline]
+	/// Convert a [`Date`] into a [`Tzolkin`]
+	///
+	/// This works before `0.0.0.0.0` as well.
+	pub const fn from_date(date: Date, correlation: MayanCorrelation) -> Self {
+		Self::from_days(correlation.days(date))
+	}
+
+	/// Parse a [`Tzolkin`] written as `<number> <name>`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Tzolkin::from_str("13 Etz'nab'"), Some(Tzolkin::new(13, TzolkinName::Etznab)));
+	/// assert_eq!(Tzolkin::from_str("13 etznab"),   Some(Tzolkin::new(13, TzolkinName::Etznab)));
+	/// assert_eq!(Tzolkin::from_str("14 Ajaw"),     None);
+	/// ```
+	pub fn from_str(s: &str) -> Option<Self> {
+		let (number, name) = split_number_name(s)?;
+		if number == 0 || number > 13 {
+			return None;
+		}
+		Some(Self { number, name: TzolkinName::from_str(name)? })
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- HaabMonth
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Month in the Haab'
+///
+/// [`HaabMonth::Wayeb`] is the 5 days at the end of the year.
+#[allow(missing_docs)]
+pub enum HaabMonth {
+	#[default]
+	Pop     = 1,
+	Wo      = 2,
+	Sip     = 3,
+	Sotz    = 4,
+	Sek     = 5,
+	Xul     = 6,
+	Yaxkin  = 7,
+	Mol     = 8,
+	Chen    = 9,
+	Yax     = 10,
+	Sak     = 11,
+	Keh     = 12,
+	Mak     = 13,
+	Kankin  = 14,
+	Muwan   = 15,
+	Pax     = 16,
+	Kayab   = 17,
+	Kumku   = 18,
+	Wayeb   = 19,
+}
+
+impl HaabMonth {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HaabMonth::FIRST, HaabMonth::Pop);
+	/// ```
+	pub const FIRST: Self = Self::Pop;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HaabMonth::LAST, HaabMonth::Wayeb);
+	/// ```
+	pub const LAST: Self = Self::Wayeb;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HaabMonth::ALL[0],  HaabMonth::Pop);
+	/// assert_eq!(HaabMonth::ALL[18], HaabMonth::Wayeb);
+	/// ```
+	pub const ALL: [Self; 19] = [
+		Self::Pop,
+		Self::Wo,
+		Self::Sip,
+		Self::Sotz,
+		Self::Sek,
+		Self::Xul,
+		Self::Yaxkin,
+		Self::Mol,
+		Self::Chen,
+		Self::Yax,
+		Self::Sak,
+		Self::Keh,
+		Self::Mak,
+		Self::Kankin,
+		Self::Muwan,
+		Self::Pax,
+		Self::Kayab,
+		Self::Kumku,
+		Self::Wayeb,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HaabMonth::new(1),  HaabMonth::Pop);
+	/// assert_eq!(HaabMonth::new(19), HaabMonth::Wayeb);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// HaabMonth::new(20);
+	/// ```
+	pub const fn new(month: u8) -> Self {
+		assert!(month != 0, "month must not be 0");
+		assert!(month < 20, "month must not be > 19");
+		// SAFETY: repr(u8)
+		unsafe { Self::new_unchecked(month) }
+	}
+
+	#[inline]
+	/// ## Safety
+	/// `month` must be `1..=19`.
+	pub const unsafe fn new_unchecked(month: u8) -> Self {
+		debug_assert!(month != 0, "month must not be 0");
+		debug_assert!(month < 20, "month must not be > 19");
+		// SAFETY: repr(u8)
+		std::mem::transmute(month)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HaabMonth::new_saturating(0),  HaabMonth::Pop);
+	/// assert_eq!(HaabMonth::new_saturating(20), HaabMonth::Wayeb);
+	/// ```
+	pub const fn new_saturating(month: u8) -> Self {
+		if month == 0 {
+			Self::FIRST
+		} else if month < 20 {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(month) }
+		} else {
+			Self::LAST
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HaabMonth::new_wrapping(0),  HaabMonth::Wayeb);
+	/// assert_eq!(HaabMonth::new_wrapping(20), HaabMonth::Pop);
+	/// ```
+	pub const fn new_wrapping(month: u8) -> Self {
+		let month = month % 19;
+		if month == 0 {
+			Self::LAST
+		} else {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(month) }
+		}
+	}
+
+	impl_u8_enum!();
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HaabMonth::Pop.inner(),   1);
+	/// assert_eq!(HaabMonth::Wayeb.inner(), 19);
+	/// ```
+	pub const fn inner(self) -> u8 {
+		// SAFETY: repr(u8)
+		unsafe { std::mem::transmute(self) }
+	}
+
+	#[inline]
+	/// Amount of days in the month
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HaabMonth::Pop.days(),   20);
+	/// assert_eq!(HaabMonth::Wayeb.days(), 5);
+	/// ```
+	pub const fn days(self) -> u8 {
+		match self {
+			Self::Wayeb => 5,
+			_ => 20,
+		}
+	}
+
+	#[inline]
+	/// Yukatek name in the modern orthography
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HaabMonth::Pop.as_str(),    "Pop");
+	/// assert_eq!(HaabMonth::Wo.as_str(),     "Wo'");
+	/// assert_eq!(HaabMonth::Sip.as_str(),    "Sip");
+	/// assert_eq!(HaabMonth::Sotz.as_str(),   "Sotz'");
+	/// assert_eq!(HaabMonth::Sek.as_str(),    "Sek");
+	/// assert_eq!(HaabMonth::Xul.as_str(),    "Xul");
+	/// assert_eq!(HaabMonth::Yaxkin.as_str(), "Yaxk'in");
+	/// assert_eq!(HaabMonth::Mol.as_str(),    "Mol");
+	/// assert_eq!(HaabMonth::Chen.as_str(),   "Ch'en");
+	/// assert_eq!(HaabMonth::Yax.as_str(),    "Yax");
+	/// assert_eq!(HaabMonth::Sak.as_str(),    "Sak'");
+	/// assert_eq!(HaabMonth::Keh.as_str(),    "Keh");
+	/// assert_eq!(HaabMonth::Mak.as_str(),    "Mak");
+	/// assert_eq!(HaabMonth::Kankin.as_str(), "K'ank'in");
+	/// assert_eq!(HaabMonth::Muwan.as_str(),  "Muwan");
+	/// assert_eq!(HaabMonth::Pax.as_str(),    "Pax");
+	/// assert_eq!(HaabMonth::Kayab.as_str(),  "K'ayab");
+	/// assert_eq!(HaabMonth::Kumku.as_str(),  "Kumk'u");
+	/// assert_eq!(HaabMonth::Wayeb.as_str(),  "Wayeb'");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Pop    => "Pop",
+			Self::Wo     => "Wo'",
+			Self::Sip    => "Sip",
+			Self::Sotz   => "Sotz'",
+			Self::Sek    => "Sek",
+			Self::Xul    => "Xul",
+			Self::Yaxkin => "Yaxk'in",
+			Self::Mol    => "Mol",
+			Self::Chen   => "Ch'en",
+			Self::Yax    => "Yax",
+			Self::Sak    => "Sak'",
+			Self::Keh    => "Keh",
+			Self::Mak    => "Mak",
+			Self::Kankin => "K'ank'in",
+			Self::Muwan  => "Muwan",
+			Self::Pax    => "Pax",
+			Self::Kayab  => "K'ayab",
+			Self::Kumku  => "Kumk'u",
+			Self::Wayeb  => "Wayeb'",
+		}
+	}
+
+	#[inline]
+	/// Parse a name, ignoring ASCII case and apostrophes
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(HaabMonth::from_str("K'ank'in"), Some(HaabMonth::Kankin));
+	/// assert_eq!(HaabMonth::from_str("kankin"),   Some(HaabMonth::Kankin));
+	/// assert_eq!(HaabMonth::from_str("Ajaw"),     None);
+	/// ```
+	pub fn from_str(s: &str) -> Option<Self> {
+		Self::ALL.into_iter().find(|month| name_matches(month.as_str(), s))
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Haab
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// The 365 day Haab' year, a day `0..=19` and a [`HaabMonth`]
+///
+/// ```rust
+/// # use nichi::*;
+/// let haab = Haab::from_date(Date::new(2012, 12, 21), MayanCorrelation::GMT);
+/// assert_eq!(haab, Haab::new(3, HaabMonth::Kankin));
+/// assert_eq!(haab.to_string(), "3 K'ank'in");
+/// assert_eq!(Haab::from_str("3 K'ank'in"), Some(haab));
+/// ```
+pub struct Haab {
+	day: u8,
+	month: HaabMonth,
+}
+
+impl Haab {
+	#[inline]
+	/// Create a new [`Haab`]
+	///
+	/// ## Panics
+	/// This function panics if `day` does not exist in `month`.
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// Haab::new(5, HaabMonth::Wayeb);
+	/// ```
+	pub const fn new(day: u8, month: HaabMonth) -> Self {
+		assert!(day < month.days(), "day does not exist in month");
+		Self { day, month }
+	}
+
+	#[inline]
+	/// The day, `0..=19`
+	pub const fn day(self) -> u8 {
+		self.day
+	}
+
+	#[inline]
+	/// The [`HaabMonth`]
+	pub const fn month(self) -> HaabMonth {
+		self.month
+	}
+
+	#[inline]
+	/// The position in the year, `1..=365`, starting at `0 Pop`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Haab::new(0, HaabMonth::Pop).ordinal(),   1);
+	/// assert_eq!(Haab::new(4, HaabMonth::Wayeb).ordinal(), 365);
+	/// ```
+	pub const fn ordinal(self) -> u16 {
+		(self.month.inner() as u16 - 1) * 20 + self.day as u16 + 1
+	}
+
+	#[inline]
+	/// The [`Haab`] of days since `0.0.0.0.0`, which is `8 Kumk'u`
+	const fn from_days(days: i128) -> Self {
+		let ordinal = (days + 348).rem_euclid(365);
+		Self {
+			day: (ordinal % 20) as u8,
+			// SAFETY: `1..=19`
+			month: unsafe { HaabMonth::new_unchecked((ordinal / 20 + 1) as u8) },
+		}
+	}
+
+	#[inline]
+	/// Convert a [`Date`] into a [`Haab`]
+	///
+	/// This works before `0.0.0.0.0` as well.
+	pub const fn from_date(date: Date, correlation: MayanCorrelation) -> Self {
+		Self::from_days(correlation.days(date))
+	}
+
+	/// Parse a [`Haab`] written as `<day> <month>`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Haab::from_str("0 Pop"),    Some(Haab::new(0, HaabMonth::Pop)));
+	/// assert_eq!(Haab::from_str("8 kumku"),  Some(Haab::new(8, HaabMonth::Kumku)));
+	/// assert_eq!(Haab::from_str("5 Wayeb'"), None);
+	/// ```
+	pub fn from_str(s: &str) -> Option<Self> {
+		let (day, month) = split_number_name(s)?;
+		let month = HaabMonth::from_str(month)?;
+		if day >= month.days() {
+			return None;
+		}
+		Some(Self { day, month })
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Trait
+impl std::fmt::Display for LongCount {
+	/// Formats as `13.0.10.17.8`
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}.{}.{}.{}.{}", self.baktun, self.katun, self.tun, self.winal, self.kin)
+	}
+}
+
+impl std::fmt::Display for Tzolkin {
+	/// Formats as `4 Ajaw`
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} {}", self.number, self.name.as_str())
+	}
+}
+
+impl std::fmt::Display for Haab {
+	/// Formats as `3 K'ank'in`
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} {}", self.day, self.month.as_str())
+	}
+}
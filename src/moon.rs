@@ -0,0 +1,267 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::date_range::DateRange;
+use crate::astronomy::{
+	moon_phase_moment,
+	moon_phase_quarter_before,
+	moon_illumination,
+	moment_to_rata_die,
+};
+
+//---------------------------------------------------------------------------------------------------- MoonPhase
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Phase of the Moon
+///
+/// [`MoonPhase::NewMoon`], [`MoonPhase::FirstQuarter`], [`MoonPhase::FullMoon`]
+/// and [`MoonPhase::LastQuarter`] are the principal phases, which are instants.
+///
+/// The other phases are the days in between.
+///
+/// ## Accuracy
+/// The principal phases are calculated with the algorithm from
+/// "Astronomical Algorithms" (Meeus), chapter 49, they are within
+/// about a minute of published values between `1800` and `2150`.
+///
+/// Outside of that range ΔT is only estimated, so the error grows the further away the year is.
+///
+/// The illumination is within about `1%`.
+#[allow(missing_docs)]
+pub enum MoonPhase {
+	#[default]
+	NewMoon        = 1,
+	WaxingCrescent = 2,
+	FirstQuarter   = 3,
+	WaxingGibbous  = 4,
+	FullMoon       = 5,
+	WaningGibbous  = 6,
+	LastQuarter    = 7,
+	WaningCrescent = 8,
+}
+
+impl MoonPhase {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(MoonPhase::ALL[0], MoonPhase::NewMoon);
+	/// assert_eq!(MoonPhase::ALL[7], MoonPhase::WaningCrescent);
+	/// ```
+	pub const ALL: [Self; 8] = [
+		Self::NewMoon,
+		Self::WaxingCrescent,
+		Self::FirstQuarter,
+		Self::WaxingGibbous,
+		Self::FullMoon,
+		Self::WaningGibbous,
+		Self::LastQuarter,
+		Self::WaningCrescent,
+	];
+
+	/// The principal phases
+	pub const PRINCIPAL: [Self; 4] = [
+		Self::NewMoon,
+		Self::FirstQuarter,
+		Self::FullMoon,
+		Self::LastQuarter,
+	];
+
+	#[inline]
+	/// If this is a principal phase
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert!(MoonPhase::FullMoon.is_principal());
+	/// assert!(!MoonPhase::WaxingGibbous.is_principal());
+	/// ```
+	pub const fn is_principal(self) -> bool {
+		(self as u8) % 2 == 1
+	}
+
+	#[inline]
+	/// The principal phase of a `quarter`
+	const fn from_quarter(quarter: i64) -> Self {
+		Self::PRINCIPAL[quarter.rem_euclid(4) as usize]
+	}
+
+	#[inline]
+	/// The phase between the principal phase of `quarter` and the next one
+	const fn after_quarter(quarter: i64) -> Self {
+		Self::ALL[quarter.rem_euclid(4) as usize * 2 + 1]
+	}
+
+	/// Iterate over the [`Date`]s of this principal phase within `range`, `utc_offset` seconds away from UTC
+	///
+	/// This is empty if `self` is not a principal phase.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let range = DateRange::new(Date::new(2024, 1, 1), Date::new(2024, 4, 30));
+	/// let full_moons: Vec<Date> = MoonPhase::FullMoon.dates(range, 0).collect();
+	/// assert_eq!(full_moons, [
+	/// 	Date::new(2024, 1, 25),
+	/// 	Date::new(2024, 2, 24),
+	/// 	Date::new(2024, 3, 25),
+	/// 	Date::new(2024, 4, 23),
+	/// ]);
+	/// ```
+	pub fn dates(self, range: DateRange, utc_offset: i32) -> impl Iterator<Item = Date> {
+		MoonPhases::new(range, utc_offset)
+			.filter(move |(phase, _)| *phase == self)
+			.map(|(_, date)| date)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(MoonPhase::NewMoon.as_str(),        "New Moon");
+	/// assert_eq!(MoonPhase::WaxingCrescent.as_str(), "Waxing Crescent");
+	/// assert_eq!(MoonPhase::FirstQuarter.as_str(),   "First Quarter");
+	/// assert_eq!(MoonPhase::WaxingGibbous.as_str(),  "Waxing Gibbous");
+	/// assert_eq!(MoonPhase::FullMoon.as_str(),       "Full Moon");
+	/// assert_eq!(MoonPhase::WaningGibbous.as_str(),  "Waning Gibbous");
+	/// assert_eq!(MoonPhase::LastQuarter.as_str(),    "Last Quarter");
+	/// assert_eq!(MoonPhase::WaningCrescent.as_str(), "Waning Crescent");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::NewMoon        => "New Moon",
+			Self::WaxingCrescent => "Waxing Crescent",
+			Self::FirstQuarter   => "First Quarter",
+			Self::WaxingGibbous  => "Waxing Gibbous",
+			Self::FullMoon       => "Full Moon",
+			Self::WaningGibbous  => "Waning Gibbous",
+			Self::LastQuarter    => "Last Quarter",
+			Self::WaningCrescent => "Waning Crescent",
+		}
+	}
+
+	#[inline]
+	/// The phases in between the principal ones use the traditional name of a representative night.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(MoonPhase::NewMoon.as_str_jp(),        "新月");
+	/// assert_eq!(MoonPhase::WaxingCrescent.as_str_jp(), "三日月");
+	/// assert_eq!(MoonPhase::FirstQuarter.as_str_jp(),   "上弦の月");
+	/// assert_eq!(MoonPhase::WaxingGibbous.as_str_jp(),  "十三夜月");
+	/// assert_eq!(MoonPhase::FullMoon.as_str_jp(),       "満月");
+	/// assert_eq!(MoonPhase::WaningGibbous.as_str_jp(),  "居待月");
+	/// assert_eq!(MoonPhase::LastQuarter.as_str_jp(),    "下弦の月");
+	/// assert_eq!(MoonPhase::WaningCrescent.as_str_jp(), "有明月");
+	/// ```
+	pub const fn as_str_jp(self) -> &'static str {
+		match self {
+			Self::NewMoon        => "新月",
+			Self::WaxingCrescent => "三日月",
+			Self::FirstQuarter   => "上弦の月",
+			Self::WaxingGibbous  => "十三夜月",
+			Self::FullMoon       => "満月",
+			Self::WaningGibbous  => "居待月",
+			Self::LastQuarter    => "下弦の月",
+			Self::WaningCrescent => "有明月",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Moon
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd)]
+/// The Moon on a [`Date`], returned from [`Date::moon_phase`]
+pub struct Moon {
+	phase: MoonPhase,
+	illumination: f64,
+}
+
+impl Moon {
+	/// The [`Moon`] on `date`, `utc_offset` seconds away from UTC
+	pub(crate) fn on(date: Date, utc_offset: i32) -> Self {
+		let start = date.to_rata_die() as f64 - utc_offset as f64 / 86400.0;
+
+		let quarter = moon_phase_quarter_before(start + 1.0);
+		let phase = if moon_phase_moment(quarter) >= start {
+			MoonPhase::from_quarter(quarter)
+		} else {
+			MoonPhase::after_quarter(quarter)
+		};
+
+		Self {
+			phase,
+			illumination: moon_illumination(start + 0.5),
+		}
+	}
+
+	#[inline]
+	/// The [`MoonPhase`]
+	///
+	/// This is a principal phase if it occurs during the day.
+	pub const fn phase(self) -> MoonPhase {
+		self.phase
+	}
+
+	#[inline]
+	/// The illuminated fraction of the Moon's disk at noon, `0.0..=1.0`
+	pub const fn illumination(self) -> f64 {
+		self.illumination
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- MoonPhases
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// Iterator over the principal [`MoonPhase`]s within a [`DateRange`]
+///
+/// ```rust
+/// # use nichi::*;
+/// let mut phases = MoonPhases::new(DateRange::year(Year(2024)), 0);
+/// assert_eq!(phases.next(), Some((MoonPhase::LastQuarter,  Date::new(2024, 1, 4))));
+/// assert_eq!(phases.next(), Some((MoonPhase::NewMoon,      Date::new(2024, 1, 11))));
+/// assert_eq!(phases.next(), Some((MoonPhase::FirstQuarter, Date::new(2024, 1, 18))));
+/// assert_eq!(phases.next(), Some((MoonPhase::FullMoon,     Date::new(2024, 1, 25))));
+///
+/// // Japan Standard Time, the last one is 2024-12-30 22:27 UTC.
+/// let new_moons: Vec<Date> = MoonPhases::new(DateRange::year(Year(2024)), 9 * 3600)
+/// 	.filter(|(phase, _)| *phase == MoonPhase::NewMoon)
+/// 	.map(|(_, date)| date)
+/// 	.collect();
+/// assert_eq!(new_moons.len(), 13);
+/// assert_eq!(new_moons[12], Date::new(2024, 12, 31));
+/// ```
+pub struct MoonPhases {
+	quarter: i64,
+	end: i128,
+	utc_offset: i32,
+}
+
+impl MoonPhases {
+	/// Create an iterator over the principal phases within `range`, `utc_offset` seconds away from UTC
+	pub fn new(range: DateRange, utc_offset: i32) -> Self {
+		let start = range.start().to_rata_die() as f64 - utc_offset as f64 / 86400.0;
+
+		let mut quarter = moon_phase_quarter_before(start);
+		if moon_phase_moment(quarter) < start {
+			quarter += 1;
+		}
+
+		Self {
+			quarter,
+			end: range.end().to_rata_die(),
+			utc_offset,
+		}
+	}
+}
+
+impl Iterator for MoonPhases {
+	type Item = (MoonPhase, Date);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let rata_die = moment_to_rata_die(moon_phase_moment(self.quarter), self.utc_offset);
+		if rata_die > self.end {
+			return None;
+		}
+
+		let phase = MoonPhase::from_quarter(self.quarter);
+		self.quarter += 1;
+		Some((phase, Date::from_rata_die(rata_die)))
+	}
+}
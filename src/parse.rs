@@ -0,0 +1,994 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+use crate::month::Month;
+use crate::day::Day;
+use crate::weekday::Weekday;
+use crate::free::days_in_month;
+use crate::pivot::{YearPivot,YearExpansion};
+use crate::historical::{COMMON_ERA_NAMES,ANNO_DOMINI_NAMES};
+
+//---------------------------------------------------------------------------------------------------- ParseErrorKind
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// The reason a [`ParseError`] occurred
+pub enum ParseErrorKind {
+	/// The input ended before the pattern did
+	UnexpectedEnd,
+	/// The input continued after the pattern ended
+	TrailingInput,
+	/// The input did not match a literal character in the pattern
+	Literal,
+	/// Expected a number, or the number had the wrong amount of digits
+	Number,
+	/// Expected a month or weekday name
+	Name,
+	/// A number was out of range, e.g, month `13`
+	OutOfRange,
+	/// The fields do not form an existing date, e.g, February 30th
+	InvalidDate,
+	/// Two fields disagree, e.g, the weekday is not the weekday of the date
+	Conflict,
+	/// There were not enough fields to form a date, e.g, no year
+	Incomplete,
+	/// The pattern has an unknown specifier, the offset is in the pattern
+	Pattern,
+}
+
+impl ParseErrorKind {
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(ParseErrorKind::InvalidDate.as_str(), "date does not exist");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::UnexpectedEnd => "unexpected end of input",
+			Self::TrailingInput => "trailing input",
+			Self::Literal       => "input does not match pattern",
+			Self::Number        => "invalid number",
+			Self::Name          => "invalid name",
+			Self::OutOfRange    => "number out of range",
+			Self::InvalidDate   => "date does not exist",
+			Self::Conflict      => "conflicting fields",
+			Self::Incomplete    => "not enough fields to form a date",
+			Self::Pattern       => "unknown specifier in pattern",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- ParseError
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Error returned from [`Date::parse_with_format`] and [`Date::parse_iso8601`]
+///
+/// ```rust
+/// # use nichi::*;
+/// let error = Date::parse_with_format("2023-02-30", "%Y-%m-%d").unwrap_err();
+/// assert_eq!(error.kind(),   ParseErrorKind::InvalidDate);
+/// assert_eq!(error.offset(), 8);
+/// assert_eq!(error.to_string(), "date does not exist at byte 8");
+/// ```
+pub struct ParseError {
+	kind: ParseErrorKind,
+	offset: usize,
+}
+
+impl ParseError {
+	#[inline]
+	/// Create a new [`ParseError`]
+	pub const fn new(kind: ParseErrorKind, offset: usize) -> Self {
+		Self { kind, offset }
+	}
+
+	#[inline]
+	/// The [`ParseErrorKind`]
+	pub const fn kind(self) -> ParseErrorKind {
+		self.kind
+	}
+
+	#[inline]
+	/// The byte offset into the input where the error occurred
+	///
+	/// For [`ParseErrorKind::Pattern`] this is the byte offset into the pattern.
+	pub const fn offset(self) -> usize {
+		self.offset
+	}
+}
+
+impl std::fmt::Display for ParseError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} at byte {}", self.kind.as_str(), self.offset)
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+//---------------------------------------------------------------------------------------------------- Free
+/// The length of the run of ASCII digits in `bytes` starting at `start`
+const fn digit_run(bytes: &[u8], start: usize) -> usize {
+	let mut i = start;
+	while i < bytes.len() && bytes[i].is_ascii_digit() {
+		i += 1;
+	}
+	i - start
+}
+
+/// The value of the ASCII digits in `bytes[start..end]`, saturating at `i32::MAX`
+const fn digit_value(bytes: &[u8], start: usize, end: usize) -> i32 {
+	let mut value: i32 = 0;
+	let mut i = start;
+	while i < end {
+		value = value.saturating_mul(10).saturating_add((bytes[i] - b'0') as i32);
+		i += 1;
+	}
+	value
+}
+
+//---------------------------------------------------------------------------------------------------- Fields
+#[derive(Copy,Clone,Debug,Default)]
+/// A parsed number and the offset it started at
+struct Field {
+	value: i64,
+	offset: usize,
+}
+
+#[derive(Copy,Clone,Debug,Default)]
+/// Every field a pattern can contain
+struct Fields {
+	year: Option<Field>,
+	century: Option<Field>,
+	year_of_century: Option<Field>,
+	historical_year: Option<Field>,
+	/// `0` is BCE, `1` is CE
+	era: Option<Field>,
+	month: Option<Field>,
+	day: Option<Field>,
+	day_of_year: Option<Field>,
+	/// `0` is Sunday
+	weekday: Option<Field>,
+	week_sunday: Option<Field>,
+	week_monday: Option<Field>,
+	iso_year: Option<Field>,
+	iso_year_of_century: Option<Field>,
+	iso_week: Option<Field>,
+}
+
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+/// How numbers are padded, same as formatting
+enum Pad {
+	None,
+	Space,
+	Zero,
+}
+
+//---------------------------------------------------------------------------------------------------- Parser
+/// If the next item of a pattern is a specifier that parses a number
+fn next_is_number(mut chars: impl Iterator<Item = (usize, char)>) -> bool {
+	if chars.next().map(|(_, c)| c) != Some('%') {
+		return false;
+	}
+	for (_, c) in chars {
+		match c {
+			'-' | '_' | '0' | '^' => (),
+			'Y' | 'C' | 'y' | 'L' | 'G' | 'g' | 'm' | 'd' | 'e' | 'o' | 'j' | 'u' | 'w' | 'U' | 'W' | 'V' | 'F' | 'D' => return true,
+			_ => return false,
+		}
+	}
+	false
+}
+
+/// Parses an input with a pattern, see [`Date::parse_with_format`]
+struct Parser<'a> {
+	input: &'a str,
+	pos: usize,
+	lenient: bool,
+	pivot: YearPivot,
+	fields: Fields,
+}
+
+impl<'a> Parser<'a> {
+	fn error(&self, kind: ParseErrorKind) -> ParseError {
+		ParseError::new(kind, self.pos)
+	}
+
+	fn rest(&self) -> &'a str {
+		&self.input[self.pos..]
+	}
+
+	fn skip_whitespace(&mut self) {
+		let rest = self.rest();
+		self.pos += rest.len() - rest.trim_start().len();
+	}
+
+	/// Parse a number that is formatted as `width` digits, padded with `pad`
+	///
+	/// `max` is the maximum amount of digits, `signed` allows a leading `-`.
+	fn number(&mut self, width: usize, max: usize, pad: Pad, signed: bool) -> Result<Field, ParseError> {
+		let offset = self.pos;
+		let bytes = self.rest().as_bytes();
+		let mut i = 0;
+
+		if self.lenient {
+			while bytes.get(i) == Some(&b' ') {
+				i += 1;
+			}
+		} else if pad == Pad::Space {
+			while i + 1 < width && bytes.get(i) == Some(&b' ') {
+				i += 1;
+			}
+		}
+
+		let negative = signed && bytes.get(i) == Some(&b'-');
+		if negative {
+			i += 1;
+		}
+
+		let start = i;
+		while i < bytes.len() && i - start < max && bytes[i].is_ascii_digit() {
+			i += 1;
+		}
+		let digits = i - start;
+
+		let valid = match (self.lenient, pad) {
+			_ if digits == 0 => false,
+			(true, _) => true,
+			(false, Pad::Zero) => digits >= width,
+			(false, Pad::Space) => start + digits >= width,
+			(false, Pad::None) => digits == 1 || bytes[start] != b'0',
+		};
+		if !valid {
+			return Err(ParseError::new(ParseErrorKind::Number, offset));
+		}
+
+		// At most 5 digits, this never overflows.
+		let mut value = self.rest()[start..i].parse::<i64>().unwrap_or_default();
+		if negative {
+			value = -value;
+		}
+
+		self.pos += i;
+		Ok(Field { value, offset })
+	}
+
+	/// The most digits a `%Y` or `%G` year can have
+	///
+	/// An unsigned year directly followed by a number is 4 digits, e.g, `%Y%m%d`.
+	fn year_digits(&self, next_is_number: bool) -> usize {
+		let rest = if self.lenient { self.rest().trim_start_matches(' ') } else { self.rest() };
+		if next_is_number && !rest.starts_with('-') { 4 } else { 5 }
+	}
+
+	/// Parse a number and check that it is within `range`
+	fn number_in(&mut self, range: std::ops::RangeInclusive<i64>, width: usize, pad: Pad) -> Result<Field, ParseError> {
+		let field = self.number(width, width, pad, false)?;
+		if range.contains(&field.value) {
+			Ok(field)
+		} else {
+			Err(ParseError::new(ParseErrorKind::OutOfRange, field.offset))
+		}
+	}
+
+	/// Parse the first name of `names` the input starts with, ignoring ASCII case
+	fn name<const N: usize>(&mut self, names: [&str; N]) -> Result<Field, ParseError> {
+		let rest = self.rest();
+		for (i, name) in names.iter().enumerate() {
+			if rest.get(..name.len()).is_some_and(|s| s.eq_ignore_ascii_case(name)) {
+				let offset = self.pos;
+				self.pos += name.len();
+				return Ok(Field { value: i as i64, offset });
+			}
+		}
+		Err(self.error(ParseErrorKind::Name))
+	}
+
+	/// Parse a month name, `full` or abbreviated
+	fn month_name(&mut self, full: bool) -> Result<Field, ParseError> {
+		let long  = Month::ALL.map(Month::as_str);
+		let short = Month::ALL.map(Month::as_str_short);
+		let field = match (full, self.lenient) {
+			(true, false) => self.name(long),
+			(false, false) => self.name(short),
+			(_, true) => self.name(long).or_else(|_| self.name(short)),
+		}?;
+		Ok(Field { value: field.value + 1, ..field })
+	}
+
+	/// Parse a weekday name, `full` or abbreviated
+	fn weekday_name(&mut self, full: bool) -> Result<Field, ParseError> {
+		let long  = Weekday::ALL.map(Weekday::as_str);
+		let short = Weekday::ALL.map(Weekday::as_str_short);
+		match (full, self.lenient) {
+			(true, false) => self.name(long),
+			(false, false) => self.name(short),
+			(_, true) => self.name(long).or_else(|_| self.name(short)),
+		}
+	}
+
+	/// Parse an era, `common` is `BCE` and `CE`, otherwise `BC` and `AD`
+	fn era_name(&mut self, common: bool) -> Result<Field, ParseError> {
+		let field = match (common, self.lenient) {
+			(true, false) => self.name(COMMON_ERA_NAMES),
+			(false, false) => self.name(ANNO_DOMINI_NAMES),
+			(_, true) => self.name(COMMON_ERA_NAMES).or_else(|_| self.name(ANNO_DOMINI_NAMES)),
+		}?;
+		Ok(Field { value: field.value % 2, ..field })
+	}
+
+	/// Parse a day with an ordinal suffix, e.g, `5th`
+	fn ordinal(&mut self, pad: Pad) -> Result<Field, ParseError> {
+		let field = self.number_in(1..=31, 2, pad)?;
+		let suffix = match field.value {
+			1 | 21 | 31 => "st",
+			2 | 22      => "nd",
+			3 | 23      => "rd",
+			_           => "th",
+		};
+
+		let rest = self.rest();
+		let matches = |s: &str| rest.get(..2).is_some_and(|r| r.eq_ignore_ascii_case(s));
+		if matches(suffix) || (self.lenient && ["st", "nd", "rd", "th"].into_iter().any(matches)) {
+			self.pos += 2;
+			Ok(field)
+		} else {
+			Err(self.error(ParseErrorKind::Literal))
+		}
+	}
+
+	/// Store a field, fields that appear twice must agree
+	fn set(slot: &mut Option<Field>, field: Field) -> Result<(), ParseError> {
+		match slot {
+			Some(old) if old.value != field.value => Err(ParseError::new(ParseErrorKind::Conflict, field.offset)),
+			_ => {
+				*slot = Some(field);
+				Ok(())
+			},
+		}
+	}
+
+	/// Parse the input with `pattern`
+	fn parse(&mut self, pattern: &str) -> Result<(), ParseError> {
+		let mut chars = pattern.char_indices().peekable();
+
+		while let Some((pattern_offset, c)) = chars.next() {
+			// Literals.
+			if c != '%' {
+				if self.lenient && c.is_whitespace() {
+					while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+					self.skip_whitespace();
+					continue;
+				}
+				self.parse_literal(c)?;
+				continue;
+			}
+
+			// Modifiers.
+			let mut pad = None;
+			let mut japanese = false;
+			let spec = loop {
+				match chars.next() {
+					Some((_, '-')) => pad = Some(Pad::None),
+					Some((_, '_')) => pad = Some(Pad::Space),
+					Some((_, '0')) => pad = Some(Pad::Zero),
+					Some((_, '^')) => (),
+					Some((_, 'J')) => japanese = true,
+					Some((_, c))   => break c,
+					None => return Err(ParseError::new(ParseErrorKind::Pattern, pattern_offset)),
+				}
+			};
+
+			if self.pos == self.input.len() && !matches!(spec, '%' | 'n' | 't') {
+				return Err(self.error(ParseErrorKind::UnexpectedEnd));
+			}
+
+			let zero  = pad.unwrap_or(Pad::Zero);
+			let space = pad.unwrap_or(Pad::Space);
+
+			match (spec, japanese) {
+				('A', true) => {
+					let field = self.name(Weekday::ALL.map(Weekday::as_str_jp))?;
+					Self::set(&mut self.fields.weekday, field)?;
+				},
+				('a', true) => {
+					let short = Weekday::ALL.map(|w| w.as_str_jp().split_at(3).0);
+					let field = self.name(short)?;
+					Self::set(&mut self.fields.weekday, field)?;
+				},
+				('B' | 'b' | 'h', true) => {
+					let field = self.name(Month::ALL.map(Month::as_str_jp))?;
+					Self::set(&mut self.fields.month, Field { value: field.value + 1, ..field })?;
+				},
+				('d' | 'e', true) => {
+					let field = self.name(Day::ALL.map(Day::as_str_jp))?;
+					Self::set(&mut self.fields.day, Field { value: field.value + 1, ..field })?;
+				},
+
+				('Y', _) => {
+					let max = self.year_digits(next_is_number(chars.clone()));
+					let field = self.number(4, max, zero, true)?;
+					Self::set(&mut self.fields.year, field)?;
+				},
+				('C', _) => {
+					let field = self.number(2, 2, zero, true)?;
+					Self::set(&mut self.fields.century, field)?;
+				},
+				('y', _) => {
+					let field = self.number_in(0..=99, 2, zero)?;
+					Self::set(&mut self.fields.year_of_century, field)?;
+				},
+				('L', _) => {
+					let field = self.number(1, 5, pad.unwrap_or(Pad::None), false)?;
+					Self::set(&mut self.fields.historical_year, field)?;
+				},
+				('E', _) => {
+					let field = self.era_name(true)?;
+					Self::set(&mut self.fields.era, field)?;
+				},
+				('K', _) => {
+					let field = self.era_name(false)?;
+					Self::set(&mut self.fields.era, field)?;
+				},
+				('G', _) => {
+					let max = self.year_digits(next_is_number(chars.clone()));
+					let field = self.number(4, max, zero, true)?;
+					Self::set(&mut self.fields.iso_year, field)?;
+				},
+				('g', _) => {
+					let field = self.number_in(0..=99, 2, zero)?;
+					Self::set(&mut self.fields.iso_year_of_century, field)?;
+				},
+				('m', _) => {
+					let field = self.number_in(1..=12, 2, zero)?;
+					Self::set(&mut self.fields.month, field)?;
+				},
+				('B', _) => {
+					let field = self.month_name(true)?;
+					Self::set(&mut self.fields.month, field)?;
+				},
+				('b' | 'h', _) => {
+					let field = self.month_name(false)?;
+					Self::set(&mut self.fields.month, field)?;
+				},
+				('d', _) => {
+					let field = self.number_in(1..=31, 2, zero)?;
+					Self::set(&mut self.fields.day, field)?;
+				},
+				('e', _) => {
+					let field = self.number_in(1..=31, 2, space)?;
+					Self::set(&mut self.fields.day, field)?;
+				},
+				('o', _) => {
+					let field = self.ordinal(pad.unwrap_or(Pad::None))?;
+					Self::set(&mut self.fields.day, field)?;
+				},
+				('j', _) => {
+					let field = self.number_in(1..=366, 3, zero)?;
+					Self::set(&mut self.fields.day_of_year, field)?;
+				},
+				('A', _) => {
+					let field = self.weekday_name(true)?;
+					Self::set(&mut self.fields.weekday, field)?;
+				},
+				('a', _) => {
+					let field = self.weekday_name(false)?;
+					Self::set(&mut self.fields.weekday, field)?;
+				},
+				('u', _) => {
+					let field = self.number_in(1..=7, 1, pad.unwrap_or(Pad::None))?;
+					Self::set(&mut self.fields.weekday, Field { value: field.value % 7, ..field })?;
+				},
+				('w', _) => {
+					let field = self.number_in(0..=6, 1, pad.unwrap_or(Pad::None))?;
+					Self::set(&mut self.fields.weekday, field)?;
+				},
+				('U', _) => {
+					let field = self.number_in(0..=53, 2, zero)?;
+					Self::set(&mut self.fields.week_sunday, field)?;
+				},
+				('W', _) => {
+					let field = self.number_in(0..=53, 2, zero)?;
+					Self::set(&mut self.fields.week_monday, field)?;
+				},
+				('V', _) => {
+					let field = self.number_in(1..=53, 2, zero)?;
+					Self::set(&mut self.fields.iso_week, field)?;
+				},
+				('F', _) => self.parse("%Y-%m-%d")?,
+				('D', _) => self.parse("%m/%d/%y")?,
+				('%', _) => self.parse_literal('%')?,
+				('n' | 't', _) => self.skip_whitespace(),
+
+				_ => return Err(ParseError::new(ParseErrorKind::Pattern, pattern_offset)),
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Parse a literal `c`
+	fn parse_literal(&mut self, c: char) -> Result<(), ParseError> {
+		match self.rest().chars().next() {
+			Some(i) if i == c => {
+				self.pos += i.len_utf8();
+				Ok(())
+			},
+			Some(_) => Err(self.error(ParseErrorKind::Literal)),
+			None => Err(self.error(ParseErrorKind::UnexpectedEnd)),
+		}
+	}
+
+	/// Turn the parsed fields into a [`Date`], and the 2 digit year expansion if one was used
+	fn resolve(&self) -> Result<(Date, Option<YearExpansion>), ParseError> {
+		let f = &self.fields;
+		let incomplete = ParseError::new(ParseErrorKind::Incomplete, self.input.len());
+		let mut expansion = None;
+
+		// `%y` without `%C` is expanded with the pivot.
+		let mut year_of = |year: Option<Field>, century: Option<Field>, year_of_century: Option<Field>| {
+			match (year, century, year_of_century) {
+				(Some(year), _, _) => Some(year),
+				(None, Some(c), Some(y)) => Some(Field { value: c.value * 100 + y.value, offset: c.offset }),
+				(None, Some(c), None) => Some(Field { value: c.value * 100, offset: c.offset }),
+				(None, None, Some(y)) => {
+					let e = YearExpansion::new(y.value as u8, self.pivot);
+					expansion = Some(e);
+					Some(Field { value: e.year().inner() as i64, ..y })
+				},
+				(None, None, None) => None,
+			}
+		};
+
+		let check_year = |field: Field| {
+			if (i16::MIN as i64..=i16::MAX as i64).contains(&field.value) {
+				Ok(field.value as i16)
+			} else {
+				Err(ParseError::new(ParseErrorKind::OutOfRange, field.offset))
+			}
+		};
+
+		let mut year = year_of(f.year, f.century, f.year_of_century);
+
+		// `%L` is historical, `1 BCE` is `0`, without an era it is CE.
+		let bce = f.era.map(|era| era.value == 0);
+		if let Some(historical) = f.historical_year {
+			if historical.value == 0 {
+				return Err(ParseError::new(ParseErrorKind::OutOfRange, historical.offset));
+			}
+			let value = if bce == Some(true) { 1 - historical.value } else { historical.value };
+			match year {
+				Some(year) if year.value != value => return Err(ParseError::new(ParseErrorKind::Conflict, historical.offset)),
+				Some(_) => (),
+				None => year = Some(Field { value, ..historical }),
+			}
+		} else if let (Some(era), Some(bce), Some(year)) = (f.era, bce, year) {
+			// An era with an astronomical year must agree with its sign.
+			if bce != (year.value <= 0) {
+				return Err(ParseError::new(ParseErrorKind::Conflict, era.offset));
+			}
+		}
+
+		let iso_year = year_of(f.iso_year, None, f.iso_year_of_century);
+
+		let date = if let (Some(week), Some(weekday)) = (f.iso_week, f.weekday) {
+			// ISO 8601 week date.
+			let iso_year = check_year(iso_year.or(year).ok_or(incomplete)?)?;
+			let january_4th = Date::new(iso_year, 1, 4);
+			let monday = january_4th.to_rata_die() - january_4th.weekday_iso() as i128 + 1;
+			let days = (week.value as i128 - 1) * 7 + (weekday.value as i128 + 6) % 7;
+			let date = Date::from_rata_die(monday + days);
+			if date.iso_week() != (Year(iso_year), week.value as u8) {
+				return Err(ParseError::new(ParseErrorKind::InvalidDate, week.offset));
+			}
+			date
+		} else {
+			let year_field = year.ok_or(incomplete)?;
+			let year = check_year(year_field)?;
+			let january_1st = Date::new(year, 1, 1);
+
+			let day_of_year = if let Some(day_of_year) = f.day_of_year {
+				Some(day_of_year)
+			} else if let (Some(week), Some(weekday)) = (f.week_sunday.or(f.week_monday), f.weekday) {
+				// The first Sunday (or Monday) of the year is the first day of week 1.
+				let first_weekday = if f.week_sunday.is_some() { 0 } else { 1 };
+				let january_1st_weekday = january_1st.weekday().inner() as i64 - 1;
+				let first = (7 + first_weekday - january_1st_weekday) % 7;
+				let value = first + (week.value - 1) * 7 + (weekday.value + 7 - first_weekday) % 7 + 1;
+				Some(Field { value, offset: week.offset })
+			} else {
+				None
+			};
+
+			match (day_of_year, f.month, f.day) {
+				(Some(day_of_year), _, _) => {
+					let days = Year(year).days_in_year().inner() as i64;
+					if day_of_year.value < 1 || day_of_year.value > days {
+						return Err(ParseError::new(ParseErrorKind::InvalidDate, day_of_year.offset));
+					}
+					let date = Date::from_rata_die(january_1st.to_rata_die() + day_of_year.value as i128 - 1);
+					if let Some(month) = f.month.filter(|m| m.value != date.month().inner() as i64) {
+						return Err(ParseError::new(ParseErrorKind::Conflict, month.offset));
+					}
+					if let Some(day) = f.day.filter(|d| d.value != date.day().inner() as i64) {
+						return Err(ParseError::new(ParseErrorKind::Conflict, day.offset));
+					}
+					date
+				},
+				(None, month, day) => {
+					let month = month.map_or(1, |m| m.value as u8);
+					let day_field = day.unwrap_or(Field { value: 1, offset: year_field.offset });
+					let days = days_in_month(year as i128, Month::new(month)).inner() as i64;
+					if day_field.value > days {
+						return Err(ParseError::new(ParseErrorKind::InvalidDate, day_field.offset));
+					}
+					Date::new(year, month, day_field.value as u8)
+				},
+			}
+		};
+
+		if let Some(weekday) = f.weekday {
+			if date.weekday().inner() as i64 - 1 != weekday.value {
+				return Err(ParseError::new(ParseErrorKind::Conflict, weekday.offset));
+			}
+		}
+
+		Ok((date, expansion))
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- FormatParser
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// A reusable `strftime`-style parser, see [`Date::parse_with_format`]
+///
+/// This adds a [`YearPivot`] for `%y` and reports the expansion it applied.
+///
+/// ```rust
+/// # use nichi::*;
+/// let parser = FormatParser::new("%d-%b-%y");
+///
+/// // The POSIX window by default.
+/// assert_eq!(parser.parse("20-Oct-99"), Ok(Date::new(1999, 10, 20)));
+/// assert_eq!(parser.parse("20-Oct-23"), Ok(Date::new(2023, 10, 20)));
+///
+/// // Birth dates are never in the future.
+/// let today = Date::new(2023, 10, 20);
+/// let births = parser.pivot(YearPivot::sliding(today, 0));
+/// assert_eq!(births.parse("20-Oct-23"), Ok(Date::new(2023, 10, 20)));
+/// assert_eq!(births.parse("20-Oct-24"), Ok(Date::new(1924, 10, 20)));
+///
+/// // Which expansion was applied is reported.
+/// let (date, expansion) = births.parse_expanded("20-Oct-99").unwrap();
+/// assert_eq!(date, Date::new(1999, 10, 20));
+/// let expansion = expansion.unwrap();
+/// assert_eq!((expansion.two_digit(), expansion.year()), (99, Year(1999)));
+/// assert_eq!(expansion.pivot().window(), (Year(1924), Year(2023)));
+///
+/// // 4 digit years are not expanded.
+/// let (_, expansion) = FormatParser::new("%d-%b-%Y").parse_expanded("20-Oct-1999").unwrap();
+/// assert_eq!(expansion, None);
+/// ```
+pub struct FormatParser<'a> {
+	pattern: &'a str,
+	lenient: bool,
+	pivot: YearPivot,
+}
+
+impl<'a> FormatParser<'a> {
+	#[inline]
+	/// Create a new [`FormatParser`] for `pattern`
+	///
+	/// | Setting | Default |
+	/// |---------|---------|
+	/// | [`FormatParser::lenient`] | `false` |
+	/// | [`FormatParser::pivot`] | [`YearPivot::POSIX`] |
+	pub const fn new(pattern: &'a str) -> Self {
+		Self {
+			pattern,
+			lenient: false,
+			pivot: YearPivot::POSIX,
+		}
+	}
+
+	#[inline]
+	/// Parse leniently, see [`Date::parse_with_format_lenient`]
+	pub const fn lenient(mut self, lenient: bool) -> Self {
+		self.lenient = lenient;
+		self
+	}
+
+	#[inline]
+	/// The [`YearPivot`] that expands `%y` when there is no `%C`, also used for `%g`
+	pub const fn pivot(mut self, pivot: YearPivot) -> Self {
+		self.pivot = pivot;
+		self
+	}
+
+	#[inline]
+	/// The pattern
+	pub const fn pattern(self) -> &'a str {
+		self.pattern
+	}
+
+	#[inline]
+	/// Parse `input`
+	///
+	/// ## Errors
+	/// Same as [`Date::parse_with_format`].
+	pub fn parse(self, input: &str) -> Result<Date, ParseError> {
+		self.parse_expanded(input).map(|(date, _)| date)
+	}
+
+	/// Parse `input`, and return the [`YearExpansion`] if a 2 digit year was expanded
+	///
+	/// ## Errors
+	/// Same as [`Date::parse_with_format`].
+	pub fn parse_expanded(self, input: &str) -> Result<(Date, Option<YearExpansion>), ParseError> {
+		let mut parser = Parser {
+			input,
+			pos: 0,
+			lenient: self.lenient,
+			pivot: self.pivot,
+			fields: Fields::default(),
+		};
+
+		if self.lenient {
+			parser.skip_whitespace();
+		}
+		parser.parse(self.pattern)?;
+		if self.lenient {
+			parser.skip_whitespace();
+		}
+
+		if parser.pos != input.len() {
+			return Err(parser.error(ParseErrorKind::TrailingInput));
+		}
+
+		parser.resolve()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Date
+impl Date {
+	/// Parse `input` with a `strftime`-style `pattern`
+	///
+	/// The specifiers and modifiers are the same as [`Date::format`], see [`DateFormat`](crate::DateFormat).
+	///
+	/// This is strict, the input must look exactly like
+	/// the output of [`Date::format`] with the same pattern:
+	/// - Whitespace must match exactly
+	/// - Numbers must have the same padding
+	/// - Names must not be abbreviated unless the pattern is
+	///
+	/// Names are matched ignoring ASCII case.
+	///
+	/// A year is required, a missing month or day is `1`.
+	///
+	/// `%y` without `%C` is `1969..=2068`, see [`FormatParser::pivot`] to change this.
+	///
+	/// `%Y` and `%G` are up to 5 digits, but only 4 when unsigned and directly followed by a number, e.g, `%Y%m%d`.
+	///
+	/// `%L` is a historical year, it is BCE if `%E` or `%K` say so, otherwise CE.
+	/// An era with `%Y` must agree with the sign of the year, e.g, `-0043 BCE`.
+	///
+	/// Weekdays, week numbers and the day of the year are checked against each other.
+	///
+	/// ## Errors
+	/// Returns a [`ParseError`] with the [`ParseErrorKind`] and the byte offset of the problem.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let date = Date::new(2023, 4, 3);
+	/// assert_eq!(Date::parse_with_format("03/04/2023",           "%d/%m/%Y"),        Ok(date));
+	/// assert_eq!(Date::parse_with_format("04/03/2023",           "%m/%d/%Y"),        Ok(date));
+	/// assert_eq!(Date::parse_with_format("Apr  3, 2023",         "%b %e, %Y"),       Ok(date));
+	/// assert_eq!(Date::parse_with_format("Monday, April 3rd",    "%A, %B %o"),       Err(ParseError::new(ParseErrorKind::Incomplete, 17)));
+	/// assert_eq!(Date::parse_with_format("2023-093",             "%Y-%j"),           Ok(date));
+	/// assert_eq!(Date::parse_with_format("2023-W14-1",           "%G-W%V-%u"),       Ok(date));
+	/// assert_eq!(Date::parse_with_format("20230403",             "%Y%m%d"),          Ok(date));
+	/// assert_eq!(Date::parse_with_format("2023093",              "%Y%j"),            Ok(date));
+	/// assert_eq!(Date::parse_with_format("2023141",              "%G%V%u"),          Ok(date));
+	/// assert_eq!(Date::parse_with_format("-100000403",           "%Y%m%d"),          Ok(Date::new(-10000, 4, 3)));
+	/// assert_eq!(Date::parse_with_format("10000",                "%Y"),              Ok(Date::new(10000, 1, 1)));
+	/// assert_eq!(Date::parse_with_format("2023年4月3日(月)",      "%Y年%-m月%-d日(%Ja)"), Ok(date));
+	///
+	/// // Historical years.
+	/// let ides = Date::new(-43, 3, 15);
+	/// assert_eq!(Date::parse_with_format("March 15, 44 BC",      "%B %-d, %L %K"),   Ok(ides));
+	/// assert_eq!(Date::parse_with_format("-0043-03-15 BCE",      "%F %E"),           Ok(ides));
+	/// assert_eq!(Date::parse_with_format("1 BCE",                "%L %E"),           Ok(Date::new(0, 1, 1)));
+	/// assert_eq!(Date::parse_with_format("AD 14",                "%K %L"),           Ok(Date::new(14, 1, 1)));
+	/// assert_eq!(Date::parse_with_format("14",                   "%L"),              Ok(Date::new(14, 1, 1)));
+	///
+	/// // Errors point at the problem.
+	/// let error = Date::parse_with_format("03/4/2023", "%d/%m/%Y").unwrap_err();
+	/// assert_eq!((error.kind(), error.offset()), (ParseErrorKind::Number, 3));
+	///
+	/// let error = Date::parse_with_format("03/13/2023", "%d/%m/%Y").unwrap_err();
+	/// assert_eq!((error.kind(), error.offset()), (ParseErrorKind::OutOfRange, 3));
+	///
+	/// let error = Date::parse_with_format("Tue 2023-04-03", "%a %F").unwrap_err();
+	/// assert_eq!((error.kind(), error.offset()), (ParseErrorKind::Conflict, 0));
+	///
+	/// let error = Date::parse_with_format("0043-03-15 BCE", "%F %E").unwrap_err();
+	/// assert_eq!((error.kind(), error.offset()), (ParseErrorKind::Conflict, 11));
+	///
+	/// let error = Date::parse_with_format("2023-04-03 ", "%F").unwrap_err();
+	/// assert_eq!((error.kind(), error.offset()), (ParseErrorKind::TrailingInput, 10));
+	///
+	/// let error = Date::parse_with_format("2023-04-03", "%Y-%m-%Q").unwrap_err();
+	/// assert_eq!((error.kind(), error.offset()), (ParseErrorKind::Pattern, 6));
+	/// ```
+	pub fn parse_with_format(input: &str, pattern: &str) -> Result<Self, ParseError> {
+		FormatParser::new(pattern).parse(input)
+	}
+
+	/// Same as [`Date::parse_with_format`] but lenient
+	///
+	/// - Whitespace in the pattern matches any amount of whitespace, including none
+	/// - Leading and trailing whitespace is ignored
+	/// - Numbers can have any padding, or none
+	/// - Full and abbreviated names are both accepted
+	/// - Any ordinal suffix is accepted, e.g, `3th`
+	/// - `%E` and `%K` accept both `BCE`/`CE` and `BC`/`AD`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let date = Date::new(2023, 4, 3);
+	/// assert_eq!(Date::parse_with_format_lenient("3/4/2023",          "%d/%m/%Y"),   Ok(date));
+	/// assert_eq!(Date::parse_with_format_lenient("  april 3,2023  ",  "%b %e, %Y"),  Ok(date));
+	/// assert_eq!(Date::parse_with_format_lenient("MON 2023-4-03",     "%A %F"),      Ok(date));
+	/// assert_eq!(Date::parse_with_format_lenient("20230403",          "%Y%m%d"),     Ok(date));
+	/// assert_eq!(Date::parse_with_format_lenient("15 Mar 44 b.c.",    "%d %b %L %E"), Ok(Date::new(-43, 3, 15)));
+	/// assert!(Date::parse_with_format("3/4/2023", "%d/%m/%Y").is_err());
+	/// ```
+	pub fn parse_with_format_lenient(input: &str, pattern: &str) -> Result<Self, ParseError> {
+		FormatParser::new(pattern).lenient(true).parse(input)
+	}
+
+	/// Strictly parse an ISO 8601 calendar date, e.g, `2023-10-20`
+	///
+	/// Exactly these forms are accepted:
+	///
+	/// | Form | Example |
+	/// |------|---------|
+	/// | Extended, also RFC 3339's `full-date` | `2023-10-20`
+	/// | Basic | `20231020`
+	/// | Expanded extended, a sign and 5 or more year digits | `+012023-10-20`, `-00044-03-15`
+	/// | Expanded basic | `+0120231020`, `-000440315`
+	/// | Negative, a `-` and 4 year digits | `-0044-03-15`, `-00440315`
+	///
+	/// Every month and day must be 2 digits.
+	/// Nothing is allowed before or after the date, including whitespace.
+	///
+	/// Years without a sign must be 4 digits, so `0000..=9999`.
+	/// ISO 8601 expanded years have more than 4 digits, so `+2023-10-20` is rejected,
+	/// but `-0044-03-15` is accepted as it is the common way to write negative years,
+	/// e.g, XML Schema's `xs:date` and `%F` in [`Date::format`].
+	/// Expanded years must fit in [`Year`], so `-32768..=32767`.
+	/// Negative years are astronomical, `-0001` is the year before `0000`.
+	///
+	/// This does not allocate.
+	///
+	/// ## Errors
+	/// Returns a [`ParseError`] with the [`ParseErrorKind`] and the byte offset of the problem.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Date::parse_iso8601(b"2023-10-20"),    Ok(Date::new(2023, 10, 20)));
+	/// assert_eq!(Date::parse_iso8601(b"20231020"),      Ok(Date::new(2023, 10, 20)));
+	/// assert_eq!(Date::parse_iso8601(b"0999-01-01"),    Ok(Date::new(999, 1, 1)));
+	/// assert_eq!(Date::parse_iso8601(b"+012023-10-20"), Ok(Date::new(12023, 10, 20)));
+	/// assert_eq!(Date::parse_iso8601(b"-0044-03-15"),   Ok(Date::new(-44, 3, 15)));
+	/// assert_eq!(Date::parse_iso8601(b"-00440315"),     Ok(Date::new(-44, 3, 15)));
+	/// assert_eq!(Date::parse_iso8601(b"-00044-03-15"),  Ok(Date::new(-44, 3, 15)));
+	///
+	/// // It can be used in constants.
+	/// const DATE: Date = match Date::parse_iso8601(b"2000-02-29") {
+	/// 	Ok(date) => date,
+	/// 	Err(_) => panic!(),
+	/// };
+	/// assert_eq!(DATE, Date::new(2000, 2, 29));
+	///
+	/// // Errors point at the problem.
+	/// let error = |s: &str| {
+	/// 	let e = Date::parse_iso8601(s.as_bytes()).unwrap_err();
+	/// 	(e.kind(), e.offset())
+	/// };
+	/// assert_eq!(error("xx2023-10-20yy"), (ParseErrorKind::Number,        0));
+	/// assert_eq!(error("2023-10-20yy"),   (ParseErrorKind::TrailingInput, 10));
+	/// assert_eq!(error("20231020 "),      (ParseErrorKind::TrailingInput, 8));
+	/// assert_eq!(error("2023/10/20"),     (ParseErrorKind::Literal,       4));
+	/// assert_eq!(error("2023-1020"),      (ParseErrorKind::Number,        5));
+	/// assert_eq!(error("2023-10-2"),      (ParseErrorKind::Number,        8));
+	/// assert_eq!(error("2023-10"),        (ParseErrorKind::UnexpectedEnd, 7));
+	/// assert_eq!(error("2023-13-01"),     (ParseErrorKind::OutOfRange,    5));
+	/// assert_eq!(error("2023-02-29"),     (ParseErrorKind::InvalidDate,   8));
+	/// assert_eq!(error("202-10-20"),      (ParseErrorKind::Number,        0));
+	/// assert_eq!(error("+202-10-20"),     (ParseErrorKind::Number,        1));
+	/// assert_eq!(error("+2023-10-20"),    (ParseErrorKind::Number,        1));
+	/// assert_eq!(error("+20231020"),      (ParseErrorKind::Number,        1));
+	/// assert_eq!(error("+99999-10-20"),   (ParseErrorKind::OutOfRange,    1));
+	/// assert_eq!(error(""),               (ParseErrorKind::UnexpectedEnd, 0));
+	/// ```
+	pub const fn parse_iso8601(bytes: &[u8]) -> Result<Self, ParseError> {
+		let len = bytes.len();
+
+		// Sign.
+		let (negative, year_start) = match bytes.first() {
+			None => return Err(ParseError::new(ParseErrorKind::UnexpectedEnd, 0)),
+			Some(b'+') => (false, 1),
+			Some(b'-') => (true, 1),
+			Some(_) => (false, 0),
+		};
+		let signed = year_start == 1;
+
+		// Extended if the digits stop early or at a `-`, else basic.
+		let run = digit_run(bytes, year_start);
+		let end = year_start + run;
+		let extended = end != len && (bytes[end] == b'-' || run < 8);
+		let year_digits = if extended { run } else { run.saturating_sub(4) };
+
+		// Expanded years are more than 4 digits, a negative year can also be 4.
+		let valid_width = match (signed, negative) {
+			(false, _) => year_digits == 4,
+			(true, false) => year_digits > 4,
+			(true, true) => year_digits >= 4,
+		};
+		if !valid_width {
+			if year_start == len {
+				return Err(ParseError::new(ParseErrorKind::UnexpectedEnd, len));
+			}
+			return Err(ParseError::new(ParseErrorKind::Number, year_start));
+		}
+		let year_end = year_start + year_digits;
+		let year = digit_value(bytes, year_start, year_end);
+		let year = if negative { -year } else { year };
+		if year < i16::MIN as i32 || year > i16::MAX as i32 {
+			return Err(ParseError::new(ParseErrorKind::OutOfRange, year_start));
+		}
+
+		// Month and day.
+		let mut i = year_end;
+		let mut fields = [0_u8; 2];
+		let mut offsets = [0_usize; 2];
+		let mut f = 0;
+		while f < 2 {
+			if extended {
+				if i == len {
+					return Err(ParseError::new(ParseErrorKind::UnexpectedEnd, i));
+				}
+				if bytes[i] != b'-' {
+					return Err(ParseError::new(ParseErrorKind::Literal, i));
+				}
+				i += 1;
+			}
+			if i == len {
+				return Err(ParseError::new(ParseErrorKind::UnexpectedEnd, i));
+			}
+			if digit_run(bytes, i) < 2 || (extended && i + 2 < len && bytes[i + 2].is_ascii_digit()) {
+				return Err(ParseError::new(ParseErrorKind::Number, i));
+			}
+			fields[f] = digit_value(bytes, i, i + 2) as u8;
+			offsets[f] = i;
+			i += 2;
+			f += 1;
+		}
+		if i != len {
+			return Err(ParseError::new(ParseErrorKind::TrailingInput, i));
+		}
+
+		let [month, day] = fields;
+		if month == 0 || month > 12 {
+			return Err(ParseError::new(ParseErrorKind::OutOfRange, offsets[0]));
+		}
+		if day == 0 || day > 31 {
+			return Err(ParseError::new(ParseErrorKind::OutOfRange, offsets[1]));
+		}
+		if day > days_in_month(year as i128, Month::new(month)).inner() {
+			return Err(ParseError::new(ParseErrorKind::InvalidDate, offsets[1]));
+		}
+
+		Ok(Self::new(year as i16, month, day))
+	}
+}
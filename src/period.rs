@@ -0,0 +1,646 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+use crate::month::Month;
+use crate::day::Day;
+use crate::weekday::Weekday;
+use crate::date_range::DateRange;
+use crate::free::days_in_month;
+use crate::matcher::NameMatcher;
+use crate::relative::RelativeParser;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Separators between the start and end of a range, tried in order
+const RANGE_SEPARATORS: [&str; 8] = ["..", " – ", "–", " — ", "—", " - ", " to ", " through "];
+
+/// Month names, only exact names and common abbreviations, e.g, `Sept`, `Jan.`
+const MONTH_MATCHER: NameMatcher = NameMatcher::new().min_prefix(0).max_distance(0);
+
+//---------------------------------------------------------------------------------------------------- Granularity
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// The precision a [`Period`] was written with
+pub enum Granularity {
+	#[default]
+	/// `2023-10-03`, `yesterday`
+	Day = 1,
+	/// `2023-W42`, `last week`
+	Week = 2,
+	/// `October 2023`, `2023-10`
+	Month = 3,
+	/// `Q3 2023`
+	Quarter = 4,
+	/// `2023`
+	Year = 5,
+}
+
+impl Granularity {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Granularity::ALL.len(), 5);
+	/// ```
+	pub const ALL: [Self; 5] = [
+		Self::Day,
+		Self::Week,
+		Self::Month,
+		Self::Quarter,
+		Self::Year,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Granularity::Day.as_str(),     "day");
+	/// assert_eq!(Granularity::Week.as_str(),    "week");
+	/// assert_eq!(Granularity::Month.as_str(),   "month");
+	/// assert_eq!(Granularity::Quarter.as_str(), "quarter");
+	/// assert_eq!(Granularity::Year.as_str(),    "year");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Day     => "day",
+			Self::Week    => "week",
+			Self::Month   => "month",
+			Self::Quarter => "quarter",
+			Self::Year    => "year",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Period
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// A [`DateRange`] parsed from an expression, and the [`Granularity`] it was written with
+///
+/// See [`PeriodParser`] for the expressions.
+pub struct Period {
+	range: DateRange,
+	granularity: Granularity,
+}
+
+impl Period {
+	#[inline]
+	/// Create a new [`Period`]
+	pub const fn new(range: DateRange, granularity: Granularity) -> Self {
+		Self { range, granularity }
+	}
+
+	#[inline]
+	/// Parse `s` with the default [`PeriodParser`], relative to `anchor`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let anchor = Date::new(2023, 10, 20);
+	/// let period = Period::parse("Q3 2023", anchor).unwrap();
+	/// assert_eq!(period.start(), Date::new(2023, 7, 1));
+	/// assert_eq!(period.end(),   Date::new(2023, 9, 30));
+	/// assert_eq!(period.granularity(), Granularity::Quarter);
+	/// ```
+	pub fn parse(s: &str, anchor: Date) -> Option<Self> {
+		PeriodParser::new().parse(s, anchor)
+	}
+
+	#[inline]
+	/// The [`DateRange`]
+	pub const fn range(self) -> DateRange {
+		self.range
+	}
+
+	#[inline]
+	/// The first day of the period
+	pub const fn start(self) -> Date {
+		self.range.start()
+	}
+
+	#[inline]
+	/// The last day of the period
+	pub const fn end(self) -> Date {
+		self.range.end()
+	}
+
+	#[inline]
+	/// The [`Granularity`], for ranges this is the finer of both ends
+	pub const fn granularity(self) -> Granularity {
+		self.granularity
+	}
+
+	/// A day
+	const fn day(date: Date) -> Self {
+		Self::new(DateRange::new(date, date), Granularity::Day)
+	}
+
+	/// The days from `start` to `start + days - 1`
+	const fn days(start: Date, days: i128, granularity: Granularity) -> Self {
+		let end = Date::from_rata_die(start.to_rata_die() + days - 1);
+		Self::new(DateRange::new(start, end), granularity)
+	}
+
+	/// `months` months starting at `month` of `year`
+	const fn months(year: i16, month: Month, months: u8, granularity: Granularity) -> Self {
+		let last = Month::new(month.inner() + months - 1);
+		let start = Date::new(year, month.inner(), 1);
+		let end = Date::new(year, last.inner(), days_in_month(year as i128, last).inner());
+		Self::new(DateRange::new(start, end), granularity)
+	}
+
+	/// Quarter `quarter`, `1..=4`, of `year`
+	const fn quarter(year: i16, quarter: u8) -> Self {
+		Self::months(year, Month::new(quarter * 3 - 2), 3, Granularity::Quarter)
+	}
+
+	/// ISO 8601 week `week` of `year`, if it exists
+	fn iso_week(year: i16, week: u8) -> Option<Self> {
+		let january_4th = Date::new(year, 1, 4);
+		let monday = january_4th.to_rata_die() - january_4th.weekday_iso() as i128 + 1;
+		let start = Date::from_rata_die(monday + (week as i128 - 1) * 7);
+		(week >= 1 && start.iso_week() == (Year(year), week)).then(|| Self::days(start, 7, Granularity::Week))
+	}
+}
+
+impl std::fmt::Display for Period {
+	/// Same as [`DateRange`]'s [`Display`](std::fmt::Display)
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		self.range.fmt(f)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Side
+#[derive(Copy,Clone,Debug,Default,PartialEq,Eq)]
+/// A date missing some fields, e.g, `Oct 3` or the `5, 2023` in `Oct 3–5, 2023`
+struct Partial {
+	year: Option<i16>,
+	month: Option<Month>,
+	day: Option<Day>,
+	quarter: Option<u8>,
+}
+
+impl Partial {
+	/// The [`Period`] this is, a missing year is the year of `anchor`
+	fn resolve(self, anchor: Date) -> Option<Period> {
+		let year = self.year.unwrap_or(anchor.year().inner());
+		match (self.quarter, self.month, self.day) {
+			(Some(quarter), None, None) => Some(Period::quarter(year, quarter)),
+			(None, Some(month), Some(day)) => {
+				(day.inner() <= days_in_month(year as i128, month).inner())
+					.then(|| Period::day(Date::new_typed(Year(year), month, day)))
+			},
+			(None, Some(month), None) => Some(Period::months(year, month, 1, Granularity::Month)),
+			(None, None, None) => Some(Period::months(self.year?, Month::January, 12, Granularity::Year)),
+			_ => None,
+		}
+	}
+}
+
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+/// 1 end of a range, or a whole expression
+enum Side {
+	/// Complete on its own, e.g, `2023-10-03`, `last week`
+	Period(Period),
+	/// Can borrow fields from the other end of a range
+	Partial(Partial),
+}
+
+//---------------------------------------------------------------------------------------------------- Free
+/// Lowercase and collapse whitespace
+fn normalize(s: &str) -> String {
+	s.split_whitespace()
+		.collect::<Vec<&str>>()
+		.join(" ")
+		.to_lowercase()
+}
+
+/// Parse the ASCII digits `s`
+fn digits(s: &str) -> Option<u16> {
+	if s.is_empty() || s.len() > 4 || !s.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+	s.parse().ok()
+}
+
+/// A 4 digit year
+fn year(s: &str) -> Option<i16> {
+	if s.len() != 4 {
+		return None;
+	}
+	digits(s).map(|y| y as i16)
+}
+
+/// `q1` to `q4`
+fn quarter(s: &str) -> Option<u8> {
+	let q = digits(s.strip_prefix('q')?)?;
+	(1..=4).contains(&q).then_some(q as u8)
+}
+
+/// Forms without spaces, e.g, `2023-10-03`, `2023-10`, `2023-w42`, `2023-w42-1`, `2023-q3`
+fn compact(s: &str) -> Option<Side> {
+	if let Ok(date) = Date::parse_iso8601(s.as_bytes()) {
+		return Some(Side::Period(Period::day(date)));
+	}
+
+	let (first, rest) = s.split_once('-')?;
+	let year = year(first)?;
+
+	// `2023-w42`, `2023-w42-1`
+	if let Some(week) = rest.strip_prefix('w') {
+		let (week, day) = match week.split_once('-') {
+			Some((week, day)) => (week, Some(digits(day)?)),
+			None => (week, None),
+		};
+		if week.len() != 2 {
+			return None;
+		}
+		let period = Period::iso_week(year, digits(week)? as u8)?;
+		return match day {
+			None => Some(Side::Period(period)),
+			Some(day @ 1..=7) => {
+				let date = Date::from_rata_die(period.start().to_rata_die() + day as i128 - 1);
+				Some(Side::Period(Period::day(date)))
+			},
+			Some(_) => None,
+		};
+	}
+
+	// `2023-q3`
+	if let Some(quarter) = quarter(rest) {
+		return Some(Side::Period(Period::quarter(year, quarter)));
+	}
+
+	// `2023-10`
+	if rest.len() == 2 {
+		let month = digits(rest)?;
+		if (1..=12).contains(&month) {
+			return Some(Side::Partial(Partial {
+				year: Some(year),
+				month: Some(Month::new(month as u8)),
+				..Default::default()
+			}));
+		}
+	}
+
+	None
+}
+
+/// Words in any order, e.g, `oct 3rd, 2023`, `3 october`, `q3 2023`, `2023`
+fn words(s: &str) -> Option<Partial> {
+	let mut partial = Partial::default();
+
+	fn set<T>(slot: &mut Option<T>, value: T) -> Option<()> {
+		if slot.is_some() {
+			return None;
+		}
+		*slot = Some(value);
+		Some(())
+	}
+
+	for word in s.split([' ', ',']).filter(|w| !w.is_empty()) {
+		if matches!(word, "of" | "the") {
+			continue;
+		}
+
+		if let Some(year) = year(word) {
+			set(&mut partial.year, year)?;
+		} else if let Some(quarter) = quarter(word) {
+			set(&mut partial.quarter, quarter)?;
+		} else if word.bytes().all(|b| b.is_ascii_alphabetic() || b == b'.') {
+			// Weekdays are allowed but not checked, e.g, `Tuesday, Oct 3`.
+			if Weekday::from_str(word).is_none() {
+				set(&mut partial.month, MONTH_MATCHER.month(word)?.value())?;
+			}
+		} else {
+			set(&mut partial.day, Day::from_str(word)?)?;
+		}
+	}
+
+	// Only a weekday is a relative day, e.g, `friday`.
+	(partial != Partial::default()).then_some(partial)
+}
+
+/// The start of the week containing `date`
+fn week_of(date: Date, week_start: Weekday) -> Date {
+	let offset = (date.weekday().inner() + 7 - week_start.inner()) % 7;
+	Date::from_rata_die(date.to_rata_die() - offset as i128)
+}
+
+//---------------------------------------------------------------------------------------------------- PeriodParser
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// Parse period expressions into a [`Period`]
+///
+/// | Expression | Example | [`Granularity`] |
+/// |------------|---------|-----------------|
+/// | Year | `2023` | [`Granularity::Year`]
+/// | Quarter | `Q3 2023`, `2023-Q3` | [`Granularity::Quarter`]
+/// | Month | `October 2023`, `Oct 2023`, `2023-10` | [`Granularity::Month`]
+/// | ISO 8601 week | `2023-W42` | [`Granularity::Week`]
+/// | Day | `2023-10-03`, `Oct 3rd, 2023`, `3 October 2023`, `2023-W40-2` | [`Granularity::Day`]
+/// | Relative | `this week`, `last month`, `next quarter`, `this year` | The unit
+/// | Relative day | `today`, `next friday`, `3 days ago`, see [`RelativeParser`] | [`Granularity::Day`]
+/// | Range | `2023-10-01..2023-10-31`, `Oct 3–5, 2023`, `from Q1 to Q3 2023` | The finer of both ends
+///
+/// Names and words are matched ignoring case.
+///
+/// A missing year is the year of the anchor, e.g, `October`.
+///
+/// The ends of a range are separated by `..`, `–`, `—`, ` - `, `to`, `through`,
+/// or a `-` that splits the expression into 2 valid ends.
+/// They can start with `from` or `between`, in which case `and` also separates them.
+///
+/// Ends of a range borrow missing fields from each other,
+/// e.g, `Oct 3–5, 2023` is `Oct 3, 2023` to `Oct 5, 2023`.
+///
+/// ```rust
+/// # use nichi::*;
+/// let anchor = Date::new(2023, 10, 20); // Friday
+/// let p = |s: &str| {
+/// 	let period = PeriodParser::new().parse(s, anchor).unwrap();
+/// 	(period.start().inner(), period.end().inner(), period.granularity())
+/// };
+///
+/// assert_eq!(p("2023"),                   ((2023, 1, 1),   (2023, 12, 31), Granularity::Year));
+/// assert_eq!(p("Q3 2023"),                ((2023, 7, 1),   (2023, 9, 30),  Granularity::Quarter));
+/// assert_eq!(p("October 2023"),           ((2023, 10, 1),  (2023, 10, 31), Granularity::Month));
+/// assert_eq!(p("2023-W42"),               ((2023, 10, 16), (2023, 10, 22), Granularity::Week));
+/// assert_eq!(p("Oct 3rd, 2023"),          ((2023, 10, 3),  (2023, 10, 3),  Granularity::Day));
+/// assert_eq!(p("2023-10-01..2023-10-31"), ((2023, 10, 1),  (2023, 10, 31), Granularity::Day));
+/// assert_eq!(p("Oct 3–5, 2023"),          ((2023, 10, 3),  (2023, 10, 5),  Granularity::Day));
+/// assert_eq!(p("Oct 3-5, 2023"),          ((2023, 10, 3),  (2023, 10, 5),  Granularity::Day));
+/// assert_eq!(p("3 to 5 October"),         ((2023, 10, 3),  (2023, 10, 5),  Granularity::Day));
+/// assert_eq!(p("from Q1 to Q3 2023"),     ((2023, 1, 1),   (2023, 9, 30),  Granularity::Quarter));
+/// assert_eq!(p("between May and July"),   ((2023, 5, 1),   (2023, 7, 31),  Granularity::Month));
+/// assert_eq!(p("2023-09 – 2023-10-05"),   ((2023, 9, 1),   (2023, 10, 5),  Granularity::Day));
+/// assert_eq!(p("last week"),              ((2023, 10, 9),  (2023, 10, 15), Granularity::Week));
+/// assert_eq!(p("this month"),             ((2023, 10, 1),  (2023, 10, 31), Granularity::Month));
+/// assert_eq!(p("next quarter"),           ((2024, 1, 1),   (2024, 3, 31),  Granularity::Quarter));
+/// assert_eq!(p("yesterday"),              ((2023, 10, 19), (2023, 10, 19), Granularity::Day));
+/// assert_eq!(p("friday"),                 ((2023, 10, 20), (2023, 10, 20), Granularity::Day));
+/// assert_eq!(p("Tuesday"),                ((2023, 10, 24), (2023, 10, 24), Granularity::Day));
+/// assert_eq!(p("Tuesday, Oct 3"),         ((2023, 10, 3),  (2023, 10, 3),  Granularity::Day));
+/// assert_eq!(p("last week to today"),     ((2023, 10, 9),  (2023, 10, 20), Granularity::Day));
+///
+/// // Invalid.
+/// let parser = PeriodParser::new();
+/// assert_eq!(parser.parse("2023-02-30", anchor),  None);
+/// assert_eq!(parser.parse("Q5 2023", anchor),     None);
+/// assert_eq!(parser.parse("Oct 5–3, 2023", anchor), None);
+/// assert_eq!(parser.parse("2023-W53", anchor),    None);
+/// assert_eq!(parser.parse("soon", anchor),        None);
+/// ```
+pub struct PeriodParser {
+	week_start: Weekday,
+}
+
+impl PeriodParser {
+	#[inline]
+	/// Create a new [`PeriodParser`]
+	///
+	/// | Setting | Default |
+	/// |---------|---------|
+	/// | [`PeriodParser::week_start`] | [`Weekday::Monday`] |
+	pub const fn new() -> Self {
+		Self {
+			week_start: Weekday::Monday,
+		}
+	}
+
+	#[inline]
+	/// The first day of the week for `this week`, `last week` and `next week`
+	///
+	/// ISO 8601 weeks, e.g, `2023-W42`, always start on Monday.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let anchor = Date::new(2023, 10, 20);
+	/// let week = PeriodParser::new().week_start(Weekday::Sunday).parse("this week", anchor).unwrap();
+	/// assert_eq!(week.start(), Date::new(2023, 10, 15));
+	/// assert_eq!(week.end(),   Date::new(2023, 10, 21));
+	/// ```
+	pub const fn week_start(mut self, week_start: Weekday) -> Self {
+		self.week_start = week_start;
+		self
+	}
+
+	/// Parse `s` into a [`Period`], relative to `anchor`
+	pub fn parse(&self, s: &str, anchor: Date) -> Option<Period> {
+		let s = normalize(s);
+
+		if let Some(side) = self.side(&s, anchor) {
+			return self.resolve(side, anchor);
+		}
+
+		// `from a to b`, `between a and b`.
+		let (s, and) = if let Some(rest) = s.strip_prefix("from ") {
+			(rest, false)
+		} else if let Some(rest) = s.strip_prefix("between ") {
+			(rest, true)
+		} else {
+			(s.as_str(), false)
+		};
+
+		let separators = RANGE_SEPARATORS.iter().chain(and.then_some(&" and "));
+		for separator in separators {
+			if let Some((start, end)) = s.split_once(separator) {
+				return self.range(start, end, anchor);
+			}
+		}
+
+		// An unspaced `-`, e.g, `oct 3-5, 2023`.
+		s.match_indices('-').find_map(|(i, _)| self.range(&s[..i], &s[i + 1..], anchor))
+	}
+
+	/// A whole normalized expression, or 1 end of a range
+	fn side(&self, s: &str, anchor: Date) -> Option<Side> {
+		if s.is_empty() {
+			return None;
+		}
+		if let Some(period) = self.relative(s, anchor) {
+			return Some(Side::Period(period));
+		}
+		if let Some(side) = compact(s) {
+			return Some(side);
+		}
+		if let Some(partial) = words(s) {
+			return Some(Side::Partial(partial));
+		}
+		RelativeParser::new().resolve(s, anchor).map(|date| Side::Period(Period::day(date)))
+	}
+
+	/// `this week`, `last month`, `next quarter`, `this year`, ...
+	fn relative(&self, s: &str, anchor: Date) -> Option<Period> {
+		let (offset, unit) = s.split_once(' ')?;
+		let offset: i32 = match offset {
+			"this" | "current" => 0,
+			"last" | "previous" | "past" => -1,
+			"next" | "coming" => 1,
+			_ => return None,
+		};
+
+		let (year, month, _) = anchor.inner();
+		// `n` months starting at the `n` month block containing `anchor`, moved by `offset` blocks.
+		let months = |n: i32, granularity| {
+			let index = year as i32 * 12 + (month as i32 - 1) / n * n + offset * n;
+			let (year, month) = (index.div_euclid(12), index.rem_euclid(12) + 1);
+			let year = i16::try_from(year).ok()?;
+			Some(Period::months(year, Month::new(month as u8), n as u8, granularity))
+		};
+
+		match unit {
+			"week" => {
+				let start = week_of(anchor, self.week_start).to_rata_die() + offset as i128 * 7;
+				Some(Period::days(Date::from_rata_die(start), 7, Granularity::Week))
+			},
+			"month" => months(1, Granularity::Month),
+			"quarter" => months(3, Granularity::Quarter),
+			"year" => months(12, Granularity::Year),
+			_ => None,
+		}
+	}
+
+	/// A [`Side`] as a [`Period`]
+	fn resolve(&self, side: Side, anchor: Date) -> Option<Period> {
+		match side {
+			Side::Period(period) => Some(period),
+			Side::Partial(partial) => partial.resolve(anchor),
+		}
+	}
+
+	/// A range from `start` to `end`
+	fn range(&self, start: &str, end: &str, anchor: Date) -> Option<Period> {
+		let mut start = self.side(start.trim(), anchor)?;
+		let mut end = self.side(end.trim(), anchor)?;
+
+		// Borrow missing fields, e.g, `oct 3–5, 2023`.
+		if let (Side::Partial(a), Side::Partial(b)) = (&mut start, &mut end) {
+			a.year = a.year.or(b.year);
+			b.year = b.year.or(a.year);
+			if a.day.is_some() && b.day.is_some() {
+				a.month = a.month.or(b.month);
+				b.month = b.month.or(a.month);
+			}
+		}
+
+		let start = self.resolve(start, anchor)?;
+		let end = self.resolve(end, anchor)?;
+		if start.start() > end.end() {
+			return None;
+		}
+
+		let range = DateRange::new(start.start(), end.end());
+		Some(Period::new(range, start.granularity().min(end.granularity())))
+	}
+}
+
+impl Default for PeriodParser {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Display
+impl DateRange {
+	#[inline]
+	/// Parse `s` with the default [`PeriodParser`], relative to `anchor`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let anchor = Date::new(2023, 10, 20);
+	/// let range = DateRange::parse("Oct 3–5, 2023", anchor).unwrap();
+	/// assert_eq!(range, DateRange::new(Date::new(2023, 10, 3), Date::new(2023, 10, 5)));
+	/// ```
+	pub fn parse(s: &str, anchor: Date) -> Option<Self> {
+		Period::parse(s, anchor).map(Period::range)
+	}
+}
+
+impl std::fmt::Display for DateRange {
+	/// The most compact English form of the range
+	///
+	/// | Range | Output |
+	/// |-------|--------|
+	/// | 1 year | `2023`
+	/// | Years | `2021–2023`
+	/// | 1 quarter | `Q3 2023`
+	/// | 1 month | `October 2023`
+	/// | Months in 1 year | `October–December 2023`
+	/// | Months across years | `November 2023 – February 2024`
+	/// | 1 day | `Oct 3, 2023`
+	/// | Days in 1 month | `Oct 3–5, 2023`
+	/// | Days in 1 year | `Oct 3 – Nov 5, 2023`
+	/// | Days across years | `Dec 30, 2023 – Jan 2, 2024`
+	///
+	/// The output can be parsed back with [`PeriodParser`] for years `1000..=9999`.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let range = |a: (i16, u8, u8), b: (i16, u8, u8)| {
+	/// 	DateRange::new(Date::new(a.0, a.1, a.2), Date::new(b.0, b.1, b.2))
+	/// };
+	///
+	/// assert_eq!(range((2023, 1, 1),   (2023, 12, 31)).to_string(), "2023");
+	/// assert_eq!(range((2021, 1, 1),   (2023, 12, 31)).to_string(), "2021–2023");
+	/// assert_eq!(range((2023, 7, 1),   (2023, 9, 30)).to_string(),  "Q3 2023");
+	/// assert_eq!(range((2023, 10, 1),  (2023, 10, 31)).to_string(), "October 2023");
+	/// assert_eq!(range((2023, 10, 1),  (2023, 11, 30)).to_string(), "October–November 2023");
+	/// assert_eq!(range((2023, 11, 1),  (2024, 2, 29)).to_string(),  "November 2023 – February 2024");
+	/// assert_eq!(range((2023, 10, 3),  (2023, 10, 3)).to_string(),  "Oct 3, 2023");
+	/// assert_eq!(range((2023, 10, 3),  (2023, 10, 5)).to_string(),  "Oct 3–5, 2023");
+	/// assert_eq!(range((2023, 10, 3),  (2023, 11, 5)).to_string(),  "Oct 3 – Nov 5, 2023");
+	/// assert_eq!(range((2023, 12, 30), (2024, 1, 2)).to_string(),   "Dec 30, 2023 – Jan 2, 2024");
+	///
+	/// // It parses back.
+	/// let anchor = Date::new(2000, 1, 1);
+	/// for r in [
+	/// 	range((2021, 1, 1), (2023, 12, 31)),
+	/// 	range((2023, 11, 1), (2024, 2, 29)),
+	/// 	range((2023, 10, 3), (2023, 11, 5)),
+	/// ] {
+	/// 	assert_eq!(DateRange::parse(&r.to_string(), anchor), Some(r));
+	/// }
+	/// ```
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let (start_year, start_month, start_day) = self.start().inner_typed();
+		let (end_year, end_month, end_day) = self.end().inner_typed();
+		let (start_year, end_year) = (start_year.inner(), end_year.inner());
+
+		// Whole years.
+		let whole_years = (start_month, start_day.inner(), end_month, end_day.inner())
+			== (Month::January, 1, Month::December, 31);
+		if whole_years {
+			return if start_year == end_year {
+				write!(f, "{start_year}")
+			} else {
+				write!(f, "{start_year}–{end_year}")
+			};
+		}
+
+		// Whole months.
+		let end_of_month = end_day.inner() == days_in_month(end_year as i128, end_month).inner();
+		if start_day.inner() == 1 && end_of_month {
+			let (first, last) = (start_month.inner(), end_month.inner());
+			if start_year == end_year && first % 3 == 1 && last == first + 2 {
+				return write!(f, "Q{} {start_year}", last / 3);
+			}
+			return if start_year != end_year {
+				write!(f, "{} {start_year} – {} {end_year}", start_month.as_str(), end_month.as_str())
+			} else if start_month == end_month {
+				write!(f, "{} {start_year}", start_month.as_str())
+			} else {
+				write!(f, "{}–{} {start_year}", start_month.as_str(), end_month.as_str())
+			};
+		}
+
+		// Days.
+		let (start_month, end_month) = (start_month.as_str_short(), end_month.as_str_short());
+		let (start_day, end_day) = (start_day.inner(), end_day.inner());
+		if start_year != end_year {
+			write!(f, "{start_month} {start_day}, {start_year} – {end_month} {end_day}, {end_year}")
+		} else if start_month != end_month {
+			write!(f, "{start_month} {start_day} – {end_month} {end_day}, {start_year}")
+		} else if start_day != end_day {
+			write!(f, "{start_month} {start_day}–{end_day}, {start_year}")
+		} else {
+			write!(f, "{start_month} {start_day}, {start_year}")
+		}
+	}
+}
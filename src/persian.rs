@@ -0,0 +1,570 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+use crate::macros::impl_u8_enum;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Rata Die of `1 Farvardin 1 AP`, as projected by the 33-year rule
+const PERSIAN_EPOCH: i128 = 226895;
+
+/// Days in a 33-year cycle
+const DAYS_IN_CYCLE: i128 = 33 * 365 + 8;
+
+//---------------------------------------------------------------------------------------------------- PersianMonth
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Month in a Persian (Solar Hijri) year
+#[allow(missing_docs)]
+pub enum PersianMonth {
+	#[default]
+	Farvardin   = 1,
+	Ordibehesht = 2,
+	Khordad     = 3,
+	Tir         = 4,
+	Mordad      = 5,
+	Shahrivar   = 6,
+	Mehr        = 7,
+	Aban        = 8,
+	Azar        = 9,
+	Dey         = 10,
+	Bahman      = 11,
+	Esfand      = 12,
+}
+
+impl PersianMonth {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianMonth::FIRST, PersianMonth::Farvardin);
+	/// ```
+	pub const FIRST: Self = Self::Farvardin;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianMonth::LAST, PersianMonth::Esfand);
+	/// ```
+	pub const LAST: Self = Self::Esfand;
+
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianMonth::ALL[0],  PersianMonth::Farvardin);
+	/// assert_eq!(PersianMonth::ALL[6],  PersianMonth::Mehr);
+	/// assert_eq!(PersianMonth::ALL[11], PersianMonth::Esfand);
+	/// ```
+	pub const ALL: [Self; 12] = [
+		Self::Farvardin,
+		Self::Ordibehesht,
+		Self::Khordad,
+		Self::Tir,
+		Self::Mordad,
+		Self::Shahrivar,
+		Self::Mehr,
+		Self::Aban,
+		Self::Azar,
+		Self::Dey,
+		Self::Bahman,
+		Self::Esfand,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianMonth::new(1),  PersianMonth::Farvardin);
+	/// assert_eq!(PersianMonth::new(7),  PersianMonth::Mehr);
+	/// assert_eq!(PersianMonth::new(12), PersianMonth::Esfand);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// PersianMonth::new(13);
+	/// ```
+	pub const fn new(month: u8) -> Self {
+		assert!(month != 0, "month must not be 0");
+		assert!(month < 13, "month must not be > 12");
+		// SAFETY: repr(u8)
+		unsafe { Self::new_unchecked(month) }
+	}
+
+	#[inline]
+	/// ## Safety
+	/// `month` must be `1..=12`.
+	pub const unsafe fn new_unchecked(month: u8) -> Self {
+		debug_assert!(month != 0, "month must not be 0");
+		debug_assert!(month < 13, "month must not be > 12");
+		// SAFETY: repr(u8)
+		std::mem::transmute(month)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianMonth::new_saturating(0),  PersianMonth::Farvardin);
+	/// assert_eq!(PersianMonth::new_saturating(13), PersianMonth::Esfand);
+	/// ```
+	pub const fn new_saturating(month: u8) -> Self {
+		if month == 0 {
+			Self::FIRST
+		} else if month < 13 {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(month) }
+		} else {
+			Self::LAST
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianMonth::new_wrapping(0),  PersianMonth::Esfand);
+	/// assert_eq!(PersianMonth::new_wrapping(13), PersianMonth::Farvardin);
+	/// ```
+	pub const fn new_wrapping(month: u8) -> Self {
+		let month = month % 12;
+		if month == 0 {
+			Self::LAST
+		} else {
+			// SAFETY: repr(u8)
+			unsafe { Self::new_unchecked(month) }
+		}
+	}
+
+	impl_u8_enum!();
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianMonth::Farvardin.inner(), 1);
+	/// assert_eq!(PersianMonth::Esfand.inner(),    12);
+	/// ```
+	pub const fn inner(self) -> u8 {
+		// SAFETY: repr(u8)
+		unsafe { std::mem::transmute(self) }
+	}
+
+	#[inline]
+	/// Transliterated Iranian Persian name of the month
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianMonth::Farvardin.as_str(),   "Farvardin");
+	/// assert_eq!(PersianMonth::Ordibehesht.as_str(), "Ordibehesht");
+	/// assert_eq!(PersianMonth::Khordad.as_str(),     "Khordad");
+	/// assert_eq!(PersianMonth::Tir.as_str(),         "Tir");
+	/// assert_eq!(PersianMonth::Mordad.as_str(),      "Mordad");
+	/// assert_eq!(PersianMonth::Shahrivar.as_str(),   "Shahrivar");
+	/// assert_eq!(PersianMonth::Mehr.as_str(),        "Mehr");
+	/// assert_eq!(PersianMonth::Aban.as_str(),        "Aban");
+	/// assert_eq!(PersianMonth::Azar.as_str(),        "Azar");
+	/// assert_eq!(PersianMonth::Dey.as_str(),         "Dey");
+	/// assert_eq!(PersianMonth::Bahman.as_str(),      "Bahman");
+	/// assert_eq!(PersianMonth::Esfand.as_str(),      "Esfand");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Farvardin   => "Farvardin",
+			Self::Ordibehesht => "Ordibehesht",
+			Self::Khordad     => "Khordad",
+			Self::Tir         => "Tir",
+			Self::Mordad      => "Mordad",
+			Self::Shahrivar   => "Shahrivar",
+			Self::Mehr        => "Mehr",
+			Self::Aban        => "Aban",
+			Self::Azar        => "Azar",
+			Self::Dey         => "Dey",
+			Self::Bahman      => "Bahman",
+			Self::Esfand      => "Esfand",
+		}
+	}
+
+	#[inline]
+	/// Iranian Persian name of the month
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianMonth::Farvardin.as_str_fa(),   "فروردین");
+	/// assert_eq!(PersianMonth::Ordibehesht.as_str_fa(), "اردیبهشت");
+	/// assert_eq!(PersianMonth::Khordad.as_str_fa(),     "خرداد");
+	/// assert_eq!(PersianMonth::Tir.as_str_fa(),         "تیر");
+	/// assert_eq!(PersianMonth::Mordad.as_str_fa(),      "مرداد");
+	/// assert_eq!(PersianMonth::Shahrivar.as_str_fa(),   "شهریور");
+	/// assert_eq!(PersianMonth::Mehr.as_str_fa(),        "مهر");
+	/// assert_eq!(PersianMonth::Aban.as_str_fa(),        "آبان");
+	/// assert_eq!(PersianMonth::Azar.as_str_fa(),        "آذر");
+	/// assert_eq!(PersianMonth::Dey.as_str_fa(),         "دی");
+	/// assert_eq!(PersianMonth::Bahman.as_str_fa(),      "بهمن");
+	/// assert_eq!(PersianMonth::Esfand.as_str_fa(),      "اسفند");
+	/// ```
+	pub const fn as_str_fa(self) -> &'static str {
+		match self {
+			Self::Farvardin   => "فروردین",
+			Self::Ordibehesht => "اردیبهشت",
+			Self::Khordad     => "خرداد",
+			Self::Tir         => "تیر",
+			Self::Mordad      => "مرداد",
+			Self::Shahrivar   => "شهریور",
+			Self::Mehr        => "مهر",
+			Self::Aban        => "آبان",
+			Self::Azar        => "آذر",
+			Self::Dey         => "دی",
+			Self::Bahman      => "بهمن",
+			Self::Esfand      => "اسفند",
+		}
+	}
+
+	#[inline]
+	/// Transliterated Dari (Afghan) name of the month
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianMonth::Farvardin.as_str_dari(),   "Hamal");
+	/// assert_eq!(PersianMonth::Ordibehesht.as_str_dari(), "Sawr");
+	/// assert_eq!(PersianMonth::Khordad.as_str_dari(),     "Jawza");
+	/// assert_eq!(PersianMonth::Tir.as_str_dari(),         "Saratan");
+	/// assert_eq!(PersianMonth::Mordad.as_str_dari(),      "Asad");
+	/// assert_eq!(PersianMonth::Shahrivar.as_str_dari(),   "Sonbola");
+	/// assert_eq!(PersianMonth::Mehr.as_str_dari(),        "Mizan");
+	/// assert_eq!(PersianMonth::Aban.as_str_dari(),        "Aqrab");
+	/// assert_eq!(PersianMonth::Azar.as_str_dari(),        "Qaws");
+	/// assert_eq!(PersianMonth::Dey.as_str_dari(),         "Jadi");
+	/// assert_eq!(PersianMonth::Bahman.as_str_dari(),      "Dalw");
+	/// assert_eq!(PersianMonth::Esfand.as_str_dari(),      "Hut");
+	/// ```
+	pub const fn as_str_dari(self) -> &'static str {
+		match self {
+			Self::Farvardin   => "Hamal",
+			Self::Ordibehesht => "Sawr",
+			Self::Khordad     => "Jawza",
+			Self::Tir         => "Saratan",
+			Self::Mordad      => "Asad",
+			Self::Shahrivar   => "Sonbola",
+			Self::Mehr        => "Mizan",
+			Self::Aban        => "Aqrab",
+			Self::Azar        => "Qaws",
+			Self::Dey         => "Jadi",
+			Self::Bahman      => "Dalw",
+			Self::Esfand      => "Hut",
+		}
+	}
+
+	#[inline]
+	/// Dari (Afghan) name of the month
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianMonth::Farvardin.as_str_dari_fa(),   "حمل");
+	/// assert_eq!(PersianMonth::Ordibehesht.as_str_dari_fa(), "ثور");
+	/// assert_eq!(PersianMonth::Khordad.as_str_dari_fa(),     "جوزا");
+	/// assert_eq!(PersianMonth::Tir.as_str_dari_fa(),         "سرطان");
+	/// assert_eq!(PersianMonth::Mordad.as_str_dari_fa(),      "اسد");
+	/// assert_eq!(PersianMonth::Shahrivar.as_str_dari_fa(),   "سنبله");
+	/// assert_eq!(PersianMonth::Mehr.as_str_dari_fa(),        "میزان");
+	/// assert_eq!(PersianMonth::Aban.as_str_dari_fa(),        "عقرب");
+	/// assert_eq!(PersianMonth::Azar.as_str_dari_fa(),        "قوس");
+	/// assert_eq!(PersianMonth::Dey.as_str_dari_fa(),         "جدی");
+	/// assert_eq!(PersianMonth::Bahman.as_str_dari_fa(),      "دلو");
+	/// assert_eq!(PersianMonth::Esfand.as_str_dari_fa(),      "حوت");
+	/// ```
+	pub const fn as_str_dari_fa(self) -> &'static str {
+		match self {
+			Self::Farvardin   => "حمل",
+			Self::Ordibehesht => "ثور",
+			Self::Khordad     => "جوزا",
+			Self::Tir         => "سرطان",
+			Self::Mordad      => "اسد",
+			Self::Shahrivar   => "سنبله",
+			Self::Mehr        => "میزان",
+			Self::Aban        => "عقرب",
+			Self::Azar        => "قوس",
+			Self::Dey         => "جدی",
+			Self::Bahman      => "دلو",
+			Self::Esfand      => "حوت",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- PersianDate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Persian (Solar Hijri) calendar date
+///
+/// Leap years follow the arithmetic 33-year cycle, where a year `y` is leap if `(25y + 11) % 33 < 8`.
+///
+/// The official calendar is astronomical (the year starts at the
+/// vernal equinox in Tehran), so rare years may differ by a day.
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = PersianDate::from_date(Date::new(2023, 10, 20));
+/// assert_eq!(date, PersianDate::new(1402, 7, 28));
+/// assert_eq!(date.month().as_str(), "Mehr");
+/// assert_eq!(date.to_string(), "1402/07/28");
+/// assert_eq!(date.to_date(), Date::new(2023, 10, 20));
+/// ```
+pub struct PersianDate {
+	year: i32,
+	month: PersianMonth,
+	day: u8,
+}
+
+impl PersianDate {
+	#[inline]
+	/// Create a new [`PersianDate`] from numbers
+	///
+	/// ## Panics
+	/// This function panics if:
+	/// - `month` is not `1..=12`
+	/// - `day` is not `1..=31`
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// PersianDate::new(1402, 7, 32);
+	/// ```
+	pub const fn new(year: i32, month: u8, day: u8) -> Self {
+		assert!(day != 0, "day was 0");
+		assert!(day < 32, "day was greater than 31");
+		Self { year, month: PersianMonth::new(month), day }
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianDate::new(1402, 7, 28).year(), 1402);
+	/// ```
+	pub const fn year(self) -> i32 {
+		self.year
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianDate::new(1402, 7, 28).month(), PersianMonth::Mehr);
+	/// ```
+	pub const fn month(self) -> PersianMonth {
+		self.month
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianDate::new(1402, 7, 28).day(), 28);
+	/// ```
+	pub const fn day(self) -> u8 {
+		self.day
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianDate::new(1402, 7, 28).inner(), (1402, 7, 28));
+	/// ```
+	pub const fn inner(self) -> (i32, u8, u8) {
+		(self.year, self.month.inner(), self.day)
+	}
+
+	#[inline]
+	/// If `year` is a leap year, i.e, [`PersianMonth::Esfand`] has `30` days
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert!(PersianDate::is_leap(1399));
+	/// assert!(!PersianDate::is_leap(1402));
+	/// assert!(PersianDate::is_leap(1403));
+	/// assert!(PersianDate::is_leap(1408));
+	/// ```
+	pub const fn is_leap(year: i32) -> bool {
+		(25 * year as i64 + 11).rem_euclid(33) < 8
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianDate::days_in_month(1402, PersianMonth::Farvardin), 31);
+	/// assert_eq!(PersianDate::days_in_month(1402, PersianMonth::Mehr),      30);
+	/// assert_eq!(PersianDate::days_in_month(1402, PersianMonth::Esfand),    29);
+	/// assert_eq!(PersianDate::days_in_month(1403, PersianMonth::Esfand),    30);
+	/// ```
+	pub const fn days_in_month(year: i32, month: PersianMonth) -> u8 {
+		match month.inner() {
+			1..=6 => 31,
+			7..=11 => 30,
+			_ => if Self::is_leap(year) { 30 } else { 29 },
+		}
+	}
+
+	#[inline]
+	/// Rata Die of `1 Farvardin` of `year`
+	const fn year_start(year: i64) -> i128 {
+		PERSIAN_EPOCH + 365 * (year as i128 - 1) + (8 * year as i128 + 21).div_euclid(33)
+	}
+
+	#[inline]
+	/// Convert into a Rata Die
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianDate::new(1402, 7, 28).to_rata_die(), Date::new(2023, 10, 20).to_rata_die());
+	/// ```
+	pub const fn to_rata_die(self) -> i128 {
+		let m = self.month.inner() as i128;
+		let days_before_month = if m <= 7 {
+			31 * (m - 1)
+		} else {
+			30 * (m - 1) + 6
+		};
+
+		Self::year_start(self.year as i64) + days_before_month + self.day as i128 - 1
+	}
+
+	/// Convert a Rata Die into a [`PersianDate`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let rata_die = Date::new(2025, 3, 20).to_rata_die();
+	/// assert_eq!(PersianDate::from_rata_die(rata_die), PersianDate::new(1403, 12, 30));
+	/// ```
+	pub const fn from_rata_die(rata_die: i128) -> Self {
+		// Estimate, then correct.
+		let mut year = ((rata_die - PERSIAN_EPOCH) * 33).div_euclid(DAYS_IN_CYCLE) as i64 + 1;
+		while Self::year_start(year + 1) <= rata_die {
+			year += 1;
+		}
+		while Self::year_start(year) > rata_die {
+			year -= 1;
+		}
+
+		let day_of_year = rata_die - Self::year_start(year);
+		let (month, day) = if day_of_year < 186 {
+			(day_of_year / 31 + 1, day_of_year % 31 + 1)
+		} else {
+			((day_of_year - 6) / 30 + 1, (day_of_year - 6) % 30 + 1)
+		};
+
+		// SAFETY: `month` is `1..=12`
+		Self { year: year as i32, month: unsafe { PersianMonth::new_unchecked(month as u8) }, day: day as u8 }
+	}
+
+	#[inline]
+	/// Convert a [`Date`] into a [`PersianDate`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianDate::from_date(Date::new(2023, 3, 21)), PersianDate::new(1402, 1, 1));
+	/// ```
+	pub const fn from_date(date: Date) -> Self {
+		Self::from_rata_die(date.to_rata_die())
+	}
+
+	#[inline]
+	/// Convert into a [`Date`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianDate::new(1402, 1, 1).to_date(), Date::new(2023, 3, 21));
+	/// ```
+	pub const fn to_date(self) -> Date {
+		Date::from_rata_die(self.to_rata_die())
+	}
+
+	#[inline]
+	/// The [`Date`] of Nowruz (`1 Farvardin`) in the Gregorian `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(PersianDate::nowruz(Year(2023)), Date::new(2023, 3, 21));
+	/// assert_eq!(PersianDate::nowruz(Year(2024)), Date::new(2024, 3, 20));
+	/// assert_eq!(PersianDate::nowruz(Year(2025)), Date::new(2025, 3, 21));
+	/// ```
+	pub const fn nowruz(year: Year) -> Date {
+		Date::from_rata_die(Self::year_start(year.inner() as i64 - 621))
+	}
+
+	/// Parse a `year/month/day` string
+	///
+	/// The separator can be `/` or `-`, and the month and day may be zero-padded.
+	///
+	/// Both ASCII and Persian (`۰`-`۹`) digits are accepted.
+	///
+	/// `None` is returned if the string is malformed or the day does not exist.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let date = PersianDate::new(1402, 7, 28);
+	/// assert_eq!(PersianDate::from_str("1402/07/28").unwrap(), date);
+	/// assert_eq!(PersianDate::from_str("1402/7/28").unwrap(),  date);
+	/// assert_eq!(PersianDate::from_str("1402-07-28").unwrap(), date);
+	/// assert_eq!(PersianDate::from_str("۱۴۰۲/۰۷/۲۸").unwrap(), date);
+	///
+	/// assert!(PersianDate::from_str("1402/07/31").is_none());
+	/// assert!(PersianDate::from_str("1402/12/30").is_none());
+	/// assert!(PersianDate::from_str("1402/13/01").is_none());
+	/// assert!(PersianDate::from_str("1402/07").is_none());
+	/// assert!(PersianDate::from_str("1402/007/28").is_none());
+	/// ```
+	pub const fn from_str(s: &str) -> Option<Self> {
+		Self::from_bytes(s.as_bytes())
+	}
+
+	/// Same as [`Self::from_str`] but from [`&[u8]`]
+	pub const fn from_bytes(bytes: &[u8]) -> Option<Self> {
+		let mut numbers = [0_i64; 3];
+		let mut index = 0;
+		let mut digits = 0;
+		let mut i = 0;
+
+		while i < bytes.len() {
+			let b = bytes[i];
+			let digit = if b.is_ascii_digit() {
+				i += 1;
+				b - b'0'
+			// Persian digits, `U+06F0..=U+06F9`.
+			} else if b == 0xDB && i + 1 < bytes.len() && bytes[i + 1] >= 0xB0 && bytes[i + 1] <= 0xB9 {
+				i += 2;
+				bytes[i - 1] - 0xB0
+			} else if (b == b'/' || b == b'-') && digits != 0 && index < 2 {
+				// The month must be 1 or 2 digits.
+				if index == 1 && digits > 2 {
+					return None;
+				}
+				index += 1;
+				digits = 0;
+				i += 1;
+				continue;
+			} else {
+				return None;
+			};
+
+			digits += 1;
+			if digits > 9 {
+				return None;
+			}
+			numbers[index] = numbers[index] * 10 + digit as i64;
+		}
+
+		if index != 2 || digits == 0 || digits > 2 {
+			return None;
+		}
+
+		let [year, month, day] = numbers;
+		if month < 1 || month > 12 || day < 1 {
+			return None;
+		}
+
+		// SAFETY: `month` is `1..=12`
+		let month = unsafe { PersianMonth::new_unchecked(month as u8) };
+		if day > Self::days_in_month(year as i32, month) as i64 {
+			return None;
+		}
+
+		Some(Self { year: year as i32, month, day: day as u8 })
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Trait
+impl std::fmt::Display for PersianDate {
+	/// Formats as `year/month/day`, e.g, `1402/07/28`
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}/{:02}/{:02}", self.year, self.month.inner(), self.day)
+	}
+}
@@ -0,0 +1,161 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+
+//---------------------------------------------------------------------------------------------------- YearPivot
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// How 2 digit years are expanded into full years
+///
+/// Every pivot is a window of 100 years, a 2 digit year is the 1 year in the window that ends with those digits.
+///
+/// ```rust
+/// # use nichi::*;
+/// // POSIX, `1969..=2068`.
+/// let pivot = YearPivot::POSIX;
+/// assert_eq!(pivot.expand(68), Year(2068));
+/// assert_eq!(pivot.expand(69), Year(1969));
+///
+/// // A fixed window, `1950..=2049`.
+/// let pivot = YearPivot::Fixed(1950);
+/// assert_eq!(pivot.expand(49), Year(2049));
+/// assert_eq!(pivot.expand(50), Year(1950));
+///
+/// // A window that ends 10 years after the anchor, `1934..=2033`.
+/// let pivot = YearPivot::sliding(Date::new(2023, 10, 20), 10);
+/// assert_eq!(pivot.window(), (Year(1934), Year(2033)));
+/// assert_eq!(pivot.expand(33), Year(2033));
+/// assert_eq!(pivot.expand(34), Year(1934));
+///
+/// // Birth dates are never in the future.
+/// let pivot = YearPivot::sliding(Date::new(2023, 10, 20), 0);
+/// assert_eq!(pivot.expand(23), Year(2023));
+/// assert_eq!(pivot.expand(24), Year(1924));
+/// assert_eq!(pivot.expand(99), Year(1999));
+/// ```
+pub enum YearPivot {
+	/// The window starts at this year, e.g, `1969` is `1969..=2068`
+	Fixed(i16),
+	/// The window ends `future` years after `year`
+	Sliding {
+		/// The anchor year, usually the current year
+		year: i16,
+		/// How many years after `year` the window reaches
+		future: u8,
+	},
+}
+
+impl YearPivot {
+	/// The POSIX window, `1969..=2068`
+	///
+	/// This is the default, and what `%y` uses in [`Date::parse_with_format`].
+	pub const POSIX: Self = Self::Fixed(1969);
+
+	#[inline]
+	/// A window that ends `future` years after the year of `anchor`, and starts 99 years before that
+	///
+	/// `future` is clamped to `99`.
+	pub const fn sliding(anchor: Date, future: u8) -> Self {
+		let future = if future > 99 { 99 } else { future };
+		Self::Sliding { year: anchor.year().inner(), future }
+	}
+
+	#[inline]
+	/// The first and last year of the window, inclusive
+	///
+	/// The window is moved to fit within `i16` if needed.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(YearPivot::POSIX.window(),             (Year(1969), Year(2068)));
+	/// assert_eq!(YearPivot::Fixed(i16::MAX).window(),   (Year(i16::MAX - 99), Year(i16::MAX)));
+	/// assert_eq!(YearPivot::Sliding { year: 2000, future: 0 }.window(), (Year(1901), Year(2000)));
+	/// ```
+	pub const fn window(self) -> (Year, Year) {
+		let start = match self {
+			Self::Fixed(start) => start as i32,
+			Self::Sliding { year, future } => year as i32 + future as i32 - 99,
+		};
+		let start = if start > i16::MAX as i32 - 99 {
+			i16::MAX - 99
+		} else if start < i16::MIN as i32 {
+			i16::MIN
+		} else {
+			start as i16
+		};
+		(Year(start), Year(start + 99))
+	}
+
+	#[inline]
+	/// Expand the 2 digit year `two_digit`, `0..=99`, into the year within [`YearPivot::window`]
+	///
+	/// ## Panics
+	/// This function panics if `two_digit > 99`.
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// YearPivot::POSIX.expand(100);
+	/// ```
+	pub const fn expand(self, two_digit: u8) -> Year {
+		assert!(two_digit <= 99, "two_digit was over 99");
+		let start = self.window().0.inner() as i32;
+		let offset = (two_digit as i32 - start).rem_euclid(100);
+		Year((start + offset) as i16)
+	}
+}
+
+impl Default for YearPivot {
+	fn default() -> Self {
+		Self::POSIX
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- YearExpansion
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// A 2 digit year that was expanded by a [`YearPivot`] while parsing
+///
+/// ```rust
+/// # use nichi::*;
+/// let expansion = YearExpansion::new(99, YearPivot::POSIX);
+/// assert_eq!(expansion.two_digit(), 99);
+/// assert_eq!(expansion.year(),      Year(1999));
+/// assert_eq!(expansion.pivot(),     YearPivot::POSIX);
+/// ```
+pub struct YearExpansion {
+	two_digit: u8,
+	year: Year,
+	pivot: YearPivot,
+}
+
+impl YearExpansion {
+	#[inline]
+	/// Expand `two_digit` with `pivot`, see [`YearPivot::expand`]
+	pub const fn new(two_digit: u8, pivot: YearPivot) -> Self {
+		Self {
+			two_digit,
+			year: pivot.expand(two_digit),
+			pivot,
+		}
+	}
+
+	#[inline]
+	/// The 2 digits in the input
+	pub const fn two_digit(self) -> u8 {
+		self.two_digit
+	}
+
+	#[inline]
+	/// The year they were expanded into
+	pub const fn year(self) -> Year {
+		self.year
+	}
+
+	#[inline]
+	/// The [`YearPivot`] that expanded them
+	pub const fn pivot(self) -> YearPivot {
+		self.pivot
+	}
+}
@@ -0,0 +1,724 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::month::Month;
+use crate::day::Day;
+use crate::weekday::Weekday;
+use crate::free::days_in_month;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// English number words, `one` is `1`
+const NUMBERS: [&str; 12] = [
+	"one", "two", "three", "four", "five", "six",
+	"seven", "eight", "nine", "ten", "eleven", "twelve",
+];
+
+/// Japanese `(word, days)` that are a fixed amount of days from the anchor
+const JP_DAYS: [(&str, i32); 13] = [
+	("今日", 0), ("本日", 0), ("きょう", 0),
+	("明日", 1), ("あした", 1), ("あす", 1),
+	("明後日", 2), ("あさって", 2),
+	("昨日", -1), ("きのう", -1),
+	("一昨日", -2), ("おととい", -2), ("おとつい", -2),
+];
+
+/// Japanese `(word, amount, unit)` relative to the anchor's week, month or year
+const JP_PERIODS: [(&str, i32, RelativeUnit); 17] = [
+	("再来週", 2, RelativeUnit::Week),
+	("来週", 1, RelativeUnit::Week),
+	("今週", 0, RelativeUnit::Week),
+	("先々週", -2, RelativeUnit::Week),
+	("先週", -1, RelativeUnit::Week),
+	("再来月", 2, RelativeUnit::Month),
+	("来月", 1, RelativeUnit::Month),
+	("今月", 0, RelativeUnit::Month),
+	("先々月", -2, RelativeUnit::Month),
+	("先月", -1, RelativeUnit::Month),
+	("再来年", 2, RelativeUnit::Year),
+	("来年", 1, RelativeUnit::Year),
+	("今年", 0, RelativeUnit::Year),
+	("一昨年", -2, RelativeUnit::Year),
+	("おととし", -2, RelativeUnit::Year),
+	("去年", -1, RelativeUnit::Year),
+	("昨年", -1, RelativeUnit::Year),
+];
+
+/// Japanese `(suffix, unit)` after a number, e.g, `3日後`
+const JP_UNITS: [(&str, RelativeUnit); 13] = [
+	("日間", RelativeUnit::Day),
+	("日", RelativeUnit::Day),
+	("週間", RelativeUnit::Week),
+	("週", RelativeUnit::Week),
+	("ヶ月", RelativeUnit::Month),
+	("ヵ月", RelativeUnit::Month),
+	("カ月", RelativeUnit::Month),
+	("ケ月", RelativeUnit::Month),
+	("か月", RelativeUnit::Month),
+	("箇月", RelativeUnit::Month),
+	("ヶ月間", RelativeUnit::Month),
+	("年間", RelativeUnit::Year),
+	("年", RelativeUnit::Year),
+];
+
+//---------------------------------------------------------------------------------------------------- RelativeUnit
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// A unit of time in a [`RelativeDate::Offset`]
+pub enum RelativeUnit {
+	#[default]
+	/// 1 day
+	Day = 1,
+	/// 7 days
+	Week = 2,
+	/// 1 calendar month, the day is clamped to the end of the month
+	Month = 3,
+	/// 1 calendar year, February 29th is clamped to February 28th
+	Year = 4,
+}
+
+impl RelativeUnit {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RelativeUnit::ALL.len(), 4);
+	/// ```
+	pub const ALL: [Self; 4] = [
+		Self::Day,
+		Self::Week,
+		Self::Month,
+		Self::Year,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RelativeUnit::Day.as_str(),   "day");
+	/// assert_eq!(RelativeUnit::Week.as_str(),  "week");
+	/// assert_eq!(RelativeUnit::Month.as_str(), "month");
+	/// assert_eq!(RelativeUnit::Year.as_str(),  "year");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Day   => "day",
+			Self::Week  => "week",
+			Self::Month => "month",
+			Self::Year  => "year",
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RelativeUnit::Day.as_str_jp(),   "日");
+	/// assert_eq!(RelativeUnit::Week.as_str_jp(),  "週間");
+	/// assert_eq!(RelativeUnit::Month.as_str_jp(), "ヶ月");
+	/// assert_eq!(RelativeUnit::Year.as_str_jp(),  "年");
+	/// ```
+	pub const fn as_str_jp(self) -> &'static str {
+		match self {
+			Self::Day   => "日",
+			Self::Week  => "週間",
+			Self::Month => "ヶ月",
+			Self::Year  => "年",
+		}
+	}
+
+	/// The English unit, singular or plural
+	fn from_str_en(s: &str) -> Option<Self> {
+		let s = s.strip_suffix('s').unwrap_or(s);
+		Self::ALL.into_iter().find(|unit| unit.as_str() == s)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- RelativeMonth
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// A month relative to an anchor [`Date`]
+pub enum RelativeMonth {
+	/// The month this many months after the anchor's month, negative is before, e.g, `next month`
+	Offset(i16),
+	/// The next time this month starts, or the anchor's month if it is this month, e.g, `december`
+	Month(Month),
+}
+
+impl RelativeMonth {
+	/// The year and month, relative to `anchor`
+	fn resolve(self, anchor: Date) -> Option<(i16, Month)> {
+		let (year, month, _) = anchor.inner();
+		let months = match self {
+			Self::Offset(offset) => year as i64 * 12 + month as i64 - 1 + offset as i64,
+			Self::Month(m) if m.inner() >= month => year as i64 * 12 + m.inner() as i64 - 1,
+			Self::Month(m) => (year as i64 + 1) * 12 + m.inner() as i64 - 1,
+		};
+		let year = i16::try_from(months.div_euclid(12)).ok()?;
+		Some((year, Month::new(months.rem_euclid(12) as u8 + 1)))
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- RelativeDate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+/// A date relative to an anchor [`Date`], e.g, `next friday`
+///
+/// This is what [`RelativeParser`] parses phrases into,
+/// it can be created directly and resolved with [`RelativeDate::resolve`].
+///
+/// Weeks start on Monday.
+///
+/// ```rust
+/// # use nichi::*;
+/// // Friday.
+/// let anchor = Date::new(2023, 10, 20);
+///
+/// assert_eq!(RelativeDate::Offset(3, RelativeUnit::Day).resolve(anchor),  Some(Date::new(2023, 10, 23)));
+/// assert_eq!(RelativeDate::Weekday(Weekday::Friday, 0).resolve(anchor),   Some(Date::new(2023, 10, 20)));
+/// assert_eq!(RelativeDate::Weekday(Weekday::Friday, 1).resolve(anchor),   Some(Date::new(2023, 10, 27)));
+/// assert_eq!(RelativeDate::Weekday(Weekday::Friday, -1).resolve(anchor),  Some(Date::new(2023, 10, 13)));
+/// assert_eq!(RelativeDate::WeekdayOfWeek(Weekday::Monday, 1).resolve(anchor), Some(Date::new(2023, 10, 23)));
+///
+/// let month = RelativeMonth::Month(Month::December);
+/// assert_eq!(RelativeDate::WeekdayOfMonth(Weekday::Monday, 1, month).resolve(anchor), Some(Date::new(2023, 12, 4)));
+/// assert_eq!(RelativeDate::WeekdayOfMonth(Weekday::Monday, -1, month).resolve(anchor), Some(Date::new(2023, 12, 25)));
+/// assert_eq!(RelativeDate::WeekdayOfMonth(Weekday::Monday, 5, month).resolve(anchor), None);
+/// ```
+pub enum RelativeDate {
+	/// An amount of a [`RelativeUnit`] after the anchor, negative is before
+	///
+	/// e.g, `tomorrow`, `in 3 days`, `2 weeks ago`, `3日後`
+	Offset(i32, RelativeUnit),
+	/// The `n`th [`Weekday`] after the anchor, negative is before
+	///
+	/// `0` is the first on or after the anchor.
+	///
+	/// e.g, `friday`, `last friday`, `次の金曜日`
+	Weekday(Weekday, i32),
+	/// The [`Weekday`] in the week this many weeks after the anchor's week, negative is before
+	///
+	/// e.g, `next friday`, `friday last week`, `来週の金曜日`
+	WeekdayOfWeek(Weekday, i32),
+	/// The [`Day`] of a month, [`None`] is the last day
+	///
+	/// e.g, `15th of next month`, `last day of next month`, `来月末`
+	DayOfMonth(Option<Day>, RelativeMonth),
+	/// The `n`th [`Weekday`] of a month, negative counts from the end
+	///
+	/// e.g, `first monday of december`, `last friday of the month`, `12月の第1月曜日`
+	WeekdayOfMonth(Weekday, i8, RelativeMonth),
+}
+
+impl RelativeDate {
+	#[inline]
+	/// Parse an English or Japanese phrase with the default [`RelativeParser`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RelativeDate::from_str("in 3 days"), Some(RelativeDate::Offset(3, RelativeUnit::Day)));
+	/// assert_eq!(RelativeDate::from_str("来週の金曜日"), Some(RelativeDate::WeekdayOfWeek(Weekday::Friday, 1)));
+	/// assert_eq!(RelativeDate::from_str("someday"), None);
+	/// assert_eq!(RelativeDate::from_str("九九九九九九九九九九九日後"), None);
+	/// ```
+	pub fn from_str(s: &str) -> Option<Self> {
+		RelativeParser::new().parse(s)
+	}
+
+	/// The [`Date`] this is relative to `anchor`
+	///
+	/// Returns [`None`] if the date does not exist, e.g, the 5th Monday of
+	/// a month with 4, or if the year does not fit in a [`Year`](crate::Year).
+	pub fn resolve(self, anchor: Date) -> Option<Date> {
+		let weekday_index = |w: Weekday| (w.inner() as i64 + 5) % 7;
+		let anchor_index = weekday_index(anchor.weekday());
+
+		match self {
+			Self::Offset(amount, RelativeUnit::Day)   => add_days(anchor, amount as i64),
+			Self::Offset(amount, RelativeUnit::Week)  => add_days(anchor, amount as i64 * 7),
+			Self::Offset(amount, RelativeUnit::Month) => add_months(anchor, amount as i64),
+			Self::Offset(amount, RelativeUnit::Year)  => add_months(anchor, amount as i64 * 12),
+			Self::Weekday(weekday, n) => {
+				let n = n as i64;
+				let ahead = (weekday_index(weekday) - anchor_index).rem_euclid(7);
+				let behind = (anchor_index - weekday_index(weekday)).rem_euclid(7);
+				let days = match n {
+					0 => ahead,
+					1.. => if ahead == 0 { 7 * n } else { ahead + 7 * (n - 1) },
+					_ => if behind == 0 { 7 * n } else { -behind + 7 * (n + 1) },
+				};
+				add_days(anchor, days)
+			},
+			Self::WeekdayOfWeek(weekday, weeks) => {
+				add_days(anchor, weeks as i64 * 7 + weekday_index(weekday) - anchor_index)
+			},
+			Self::DayOfMonth(day, month) => {
+				let (year, month) = month.resolve(anchor)?;
+				let last = days_in_month(year as i128, month).inner();
+				let day = day.map_or(last, Day::inner);
+				(day <= last).then(|| Date::new(year, month.inner(), day))
+			},
+			Self::WeekdayOfMonth(weekday, n, month) => {
+				let (year, month) = month.resolve(anchor)?;
+				let last = days_in_month(year as i128, month).inner() as i64;
+				let day = match n {
+					1.. => {
+						let first = weekday_index(Date::new(year, month.inner(), 1).weekday());
+						1 + (weekday_index(weekday) - first).rem_euclid(7) + 7 * (n as i64 - 1)
+					},
+					..=-1 => {
+						let end = weekday_index(Date::new(year, month.inner(), last as u8).weekday());
+						last - (end - weekday_index(weekday)).rem_euclid(7) + 7 * (n as i64 + 1)
+					},
+					0 => return None,
+				};
+				(1..=last).contains(&day).then(|| Date::new(year, month.inner(), day as u8))
+			},
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Free
+/// `anchor` plus `days`
+fn add_days(anchor: Date, days: i64) -> Option<Date> {
+	let rata_die = anchor.to_rata_die() + days as i128;
+	let date = Date::from_rata_die(rata_die);
+	(date.to_rata_die() == rata_die).then_some(date)
+}
+
+/// `anchor` plus `months`, the day is clamped to the end of the month
+fn add_months(anchor: Date, months: i64) -> Option<Date> {
+	let (year, month, day) = anchor.inner();
+	let months = (year as i64 * 12 + month as i64 - 1).checked_add(months)?;
+	let year = i16::try_from(months.div_euclid(12)).ok()?;
+	let month = Month::new(months.rem_euclid(12) as u8 + 1);
+	let day = day.min(days_in_month(year as i128, month).inner());
+	Some(Date::new(year, month.inner(), day))
+}
+
+/// Parse a Japanese number of ASCII or kanji digits at the start of `s`,
+/// returns the number and the rest of `s`
+fn parse_number_jp(s: &str) -> Option<(i32, &str)> {
+	let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+	if end > 0 {
+		return Some((s[..end].parse().ok()?, &s[end..]));
+	}
+
+	// Kanji, e.g, `三`, `九十九`, `二〇二三`, [`None`] if it overflows.
+	const DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+	let digit = |c: char| DIGITS.iter().position(|d| *d == c).map(|i| i as i32);
+
+	let mut chars = s.chars();
+	let mut number = 0;
+	let mut current = None;
+	let mut rest = s;
+	while let Some(c) = chars.next() {
+		if let Some(d) = digit(c) {
+			current = Some(current.unwrap_or(0_i32).checked_mul(10)?.checked_add(d)?);
+		} else if c == '十' {
+			number = current.unwrap_or(1).checked_mul(10)?.checked_add(number)?;
+			current = None;
+		} else {
+			break;
+		}
+		rest = chars.as_str();
+	}
+	if rest.len() == s.len() {
+		return None;
+	}
+	Some((number.checked_add(current.unwrap_or(0))?, rest))
+}
+
+/// Parse a Japanese weekday at the start of `s`, e.g, `金曜日` or `金曜`,
+/// returns the weekday and the rest of `s`
+fn parse_weekday_jp(s: &str) -> Option<(Weekday, &str)> {
+	let first = s.chars().next()?;
+	let weekday = Weekday::ALL.into_iter().find(|w| w.as_str_jp().starts_with(first))?;
+	let rest = &s[first.len_utf8()..];
+	let rest = rest.strip_prefix("曜日").or_else(|| rest.strip_prefix("曜"))?;
+	Some((weekday, rest))
+}
+
+//---------------------------------------------------------------------------------------------------- RelativeParser
+#[derive(Clone,Debug,Default,PartialEq)]
+/// Parse English and Japanese phrases into a [`RelativeDate`]
+///
+/// The grammar can be extended with [`RelativeParser::phrase`] and [`RelativeParser::unit`].
+///
+/// Input is matched ignoring case and extra whitespace, `the` and a leading `on` are ignored in English.
+///
+/// Parsing and resolving depend only on the input and the anchor, never the current time.
+///
+/// ## English
+/// | Phrase | Example | [`RelativeDate`] |
+/// |--------|---------|------------------|
+/// | `today`, `tomorrow`, `yesterday` | | [`Offset`](RelativeDate::Offset)
+/// | `day after tomorrow`, `day before yesterday` | | [`Offset`](RelativeDate::Offset)
+/// | `in <n> <unit>` | `in 3 days` | [`Offset`](RelativeDate::Offset)
+/// | `<n> <unit> ago` | `2 weeks ago` | [`Offset`](RelativeDate::Offset)
+/// | `<n> <unit> from now`, `<n> <unit> later` | `a month from now` | [`Offset`](RelativeDate::Offset)
+/// | `next <unit>`, `last <unit>`, `this <unit>` | `next week` | [`Offset`](RelativeDate::Offset)
+/// | `<weekday>`, `this <weekday>` | `friday` | [`Weekday`](RelativeDate::Weekday), on or after the anchor
+/// | `last <weekday>` | `last friday` | [`Weekday`](RelativeDate::Weekday), before the anchor
+/// | `next <weekday>` | `next friday` | [`WeekdayOfWeek`](RelativeDate::WeekdayOfWeek), in the next week
+/// | `<weekday> next week`, `<weekday> last week`, `<weekday> this week` | `friday next week` | [`WeekdayOfWeek`](RelativeDate::WeekdayOfWeek)
+/// | `<day> of <month>` | `15th of next month` | [`DayOfMonth`](RelativeDate::DayOfMonth)
+/// | `last day of <month>`, `end of <month>` | `last day of next month` | [`DayOfMonth`](RelativeDate::DayOfMonth)
+/// | `start of <month>`, `beginning of <month>` | `start of next month` | [`DayOfMonth`](RelativeDate::DayOfMonth)
+/// | `<ordinal> <weekday> of <month>` | `first monday of december` | [`WeekdayOfMonth`](RelativeDate::WeekdayOfMonth)
+/// | `last <weekday> of <month>` | `last friday of the month` | [`WeekdayOfMonth`](RelativeDate::WeekdayOfMonth)
+///
+/// - `<n>` is digits, `a`, `an`, or `one` to `twelve`
+/// - `<unit>` is `day`, `week`, `month`, `year` or their plurals
+/// - `<day>` and `<ordinal>` are anything [`Day::from_str`] accepts, e.g, `first` or `10th`
+/// - `<weekday>` is anything [`Weekday::from_str`] accepts, e.g, `thurs`
+/// - `<month>` is `this month`, `next month`, `last month`, `month`, or anything [`Month::from_str`] accepts
+///
+/// ## Japanese
+/// | Phrase | Example | [`RelativeDate`] |
+/// |--------|---------|------------------|
+/// | `今日`, `明日`, `明後日`, `昨日`, `一昨日` | | [`Offset`](RelativeDate::Offset)
+/// | `<n><unit>後`, `<n><unit>前` | `3日後`, `2週間前` | [`Offset`](RelativeDate::Offset)
+/// | `<period>` | `来週`, `先月`, `来年` | [`Offset`](RelativeDate::Offset)
+/// | `<weekday>`, `今度の<weekday>` | `金曜日` | [`Weekday`](RelativeDate::Weekday), on or after the anchor
+/// | `次の<weekday>`, `前の<weekday>` | `次の金曜日` | [`Weekday`](RelativeDate::Weekday)
+/// | `<week>の<weekday>` | `来週の金曜日` | [`WeekdayOfWeek`](RelativeDate::WeekdayOfWeek)
+/// | `<month>の<n>日` | `来月の15日` | [`DayOfMonth`](RelativeDate::DayOfMonth)
+/// | `<month>末`, `<month>の末日`, `月末` | `来月末` | [`DayOfMonth`](RelativeDate::DayOfMonth)
+/// | `<month>の第<n><weekday>` | `12月の第1月曜日` | [`WeekdayOfMonth`](RelativeDate::WeekdayOfMonth)
+/// | `<month>の最終<weekday>` | `今月の最終金曜日` | [`WeekdayOfMonth`](RelativeDate::WeekdayOfMonth)
+///
+/// - `<n>` is ASCII, full-width or kanji digits, e.g, `3`, `３` or `三`
+/// - `の` is optional
+/// - `<month>` is `今月`, `来月`, `先月`, `再来月`, `先々月` or `<n>月`
+///
+/// ## Examples
+/// ```rust
+/// # use nichi::*;
+/// let parser = RelativeParser::new();
+///
+/// // Friday.
+/// let anchor = Date::new(2023, 10, 20);
+/// let date = |s| parser.resolve(s, anchor);
+///
+/// assert_eq!(date("today"),                    Some(Date::new(2023, 10, 20)));
+/// assert_eq!(date("Tomorrow"),                 Some(Date::new(2023, 10, 21)));
+/// assert_eq!(date("the day after tomorrow"),   Some(Date::new(2023, 10, 22)));
+/// assert_eq!(date("in 3 days"),                Some(Date::new(2023, 10, 23)));
+/// assert_eq!(date("2 weeks ago"),              Some(Date::new(2023, 10, 6)));
+/// assert_eq!(date("a month from now"),         Some(Date::new(2023, 11, 20)));
+/// assert_eq!(date("next year"),                Some(Date::new(2024, 10, 20)));
+/// assert_eq!(date("friday"),                   Some(Date::new(2023, 10, 20)));
+/// assert_eq!(date("next friday"),              Some(Date::new(2023, 10, 27)));
+/// assert_eq!(date("last friday"),              Some(Date::new(2023, 10, 13)));
+/// assert_eq!(date("thurs"),                    Some(Date::new(2023, 10, 26)));
+/// assert_eq!(date("monday last week"),         Some(Date::new(2023, 10, 9)));
+/// assert_eq!(date("last day of next month"),   Some(Date::new(2023, 11, 30)));
+/// assert_eq!(date("10th of next month"),       Some(Date::new(2023, 11, 10)));
+/// assert_eq!(date("first monday of december"), Some(Date::new(2023, 12, 4)));
+/// assert_eq!(date("last friday of the month"), Some(Date::new(2023, 10, 27)));
+/// assert_eq!(date("fifth monday of december"), None);
+/// assert_eq!(date("someday"),                  None);
+///
+/// assert_eq!(date("明日"),               Some(Date::new(2023, 10, 21)));
+/// assert_eq!(date("一昨日"),             Some(Date::new(2023, 10, 18)));
+/// assert_eq!(date("3日後"),              Some(Date::new(2023, 10, 23)));
+/// assert_eq!(date("三日後"),             Some(Date::new(2023, 10, 23)));
+/// assert_eq!(date("２週間前"),           Some(Date::new(2023, 10, 6)));
+/// assert_eq!(date("来週の金曜日"),       Some(Date::new(2023, 10, 27)));
+/// assert_eq!(date("先週の月曜"),         Some(Date::new(2023, 10, 9)));
+/// assert_eq!(date("次の金曜日"),         Some(Date::new(2023, 10, 27)));
+/// assert_eq!(date("来月末"),             Some(Date::new(2023, 11, 30)));
+/// assert_eq!(date("来月の15日"),         Some(Date::new(2023, 11, 15)));
+/// assert_eq!(date("12月の第1月曜日"),    Some(Date::new(2023, 12, 4)));
+/// assert_eq!(date("十二月の第一月曜日"), Some(Date::new(2023, 12, 4)));
+/// assert_eq!(date("今月の最終金曜日"),   Some(Date::new(2023, 10, 27)));
+///
+/// // Months are clamped.
+/// assert_eq!(parser.resolve("next month", Date::new(2024, 1, 31)), Some(Date::new(2024, 2, 29)));
+///
+/// // Anything within `Year` works, anything past it is `None`.
+/// assert_eq!(date("in 3000 years"),            Some(Date::new(5023, 10, 20)));
+/// assert_eq!(date("30000 years ago"),          Some(Date::new(-27977, 10, 20)));
+/// assert_eq!(date("in 40000 years"),           None);
+/// assert_eq!(date("九九九九九九九九九九九日後"), None);
+/// ```
+///
+/// ## Extending
+/// ```rust
+/// # use nichi::*;
+/// let parser = RelativeParser::new()
+/// 	.unit("fortnight", RelativeUnit::Week, 2)
+/// 	.phrase("payday", RelativeDate::DayOfMonth(Some(Day::TwentyFifth), RelativeMonth::Offset(0)))
+/// 	.phrase("給料日", RelativeDate::DayOfMonth(Some(Day::TwentyFifth), RelativeMonth::Offset(0)));
+///
+/// let anchor = Date::new(2023, 10, 20);
+/// assert_eq!(parser.resolve("in a fortnight",  anchor), Some(Date::new(2023, 11, 3)));
+/// assert_eq!(parser.resolve("2 fortnights ago", anchor), Some(Date::new(2023, 9, 22)));
+/// assert_eq!(parser.resolve("PAYDAY",          anchor), Some(Date::new(2023, 10, 25)));
+/// assert_eq!(parser.resolve("給料日",          anchor), Some(Date::new(2023, 10, 25)));
+/// ```
+pub struct RelativeParser {
+	/// `(normalized phrase, date)`
+	phrases: Vec<(String, RelativeDate)>,
+	/// `(name, unit, amount of the unit)`
+	units: Vec<(String, RelativeUnit, i32)>,
+}
+
+impl RelativeParser {
+	#[inline]
+	/// Create a new [`RelativeParser`] with the built-in English and Japanese grammar
+	pub const fn new() -> Self {
+		Self {
+			phrases: Vec::new(),
+			units: Vec::new(),
+		}
+	}
+
+	/// Add a fixed phrase, matched before the built-in grammar
+	pub fn phrase(mut self, phrase: &str, date: RelativeDate) -> Self {
+		self.phrases.push((normalize(phrase), date));
+		self
+	}
+
+	/// Add a unit that is `amount` of `unit`, e.g, `fortnight` is 2 weeks
+	///
+	/// It is usable anywhere a built-in unit is, in English an `s` can be added for the plural.
+	pub fn unit(mut self, name: &str, unit: RelativeUnit, amount: i32) -> Self {
+		self.units.push((normalize(name), unit, amount));
+		self
+	}
+
+	/// Parse `s` into a [`RelativeDate`]
+	pub fn parse(&self, s: &str) -> Option<RelativeDate> {
+		let s = normalize(s);
+
+		if let Some((_, date)) = self.phrases.iter().find(|(phrase, _)| *phrase == s) {
+			return Some(*date);
+		}
+
+		if s.is_ascii() {
+			self.parse_en(&s)
+		} else {
+			self.parse_jp(&s.replace(' ', ""))
+		}
+	}
+
+	#[inline]
+	/// Parse `s` and resolve it relative to `anchor`
+	pub fn resolve(&self, s: &str, anchor: Date) -> Option<Date> {
+		self.parse(s)?.resolve(anchor)
+	}
+
+	/// A custom or built-in unit, returns the unit and how many of it this is
+	fn unit_of(&self, s: &str, english: bool) -> Option<(RelativeUnit, i32)> {
+		let singular = if english { s.strip_suffix('s') } else { None };
+		let custom = self.units.iter().find(|(name, _, _)| name == s || Some(name.as_str()) == singular);
+		if let Some((_, unit, amount)) = custom {
+			return Some((*unit, *amount));
+		}
+
+		if english {
+			RelativeUnit::from_str_en(s).map(|unit| (unit, 1))
+		} else {
+			JP_UNITS.iter().find(|(name, _)| *name == s).map(|(_, unit)| (*unit, 1))
+		}
+	}
+
+	/// `amount` of `unit`, e.g, `3 days`
+	fn offset(&self, amount: &str, unit: &str, sign: i32) -> Option<RelativeDate> {
+		let amount: i32 = match amount {
+			"a" | "an" => 1,
+			_ => match NUMBERS.iter().position(|n| *n == amount) {
+				Some(i) => i as i32 + 1,
+				None => amount.parse().ok()?,
+			},
+		};
+		let (unit, count) = self.unit_of(unit, true)?;
+		Some(RelativeDate::Offset(amount.checked_mul(count)?.checked_mul(sign)?, unit))
+	}
+
+	fn parse_en(&self, s: &str) -> Option<RelativeDate> {
+		use RelativeDate as R;
+
+		let words: Vec<&str> = s.split(' ').filter(|w| *w != "the").collect();
+		let words = words.strip_prefix(&["on"]).unwrap_or(&words);
+		let direction = |w: &str| match w {
+			"next" => Some(1),
+			"last" => Some(-1),
+			"this" => Some(0),
+			_ => None,
+		};
+		let weekday = |w: &str| Weekday::from_str(w);
+
+		let date = match words {
+			["today" | "now"] => R::Offset(0, RelativeUnit::Day),
+			["tomorrow"]      => R::Offset(1, RelativeUnit::Day),
+			["yesterday"]     => R::Offset(-1, RelativeUnit::Day),
+			["day", "after", "tomorrow"]   => R::Offset(2, RelativeUnit::Day),
+			["day", "before", "yesterday"] => R::Offset(-2, RelativeUnit::Day),
+
+			["in", amount, unit] => self.offset(amount, unit, 1)?,
+			[amount, unit, "ago"] => self.offset(amount, unit, -1)?,
+			[amount, unit, "from", "now"] | [amount, unit, "later"] => self.offset(amount, unit, 1)?,
+
+			[w] => R::Weekday(weekday(w)?, 0),
+			[d, w] => match (direction(d)?, weekday(w)) {
+				(0, Some(w))  => R::Weekday(w, 0),
+				(1, Some(w))  => R::WeekdayOfWeek(w, 1),
+				(_, Some(w))  => R::Weekday(w, -1),
+				(d, None) => {
+					let (unit, count) = self.unit_of(w, true)?;
+					R::Offset(d * count, unit)
+				},
+			},
+			[w, d, "week"] => R::WeekdayOfWeek(weekday(w)?, direction(d)?),
+
+			["end", "of", month @ ..] => R::DayOfMonth(None, parse_month_en(month)?),
+			["start" | "beginning", "of", month @ ..] => R::DayOfMonth(Some(Day::First), parse_month_en(month)?),
+			["last", "day", "of", month @ ..] => R::DayOfMonth(None, parse_month_en(month)?),
+			[day, "day", "of", month @ ..] | [day, "of", month @ ..] => {
+				R::DayOfMonth(Some(Day::from_str(day)?), parse_month_en(month)?)
+			},
+			[n, w, "of", month @ ..] => {
+				let n = match *n {
+					"last" => -1,
+					n => Day::from_str(n).filter(|d| d.inner() <= 5)?.inner() as i8,
+				};
+				R::WeekdayOfMonth(weekday(w)?, n, parse_month_en(month)?)
+			},
+
+			_ => return None,
+		};
+
+		Some(date)
+	}
+
+	fn parse_jp(&self, s: &str) -> Option<RelativeDate> {
+		use RelativeDate as R;
+
+		if let Some((_, days)) = JP_DAYS.iter().find(|(word, _)| *word == s) {
+			return Some(R::Offset(*days, RelativeUnit::Day));
+		}
+		if let Some((_, amount, unit)) = JP_PERIODS.iter().find(|(word, _, _)| *word == s) {
+			return Some(R::Offset(*amount, *unit));
+		}
+
+		// `3日後`, `2週間前`
+		if let Some((amount, rest)) = parse_number_jp(s) {
+			let (rest, sign) = match (rest.strip_suffix('後'), rest.strip_suffix('前')) {
+				(Some(rest), _) => (rest, 1),
+				(_, Some(rest)) => (rest, -1),
+				_ => (rest, 0),
+			};
+			if sign != 0 {
+				if let Some((unit, count)) = self.unit_of(rest, false) {
+					return Some(R::Offset(amount.checked_mul(count)?.checked_mul(sign)?, unit));
+				}
+			}
+		}
+
+		// `金曜日`, `次の金曜日`
+		for (prefix, n) in [("", 0), ("今度の", 0), ("次の", 1), ("前の", -1), ("この前の", -1)] {
+			if let Some((weekday, "")) = s.strip_prefix(prefix).and_then(parse_weekday_jp) {
+				return Some(R::Weekday(weekday, n));
+			}
+		}
+
+		// `月末`
+		if s == "月末" {
+			return Some(R::DayOfMonth(None, RelativeMonth::Offset(0)));
+		}
+
+		// `来週の金曜日`
+		for (word, amount, unit) in JP_PERIODS {
+			let Some(rest) = s.strip_prefix(word) else { continue };
+			let rest = rest.strip_prefix('の').unwrap_or(rest);
+			match unit {
+				RelativeUnit::Week => {
+					if let Some((weekday, "")) = parse_weekday_jp(rest) {
+						return Some(R::WeekdayOfWeek(weekday, amount));
+					}
+				},
+				RelativeUnit::Month => return parse_in_month_jp(rest, RelativeMonth::Offset(amount as i16)),
+				_ => (),
+			}
+		}
+
+		// `12月の第1月曜日`
+		let (month, rest) = parse_number_jp(s)?;
+		let rest = rest.strip_prefix('月')?;
+		let month = Month::new(u8::try_from(month).ok().filter(|m| (1..=12).contains(m))?);
+		let rest = rest.strip_prefix('の').unwrap_or(rest);
+		parse_in_month_jp(rest, RelativeMonth::Month(month))
+	}
+}
+
+/// Lowercase and collapse whitespace into single spaces, full-width digits become ASCII
+fn normalize(s: &str) -> String {
+	s.split_whitespace()
+		.map(|word| {
+			word.chars()
+				.map(|c| match c {
+					'０'..='９' => char::from(b'0' + (c as u32 - '０' as u32) as u8),
+					c => c,
+				})
+				.collect::<String>()
+				.to_lowercase()
+		})
+		.collect::<Vec<String>>()
+		.join(" ")
+}
+
+/// `this month`, `next month`, `last month`, `month`, or a month name
+fn parse_month_en(words: &[&str]) -> Option<RelativeMonth> {
+	match words {
+		["month"] | ["this", "month"] => Some(RelativeMonth::Offset(0)),
+		["next", "month"] => Some(RelativeMonth::Offset(1)),
+		["last", "month"] => Some(RelativeMonth::Offset(-1)),
+		[month] => Month::from_str(month).map(RelativeMonth::Month),
+		_ => None,
+	}
+}
+
+/// What comes after a month in Japanese, e.g, `末`, `15日`, `第1月曜日`
+fn parse_in_month_jp(s: &str, month: RelativeMonth) -> Option<RelativeDate> {
+	use RelativeDate as R;
+
+	if matches!(s, "末" | "末日") {
+		return Some(R::DayOfMonth(None, month));
+	}
+	if matches!(s, "初め" | "初日") {
+		return Some(R::DayOfMonth(Some(Day::First), month));
+	}
+	if let Some(rest) = s.strip_prefix("最終").or_else(|| s.strip_prefix("最後の")) {
+		let (weekday, "") = parse_weekday_jp(rest)? else { return None };
+		return Some(R::WeekdayOfMonth(weekday, -1, month));
+	}
+	if let Some(rest) = s.strip_prefix('第') {
+		let (n, rest) = parse_number_jp(rest)?;
+		let (weekday, "") = parse_weekday_jp(rest)? else { return None };
+		let n = i8::try_from(n).ok().filter(|n| (1..=5).contains(n))?;
+		return Some(R::WeekdayOfMonth(weekday, n, month));
+	}
+
+	let (day, "日") = parse_number_jp(s)? else { return None };
+	let day = u8::try_from(day).ok().filter(|d| (1..=31).contains(d))?;
+	Some(R::DayOfMonth(Some(Day::new(day)), month))
+}
+
+//---------------------------------------------------------------------------------------------------- Date
+impl Date {
+	#[inline]
+	/// Parse an English or Japanese relative date, e.g, `next friday` or `来週の金曜日`
+	///
+	/// This is [`RelativeParser::resolve`] with the default [`RelativeParser`].
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let anchor = Date::new(2023, 10, 20);
+	/// assert_eq!(Date::parse_relative("next friday", anchor),  Some(Date::new(2023, 10, 27)));
+	/// assert_eq!(Date::parse_relative("来週の金曜日", anchor), Some(Date::new(2023, 10, 27)));
+	/// ```
+	pub fn parse_relative(s: &str, anchor: Date) -> Option<Self> {
+		RelativeParser::new().resolve(s, anchor)
+	}
+}
@@ -0,0 +1,312 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+use crate::month::Month;
+use crate::free::is_leap_revised_julian;
+
+//---------------------------------------------------------------------------------------------------- Free
+#[inline]
+/// Leap years in `1..=year`, or the negative amount in `year+1..=0`
+const fn leap_years_before(year: i128) -> i128 {
+	let centuries = year.div_euclid(100);
+	year.div_euclid(4) - centuries + (centuries + 7).div_euclid(9) + (centuries + 3).div_euclid(9)
+}
+
+#[inline]
+/// Days in `month` of `year`
+const fn days_in_month(year: i128, month: u8) -> u8 {
+	match month {
+		4 | 6 | 9 | 11 => 30,
+		2 if is_leap_revised_julian(year) => 29,
+		2 => 28,
+		_ => 31,
+	}
+}
+
+#[inline]
+/// Rata Die of January 1st of `year`
+///
+/// This lines up with the Gregorian calendar from `1600-03-01` to `2800-02-28`.
+const fn new_year(year: i128) -> i128 {
+	365 * (year - 1) + leap_years_before(year - 1) + 1
+}
+
+//---------------------------------------------------------------------------------------------------- RevisedJulianDate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Revised Julian calendar date
+///
+/// The calendar of several Orthodox churches, proposed by Milutin Milanković in `1923`.
+///
+/// Months are the same as the Gregorian calendar, century years are only
+/// leap years if `year % 900` is `200` or `600`, see [`is_leap_revised_julian`].
+///
+/// The two calendars agree from `1600-03-01` to `2800-02-28`,
+/// after that the Revised Julian calendar is a day ahead until they line up again on `2900-03-01`.
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = RevisedJulianDate::from_date(Date::new(2023, 10, 20));
+/// assert_eq!(date, RevisedJulianDate::new(2023, 10, 20));
+///
+/// // Gregorian `2800-02-29` does not exist in the Revised Julian calendar.
+/// let date = RevisedJulianDate::from_date(Date::new(2800, 2, 29));
+/// assert_eq!(date, RevisedJulianDate::new(2800, 3, 1));
+/// assert_eq!(RevisedJulianDate::from_date(Date::new(2800, 3, 1)), RevisedJulianDate::new(2800, 3, 2));
+///
+/// // And Revised Julian `2900-02-29` does not exist in the Gregorian calendar.
+/// assert_eq!(RevisedJulianDate::from_date(Date::new(2900, 2, 28)), RevisedJulianDate::new(2900, 2, 29));
+/// assert_eq!(RevisedJulianDate::from_date(Date::new(2900, 3, 1)),  RevisedJulianDate::new(2900, 3, 1));
+/// ```
+pub struct RevisedJulianDate {
+	year: i32,
+	month: Month,
+	day: u8,
+}
+
+impl RevisedJulianDate {
+	#[inline]
+	/// Create a new [`RevisedJulianDate`] from numbers
+	///
+	/// ## Panics
+	/// This function panics if:
+	/// - `month` is not `1..=12`
+	/// - `day` does not exist in `month` of `year`
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// // Not a leap year.
+	/// RevisedJulianDate::new(2800, 2, 29);
+	/// ```
+	pub const fn new(year: i32, month: u8, day: u8) -> Self {
+		let month = Month::new(month);
+		assert!(day != 0, "day was 0");
+		assert!(day <= Self::days_in_month(year, month), "day does not exist in month");
+		Self { year, month, day }
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RevisedJulianDate::new(2023, 10, 20).year(), 2023);
+	/// ```
+	pub const fn year(self) -> i32 {
+		self.year
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RevisedJulianDate::new(2023, 10, 20).month(), Month::October);
+	/// ```
+	pub const fn month(self) -> Month {
+		self.month
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RevisedJulianDate::new(2023, 10, 20).day(), 20);
+	/// ```
+	pub const fn day(self) -> u8 {
+		self.day
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RevisedJulianDate::new(2023, 10, 20).inner(), (2023, 10, 20));
+	/// ```
+	pub const fn inner(self) -> (i32, u8, u8) {
+		(self.year, self.month.inner(), self.day)
+	}
+
+	#[inline]
+	/// If `year` is a leap year
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert!(RevisedJulianDate::is_leap(2400));
+	/// assert!(!RevisedJulianDate::is_leap(2800));
+	/// ```
+	pub const fn is_leap(year: i32) -> bool {
+		is_leap_revised_julian(year as i128)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RevisedJulianDate::days_in_month(2023, Month::October), 31);
+	/// assert_eq!(RevisedJulianDate::days_in_month(2400, Month::February), 29);
+	/// assert_eq!(RevisedJulianDate::days_in_month(2800, Month::February), 28);
+	/// ```
+	pub const fn days_in_month(year: i32, month: Month) -> u8 {
+		days_in_month(year as i128, month.inner())
+	}
+
+	/// Convert into a Rata Die
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(RevisedJulianDate::new(2000, 1, 1).to_rata_die(), Date::new(2000, 1, 1).to_rata_die());
+	/// assert_eq!(RevisedJulianDate::new(2800, 3, 1).to_rata_die(), Date::new(2800, 2, 29).to_rata_die());
+	/// ```
+	pub const fn to_rata_die(self) -> i128 {
+		let year = self.year as i128;
+		let mut rata_die = new_year(year) + self.day as i128 - 1;
+
+		let mut month = 1;
+		while month < self.month.inner() {
+			rata_die += days_in_month(year, month) as i128;
+			month += 1;
+		}
+
+		rata_die
+	}
+
+	/// Convert a Rata Die into a [`RevisedJulianDate`]
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// let rata_die = Date::new(2800, 12, 24).to_rata_die();
+	/// assert_eq!(RevisedJulianDate::from_rata_die(rata_die), RevisedJulianDate::new(2800, 12, 25));
+	/// ```
+	pub const fn from_rata_die(rata_die: i128) -> Self {
+		// 900 years are exactly `328,718` days, estimate and then correct the year.
+		let days = rata_die - 1;
+		let mut year = days.div_euclid(328_718) * 900 + days.rem_euclid(328_718) * 900 / 328_718 + 1;
+		while rata_die < new_year(year) {
+			year -= 1;
+		}
+		while rata_die >= new_year(year + 1) {
+			year += 1;
+		}
+
+		let mut day = rata_die - new_year(year) + 1;
+		let mut month = 1;
+		while day > days_in_month(year, month) as i128 {
+			day -= days_in_month(year, month) as i128;
+			month += 1;
+		}
+
+		// SAFETY: `month` is `1..=12`
+		Self { year: year as i32, month: unsafe { Month::new_unchecked(month) }, day: day as u8 }
+	}
+
+	#[inline]
+	/// Convert a [`Date`] into a [`RevisedJulianDate`]
+	pub const fn from_date(date: Date) -> Self {
+		Self::from_rata_die(date.to_rata_die())
+	}
+
+	#[inline]
+	/// Convert into a [`Date`]
+	pub const fn to_date(self) -> Date {
+		Date::from_rata_die(self.to_rata_die())
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- OrthodoxFeast
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// The fixed Great Feasts of the Orthodox church year
+///
+/// The other 3 Great Feasts (Palm Sunday, Ascension and Pentecost) depend on Pascha.
+///
+/// ```rust
+/// # use nichi::*;
+/// assert_eq!(OrthodoxFeast::Nativity.date(Year(2024)),     Date::new(2024, 12, 25));
+/// assert_eq!(OrthodoxFeast::Theophany.date(Year(2024)),    Date::new(2024, 1, 6));
+/// assert_eq!(OrthodoxFeast::Annunciation.date(Year(2024)), Date::new(2024, 3, 25));
+///
+/// // The calendars drift apart after the Gregorian leap day in 2800.
+/// assert_eq!(OrthodoxFeast::Annunciation.date(Year(2800)), Date::new(2800, 3, 24));
+/// assert_eq!(OrthodoxFeast::Nativity.date(Year(2800)),     Date::new(2800, 12, 24));
+/// assert_eq!(OrthodoxFeast::Nativity.date(Year(2900)),     Date::new(2900, 12, 25));
+/// ```
+pub enum OrthodoxFeast {
+	/// `September 8th`, Nativity of the Theotokos
+	NativityOfTheotokos,
+	/// `September 14th`, Exaltation of the Holy Cross
+	ExaltationOfTheCross,
+	/// `November 21st`, Entry of the Theotokos into the Temple
+	EntryOfTheotokos,
+	/// `December 25th`, Nativity of Christ
+	Nativity,
+	/// `January 6th`, Baptism of Christ
+	Theophany,
+	/// `February 2nd`, Presentation of Christ in the Temple
+	Presentation,
+	/// `March 25th`, Annunciation of the Theotokos
+	Annunciation,
+	/// `August 6th`, Transfiguration of Christ
+	Transfiguration,
+	/// `August 15th`, Dormition of the Theotokos
+	Dormition,
+}
+
+impl OrthodoxFeast {
+	/// All fixed feasts, in order of the church year, which starts on `September 1st`
+	pub const ALL: [Self; 9] = [
+		Self::NativityOfTheotokos,
+		Self::ExaltationOfTheCross,
+		Self::EntryOfTheotokos,
+		Self::Nativity,
+		Self::Theophany,
+		Self::Presentation,
+		Self::Annunciation,
+		Self::Transfiguration,
+		Self::Dormition,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(OrthodoxFeast::Nativity.as_str(),  "Nativity of Christ");
+	/// assert_eq!(OrthodoxFeast::Dormition.as_str(), "Dormition of the Theotokos");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::NativityOfTheotokos  => "Nativity of the Theotokos",
+			Self::ExaltationOfTheCross => "Exaltation of the Holy Cross",
+			Self::EntryOfTheotokos     => "Entry of the Theotokos into the Temple",
+			Self::Nativity             => "Nativity of Christ",
+			Self::Theophany            => "Theophany",
+			Self::Presentation         => "Presentation of Christ in the Temple",
+			Self::Annunciation         => "Annunciation",
+			Self::Transfiguration      => "Transfiguration",
+			Self::Dormition            => "Dormition of the Theotokos",
+		}
+	}
+
+	#[inline]
+	/// The [`RevisedJulianDate`] of this feast in the Revised Julian `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(OrthodoxFeast::Transfiguration.revised_julian_date(2024), RevisedJulianDate::new(2024, 8, 6));
+	/// ```
+	pub const fn revised_julian_date(self, year: i32) -> RevisedJulianDate {
+		let (month, day) = match self {
+			Self::NativityOfTheotokos  => (Month::September, 8),
+			Self::ExaltationOfTheCross => (Month::September, 14),
+			Self::EntryOfTheotokos     => (Month::November, 21),
+			Self::Nativity             => (Month::December, 25),
+			Self::Theophany            => (Month::January, 6),
+			Self::Presentation         => (Month::February, 2),
+			Self::Annunciation         => (Month::March, 25),
+			Self::Transfiguration      => (Month::August, 6),
+			Self::Dormition            => (Month::August, 15),
+		};
+
+		RevisedJulianDate { year, month, day }
+	}
+
+	#[inline]
+	/// The Gregorian [`Date`] of this feast in the Revised Julian `year`
+	pub const fn date(self, year: Year) -> Date {
+		self.revised_julian_date(year.inner() as i32).to_date()
+	}
+}
@@ -0,0 +1,294 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+use crate::month::Month;
+use crate::day::Day;
+use crate::unicode::{is_digit,is_word};
+
+//---------------------------------------------------------------------------------------------------- Constants
+// This is the byte scanner behind `Date::from_str`.
+//
+// It accepts exactly what the original regular expressions did,
+// each function notes the expression it replaces.
+//
+// Expressions find the leftmost match, so every function tries
+// each start position in order and returns the first that matches.
+//
+// Like the expressions, `\d` and `\b` are Unicode-aware, see `unicode.rs`.
+// ISO 8601-like dates only look for ASCII digits, the original
+// panicked on any other `\d` within them.
+
+/// Month abbreviations in lowercase, `May` is not one of them
+const MONTHS: [&[u8; 3]; 11] = [
+	b"jan", b"feb", b"mar", b"apr", b"jun", b"jul",
+	b"aug", b"sep", b"oct", b"nov", b"dec",
+];
+
+//---------------------------------------------------------------------------------------------------- Free
+#[inline]
+/// If the byte at `i` is an ASCII digit
+fn digit(bytes: &[u8], i: usize) -> bool {
+	bytes.get(i).is_some_and(u8::is_ascii_digit)
+}
+
+#[inline]
+/// If the byte at `i` is an ASCII letter
+fn alpha(bytes: &[u8], i: usize) -> bool {
+	bytes.get(i).is_some_and(u8::is_ascii_alphabetic)
+}
+
+#[inline]
+/// The value of the ASCII digits in `bytes`
+fn number(bytes: &[u8]) -> u16 {
+	bytes.iter().fold(0, |n, b| n * 10 + (b - b'0') as u16)
+}
+
+#[inline]
+/// The length of the `char` at `i` if it is not a newline, regex `.`
+fn any(s: &str, i: usize) -> Option<usize> {
+	s.get(i..)?.chars().next().filter(|c| *c != '\n').map(char::len_utf8)
+}
+
+#[inline]
+/// The `char` at `i` if it is a decimal digit, regex `\d`, returns its length
+fn decimal(s: &str, i: usize) -> Option<usize> {
+	s.get(i..)?.chars().next().filter(|c| is_digit(*c)).map(char::len_utf8)
+}
+
+#[inline]
+/// If there is a word boundary at `i`, regex `\b`
+fn boundary(s: &str, i: usize) -> bool {
+	let word = |c: Option<char>| c.is_some_and(is_word);
+	word(s[..i].chars().next_back()) != word(s[i..].chars().next())
+}
+
+//---------------------------------------------------------------------------------------------------- ISO 8601
+/// `[1-9]\d{3}.M.D` at `start`, where `M` and `D` are `month_width` and `day_width` digits
+///
+/// | Width | Month | Day |
+/// |-------|-------|-----|
+/// | 2 | `(0[1-9]\|1[012])` | `(0[1-9]\|[12][0-9]\|30\|31)`
+/// | 1 | `[1-9]` | `[1-9]`
+///
+/// Returns the [`Date`] and where it ends.
+fn iso_at(s: &str, start: usize, month_width: usize, day_width: usize) -> Option<(Date, usize)> {
+	let b = s.as_bytes();
+
+	// Year.
+	if !matches!(b[start], b'1'..=b'9') || !(1..4).all(|i| digit(b, start + i)) {
+		return None;
+	}
+	let year = number(&b[start..start + 4]);
+
+	// Month.
+	let m = start + 4 + any(s, start + 4)?;
+	let month = match (month_width, b.get(m), b.get(m + 1)) {
+		(2, Some(b'0'), Some(b'1'..=b'9')) |
+		(2, Some(b'1'), Some(b'0'..=b'2')) => number(&b[m..m + 2]),
+		(1, Some(b'1'..=b'9'), _) => number(&b[m..m + 1]),
+		_ => return None,
+	};
+
+	// Day.
+	let d = m + month_width + any(s, m + month_width)?;
+	let day = match (day_width, b.get(d), b.get(d + 1)) {
+		(2, Some(b'0'), Some(b'1'..=b'9')) |
+		(2, Some(b'1' | b'2'), Some(b'0'..=b'9')) |
+		(2, Some(b'3'), Some(b'0' | b'1')) => number(&b[d..d + 2]),
+		(1, Some(b'1'..=b'9'), _) => number(&b[d..d + 1]),
+		_ => return None,
+	};
+
+	Some((Date::new(year as i16, month as u8, day as u8), d + day_width))
+}
+
+/// The leftmost ISO 8601-like date, trying 2 digit months and days first
+pub(crate) fn iso(s: &str) -> Option<Date> {
+	for (month_width, day_width) in [(2, 2), (1, 2), (2, 1), (1, 1)] {
+		for start in 0..s.len() {
+			if let Some((date, _)) = iso_at(s, start, month_width, day_width) {
+				return Some(date);
+			}
+		}
+	}
+	None
+}
+
+//---------------------------------------------------------------------------------------------------- Heuristic
+/// The leftmost 4 digits, `\d{4}`
+fn year(s: &str) -> Option<Year> {
+	for (start, _) in s.char_indices() {
+		let mut end = start;
+		for _ in 0..4 {
+			let Some(len) = decimal(s, end) else { break };
+			end += len;
+		}
+		if s[start..end].chars().count() == 4 {
+			return Year::from_str(&s[start..end]);
+		}
+	}
+	None
+}
+
+/// The leftmost month abbreviation in title, lower or upper case, e.g, `Jan|jan|JAN`
+fn month(s: &str) -> Option<Month> {
+	let b = s.as_bytes();
+	let start = b.windows(3).position(|w| {
+		MONTHS.iter().any(|m| {
+			let title = w[0] == m[0].to_ascii_uppercase() && w[1..] == m[1..];
+			let lower = w == *m;
+			let upper = w.iter().zip(m.iter()).all(|(a, b)| *a == b.to_ascii_uppercase());
+			title || lower || upper
+		})
+	})?;
+	Month::from_str(&s[start..start + 3])
+}
+
+/// The leftmost day, `(\d{2}|\d{1})[A-Za-z]{2}|\b\d{2}\b|\b\d{1}\b`
+fn day(s: &str) -> Option<Day> {
+	let b = s.as_bytes();
+	for (i, _) in s.char_indices() {
+		let Some(first) = decimal(s, i) else { continue };
+		let one = i + first;
+		let two = decimal(s, one).map(|len| one + len);
+
+		let end = if let Some(two) = two.filter(|&j| alpha(b, j) && alpha(b, j + 1)) {
+			two + 2
+		} else if alpha(b, one) && alpha(b, one + 1) {
+			one + 2
+		} else if !boundary(s, i) {
+			continue;
+		} else if let Some(two) = two.filter(|&j| boundary(s, j)) {
+			two
+		} else if boundary(s, one) {
+			one
+		} else {
+			continue;
+		};
+
+		return Day::from_str(&s[i..end]);
+	}
+	None
+}
+
+/// The leftmost year, month and day, found independently of each other
+pub(crate) fn heuristic(s: &str) -> Option<Date> {
+	let year = year(s)?;
+	let month = month(s)?;
+	let day = day(s)?;
+	Some(Date::new_typed(year, month, day))
+}
+
+//---------------------------------------------------------------------------------------------------- Text
+// These find dates at an exact position for `DateFinder`.
+//
+// Unlike `Date::from_str`, a date in running text must stand on its own,
+// e.g, `2010-12-25` is not found within `12010-12-255`.
+
+/// Separators allowed between the parts of a date in text
+const SEPARATORS: &[u8] = b" ,-./_";
+
+/// Separators allowed in an ISO 8601-like date in text, not `,` as `2010,12,25` is more likely a list
+const ISO_SEPARATORS: &[u8] = b" -./_";
+
+/// The byte after `i` if it is not a word character
+fn word_end(s: &str, i: usize) -> bool {
+	!s[i..].chars().next().is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// An ISO 8601-like date at `start`, not touching other digits,
+/// with 2 of the same [`ISO_SEPARATORS`], e.g, `2010-12-25`, `2010/2/3`
+pub(crate) fn iso_text(s: &str, start: usize) -> Option<(Date, usize)> {
+	let b = s.as_bytes();
+	if start > 0 && digit(b, start - 1) {
+		return None;
+	}
+
+	for (month_width, day_width) in [(2, 2), (1, 2), (2, 1), (1, 1)] {
+		let Some((date, end)) = iso_at(s, start, month_width, day_width) else { continue };
+		let separator = b[start + 4];
+		if ISO_SEPARATORS.contains(&separator) && b[start + 5 + month_width] == separator && !digit(b, end) {
+			return Some((date, end));
+		}
+	}
+	None
+}
+
+/// 1 to 3 [`SEPARATORS`] at `i`, returns where they end
+fn gap(s: &str, i: usize) -> Option<usize> {
+	let len = s.as_bytes()[i..].iter().take(3).take_while(|b| SEPARATORS.contains(b)).count();
+	if len == 0 { None } else { Some(i + len) }
+}
+
+/// A month name at `i` in title, lower or upper case, e.g, `Dec`, `december`, `SEPT`
+fn month_text(s: &str, i: usize) -> Option<(Month, usize)> {
+	let b = s.as_bytes();
+	let len = b[i..].iter().take_while(|b| b.is_ascii_alphabetic()).count();
+	let end = i + len;
+	if !(3..=9).contains(&len) || !word_end(s, end) {
+		return None;
+	}
+
+	match &b[i..end] {
+		b"Sept" | b"sept" | b"SEPT" => Some((Month::September, end)),
+		word => Month::from_bytes(word).map(|m| (m, end)),
+	}
+}
+
+/// A day at `i`, 1 or 2 digits with an optional ordinal suffix, e.g, `3`, `03`, `3rd`, `25TH`
+fn day_text(s: &str, i: usize) -> Option<(Day, usize)> {
+	let b = s.as_bytes();
+	let digits = b[i..].iter().take(3).take_while(|b| b.is_ascii_digit()).count();
+	if !(1..=2).contains(&digits) {
+		return None;
+	}
+	let letters = b[i + digits..].iter().take(3).take_while(|b| b.is_ascii_alphabetic()).count();
+	let end = match letters {
+		0 => i + digits,
+		2 => i + digits + 2,
+		_ => return None,
+	};
+	if !word_end(s, end) {
+		return None;
+	}
+
+	let mut lower = [0; 4];
+	for (l, b) in lower.iter_mut().zip(&b[i..end]) {
+		*l = b.to_ascii_lowercase();
+	}
+	Day::from_bytes(&lower[..end - i]).map(|d| (d, end))
+}
+
+/// A year at `i`, exactly 4 digits, e.g, `2010`
+fn year_text(s: &str, i: usize) -> Option<(Year, usize)> {
+	let b = s.as_bytes();
+	let end = i + 4;
+	if !(0..4).all(|j| digit(b, i + j)) || !word_end(s, end) {
+		return None;
+	}
+	Some((Year(number(&b[i..end]) as i16), end))
+}
+
+/// `Year Month Day` at `start`, e.g, `2010 December 25th`, `2010.dec.25`
+pub(crate) fn ymd_text(s: &str, start: usize) -> Option<(Date, usize)> {
+	let (year, i) = year_text(s, start)?;
+	let (month, i) = month_text(s, gap(s, i)?)?;
+	let (day, end) = day_text(s, gap(s, i)?)?;
+	Some((Date::new_typed(year, month, day), end))
+}
+
+/// `Month Day Year` at `start`, e.g, `December 25th, 2010`, `DEC-25-2010`
+pub(crate) fn mdy_text(s: &str, start: usize) -> Option<(Date, usize)> {
+	let (month, i) = month_text(s, start)?;
+	let (day, i) = day_text(s, gap(s, i)?)?;
+	let (year, end) = year_text(s, gap(s, i)?)?;
+	Some((Date::new_typed(year, month, day), end))
+}
+
+/// `Day Month Year` at `start`, e.g, `25th December 2010`, `25-Dec-2010`
+pub(crate) fn dmy_text(s: &str, start: usize) -> Option<(Date, usize)> {
+	let (day, i) = day_text(s, start)?;
+	let (month, i) = month_text(s, gap(s, i)?)?;
+	let (year, end) = year_text(s, gap(s, i)?)?;
+	Some((Date::new_typed(year, month, day), end))
+}
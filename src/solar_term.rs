@@ -0,0 +1,436 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::Date;
+use crate::year::Year;
+use crate::month::Month;
+use crate::astronomy::{
+	solar_longitude_after,
+	moment_to_unix,
+	moment_to_rata_die,
+};
+
+//---------------------------------------------------------------------------------------------------- SolarTerm
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// The 24 solar terms (二十四節気), including the equinoxes and solstices
+///
+/// Each term is the moment the Sun's apparent longitude reaches a multiple of `15°`.
+///
+/// They are ordered by when they occur in a Gregorian year, starting from [`SolarTerm::MinorCold`].
+///
+/// ## Accuracy
+/// The moments are calculated with the solar longitude and ΔT algorithms
+/// of "Calendrical Calculations" (Reingold & Dershowitz), they are
+/// within a few minutes of published values between `1800` and `2150`.
+///
+/// Outside of that range ΔT is only estimated,
+/// so the error grows the further away the year is.
+///
+/// A [`Date`] can therefore only be wrong when the
+/// term happens within minutes of midnight.
+///
+/// ```rust
+/// # use nichi::*;
+/// // 春分の日 and 秋分の日, the Japanese equinox holidays.
+/// const JST: i32 = 9 * 3600;
+/// assert_eq!(SolarTerm::VernalEquinox.date(Year(2025), JST),   Date::new(2025, 3, 20));
+/// assert_eq!(SolarTerm::AutumnalEquinox.date(Year(2025), JST), Date::new(2025, 9, 23));
+/// assert_eq!(SolarTerm::AutumnalEquinox.date(Year(2024), JST), Date::new(2024, 9, 22));
+/// assert_eq!(SolarTerm::VernalEquinox.date(Year(2092), JST),   Date::new(2092, 3, 19));
+/// ```
+#[allow(missing_docs)]
+pub enum SolarTerm {
+	#[default]
+	MinorCold          = 1,
+	MajorCold          = 2,
+	StartOfSpring      = 3,
+	RainWater          = 4,
+	AwakeningOfInsects = 5,
+	VernalEquinox      = 6,
+	ClearAndBright     = 7,
+	GrainRain          = 8,
+	StartOfSummer      = 9,
+	GrainBuds          = 10,
+	GrainInEar         = 11,
+	SummerSolstice     = 12,
+	MinorHeat          = 13,
+	MajorHeat          = 14,
+	StartOfAutumn      = 15,
+	EndOfHeat          = 16,
+	WhiteDew           = 17,
+	AutumnalEquinox    = 18,
+	ColdDew            = 19,
+	FrostsDescent      = 20,
+	StartOfWinter      = 21,
+	MinorSnow          = 22,
+	MajorSnow          = 23,
+	WinterSolstice     = 24,
+}
+
+impl SolarTerm {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(SolarTerm::ALL[0],  SolarTerm::MinorCold);
+	/// assert_eq!(SolarTerm::ALL[23], SolarTerm::WinterSolstice);
+	/// ```
+	pub const ALL: [Self; 24] = [
+		Self::MinorCold,
+		Self::MajorCold,
+		Self::StartOfSpring,
+		Self::RainWater,
+		Self::AwakeningOfInsects,
+		Self::VernalEquinox,
+		Self::ClearAndBright,
+		Self::GrainRain,
+		Self::StartOfSummer,
+		Self::GrainBuds,
+		Self::GrainInEar,
+		Self::SummerSolstice,
+		Self::MinorHeat,
+		Self::MajorHeat,
+		Self::StartOfAutumn,
+		Self::EndOfHeat,
+		Self::WhiteDew,
+		Self::AutumnalEquinox,
+		Self::ColdDew,
+		Self::FrostsDescent,
+		Self::StartOfWinter,
+		Self::MinorSnow,
+		Self::MajorSnow,
+		Self::WinterSolstice,
+	];
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(SolarTerm::new(1),  SolarTerm::MinorCold);
+	/// assert_eq!(SolarTerm::new(24), SolarTerm::WinterSolstice);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// # use nichi::*;
+	/// SolarTerm::new(25);
+	/// ```
+	pub const fn new(term: u8) -> Self {
+		assert!(term != 0, "term must not be 0");
+		assert!(term < 25, "term must not be > 24");
+		Self::ALL[term as usize - 1]
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(SolarTerm::MinorCold.inner(),      1);
+	/// assert_eq!(SolarTerm::WinterSolstice.inner(), 24);
+	/// ```
+	pub const fn inner(self) -> u8 {
+		self as u8
+	}
+
+	#[inline]
+	/// The apparent solar longitude of this term in degrees
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(SolarTerm::MinorCold.longitude(),       285);
+	/// assert_eq!(SolarTerm::VernalEquinox.longitude(),   0);
+	/// assert_eq!(SolarTerm::SummerSolstice.longitude(),  90);
+	/// assert_eq!(SolarTerm::AutumnalEquinox.longitude(), 180);
+	/// assert_eq!(SolarTerm::WinterSolstice.longitude(),  270);
+	/// ```
+	pub const fn longitude(self) -> u16 {
+		(285 + 15 * (self as u16 - 1)) % 360
+	}
+
+	/// Seconds relative to the Unix epoch when this term occurs in `year`
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// // 2024-03-20 03:06 UTC
+	/// let unix = SolarTerm::VernalEquinox.unix(Year(2024));
+	/// assert!((unix - 1710903960).abs() < 120);
+	/// ```
+	pub fn unix(self, year: Year) -> i128 {
+		moment_to_unix(self.moment(year))
+	}
+
+	/// The [`Date`] this term occurs on in `year`, `utc_offset` seconds away from UTC
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// // 2024-12-21 09:20 UTC
+	/// let term = SolarTerm::WinterSolstice;
+	/// assert_eq!(term.date(Year(2024), 0),           Date::new(2024, 12, 21));
+	/// assert_eq!(term.date(Year(2024), -10 * 3600), Date::new(2024, 12, 20));
+	/// assert_eq!(term.date(Year(2024), 15 * 3600),  Date::new(2024, 12, 22));
+	/// ```
+	pub fn date(self, year: Year, utc_offset: i32) -> Date {
+		Date::from_rata_die(moment_to_rata_die(self.moment(year), utc_offset))
+	}
+
+	/// The moment this term occurs in `year`
+	fn moment(self, year: Year) -> f64 {
+		// Start a day early so that January 1st in any time zone is covered.
+		let start = Date::new(year.inner(), 1, 1).to_rata_die() as f64 - 1.0;
+		solar_longitude_after(self.longitude() as f64, start)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(SolarTerm::MinorCold.as_str(),          "Minor Cold");
+	/// assert_eq!(SolarTerm::AwakeningOfInsects.as_str(), "Awakening of Insects");
+	/// assert_eq!(SolarTerm::WinterSolstice.as_str(),     "Winter Solstice");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::MinorCold          => "Minor Cold",
+			Self::MajorCold          => "Major Cold",
+			Self::StartOfSpring      => "Start of Spring",
+			Self::RainWater          => "Rain Water",
+			Self::AwakeningOfInsects => "Awakening of Insects",
+			Self::VernalEquinox      => "Vernal Equinox",
+			Self::ClearAndBright     => "Clear and Bright",
+			Self::GrainRain          => "Grain Rain",
+			Self::StartOfSummer      => "Start of Summer",
+			Self::GrainBuds          => "Grain Buds",
+			Self::GrainInEar         => "Grain in Ear",
+			Self::SummerSolstice     => "Summer Solstice",
+			Self::MinorHeat          => "Minor Heat",
+			Self::MajorHeat          => "Major Heat",
+			Self::StartOfAutumn      => "Start of Autumn",
+			Self::EndOfHeat          => "End of Heat",
+			Self::WhiteDew           => "White Dew",
+			Self::AutumnalEquinox    => "Autumnal Equinox",
+			Self::ColdDew            => "Cold Dew",
+			Self::FrostsDescent      => "Frost's Descent",
+			Self::StartOfWinter      => "Start of Winter",
+			Self::MinorSnow          => "Minor Snow",
+			Self::MajorSnow          => "Major Snow",
+			Self::WinterSolstice     => "Winter Solstice",
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(SolarTerm::AwakeningOfInsects.as_str_jp(), "啓蟄");
+	/// assert_eq!(SolarTerm::VernalEquinox.as_str_jp(),      "春分");
+	/// assert_eq!(SolarTerm::EndOfHeat.as_str_jp(),          "処暑");
+	/// ```
+	pub const fn as_str_jp(self) -> &'static str {
+		match self {
+			Self::MinorCold          => "小寒",
+			Self::MajorCold          => "大寒",
+			Self::StartOfSpring      => "立春",
+			Self::RainWater          => "雨水",
+			Self::AwakeningOfInsects => "啓蟄",
+			Self::VernalEquinox      => "春分",
+			Self::ClearAndBright     => "清明",
+			Self::GrainRain          => "穀雨",
+			Self::StartOfSummer      => "立夏",
+			Self::GrainBuds          => "小満",
+			Self::GrainInEar         => "芒種",
+			Self::SummerSolstice     => "夏至",
+			Self::MinorHeat          => "小暑",
+			Self::MajorHeat          => "大暑",
+			Self::StartOfAutumn      => "立秋",
+			Self::EndOfHeat          => "処暑",
+			Self::WhiteDew           => "白露",
+			Self::AutumnalEquinox    => "秋分",
+			Self::ColdDew            => "寒露",
+			Self::FrostsDescent      => "霜降",
+			Self::StartOfWinter      => "立冬",
+			Self::MinorSnow          => "小雪",
+			Self::MajorSnow          => "大雪",
+			Self::WinterSolstice     => "冬至",
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(SolarTerm::AwakeningOfInsects.as_str_zh(), "惊蛰");
+	/// assert_eq!(SolarTerm::VernalEquinox.as_str_zh(),      "春分");
+	/// assert_eq!(SolarTerm::EndOfHeat.as_str_zh(),          "处暑");
+	/// ```
+	pub const fn as_str_zh(self) -> &'static str {
+		match self {
+			Self::MinorCold          => "小寒",
+			Self::MajorCold          => "大寒",
+			Self::StartOfSpring      => "立春",
+			Self::RainWater          => "雨水",
+			Self::AwakeningOfInsects => "惊蛰",
+			Self::VernalEquinox      => "春分",
+			Self::ClearAndBright     => "清明",
+			Self::GrainRain          => "谷雨",
+			Self::StartOfSummer      => "立夏",
+			Self::GrainBuds          => "小满",
+			Self::GrainInEar         => "芒种",
+			Self::SummerSolstice     => "夏至",
+			Self::MinorHeat          => "小暑",
+			Self::MajorHeat          => "大暑",
+			Self::StartOfAutumn      => "立秋",
+			Self::EndOfHeat          => "处暑",
+			Self::WhiteDew           => "白露",
+			Self::AutumnalEquinox    => "秋分",
+			Self::ColdDew            => "寒露",
+			Self::FrostsDescent      => "霜降",
+			Self::StartOfWinter      => "立冬",
+			Self::MinorSnow          => "小雪",
+			Self::MajorSnow          => "大雪",
+			Self::WinterSolstice     => "冬至",
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Hemisphere
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Hemisphere of the Earth, which flips the [`Season`]
+#[allow(missing_docs)]
+pub enum Hemisphere {
+	#[default]
+	Northern,
+	Southern,
+}
+
+//---------------------------------------------------------------------------------------------------- Season
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+/// Season of the year
+///
+/// ```rust
+/// # use nichi::*;
+/// let date = Date::new(2024, 7, 1);
+/// assert_eq!(Season::meteorological(date, Hemisphere::Northern), Season::Summer);
+/// assert_eq!(Season::meteorological(date, Hemisphere::Southern), Season::Winter);
+/// ```
+#[allow(missing_docs)]
+pub enum Season {
+	#[default]
+	Spring = 1,
+	Summer = 2,
+	Autumn = 3,
+	Winter = 4,
+}
+
+impl Season {
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Season::ALL[0], Season::Spring);
+	/// assert_eq!(Season::ALL[3], Season::Winter);
+	/// ```
+	pub const ALL: [Self; 4] = [
+		Self::Spring,
+		Self::Summer,
+		Self::Autumn,
+		Self::Winter,
+	];
+
+	#[inline]
+	/// The season on the opposite hemisphere
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Season::Spring.opposite(), Season::Autumn);
+	/// assert_eq!(Season::Winter.opposite(), Season::Summer);
+	/// ```
+	pub const fn opposite(self) -> Self {
+		Self::ALL[(self as usize + 1) % 4]
+	}
+
+	#[inline]
+	/// Flip `self` if `hemisphere` is [`Hemisphere::Southern`]
+	const fn in_hemisphere(self, hemisphere: Hemisphere) -> Self {
+		match hemisphere {
+			Hemisphere::Northern => self,
+			Hemisphere::Southern => self.opposite(),
+		}
+	}
+
+	#[inline]
+	/// The meteorological season of `date`
+	///
+	/// Seasons start on the first day of March, June, September and December (in the [`Hemisphere::Northern`]).
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Season::meteorological(Date::new(2024, 2, 29), Hemisphere::Northern), Season::Winter);
+	/// assert_eq!(Season::meteorological(Date::new(2024, 3, 1),  Hemisphere::Northern), Season::Spring);
+	/// assert_eq!(Season::meteorological(Date::new(2024, 3, 1),  Hemisphere::Southern), Season::Autumn);
+	/// assert_eq!(Season::meteorological(Date::new(2024, 12, 1), Hemisphere::Northern), Season::Winter);
+	/// ```
+	pub const fn meteorological(date: Date, hemisphere: Hemisphere) -> Self {
+		let season = match date.month() {
+			Month::March     | Month::April   | Month::May      => Self::Spring,
+			Month::June      | Month::July    | Month::August   => Self::Summer,
+			Month::September | Month::October | Month::November => Self::Autumn,
+			Month::December  | Month::January | Month::February => Self::Winter,
+		};
+		season.in_hemisphere(hemisphere)
+	}
+
+	/// The astronomical season of `date`, `utc_offset` seconds away from UTC
+	///
+	/// Seasons start on the day of the equinoxes and solstices, see [`SolarTerm`] for accuracy.
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// // 2024-03-20 03:06 UTC
+	/// assert_eq!(Season::astronomical(Date::new(2024, 3, 19), Hemisphere::Northern, 0), Season::Winter);
+	/// assert_eq!(Season::astronomical(Date::new(2024, 3, 20), Hemisphere::Northern, 0), Season::Spring);
+	/// assert_eq!(Season::astronomical(Date::new(2024, 3, 20), Hemisphere::Southern, 0), Season::Autumn);
+	/// assert_eq!(Season::astronomical(Date::new(2024, 3, 20), Hemisphere::Northern, -5 * 3600), Season::Spring);
+	/// assert_eq!(Season::astronomical(Date::new(2024, 3, 19), Hemisphere::Northern, -5 * 3600), Season::Spring);
+	/// ```
+	pub fn astronomical(date: Date, hemisphere: Hemisphere, utc_offset: i32) -> Self {
+		let year = date.year();
+		let season = if date >= SolarTerm::WinterSolstice.date(year, utc_offset) {
+			Self::Winter
+		} else if date >= SolarTerm::AutumnalEquinox.date(year, utc_offset) {
+			Self::Autumn
+		} else if date >= SolarTerm::SummerSolstice.date(year, utc_offset) {
+			Self::Summer
+		} else if date >= SolarTerm::VernalEquinox.date(year, utc_offset) {
+			Self::Spring
+		} else {
+			Self::Winter
+		};
+		season.in_hemisphere(hemisphere)
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Season::Spring.as_str(), "Spring");
+	/// assert_eq!(Season::Autumn.as_str(), "Autumn");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Spring => "Spring",
+			Self::Summer => "Summer",
+			Self::Autumn => "Autumn",
+			Self::Winter => "Winter",
+		}
+	}
+
+	#[inline]
+	/// ```rust
+	/// # use nichi::*;
+	/// assert_eq!(Season::Spring.as_str_jp(), "春");
+	/// assert_eq!(Season::Autumn.as_str_jp(), "秋");
+	/// ```
+	pub const fn as_str_jp(self) -> &'static str {
+		match self {
+			Self::Spring => "春",
+			Self::Summer => "夏",
+			Self::Autumn => "秋",
+			Self::Winter => "冬",
+		}
+	}
+}
@@ -68,6 +68,21 @@ impl Year {
 		(self.0 % 4 == 0 && self.0 % 100 != 0) || self.0 % 400 == 0
 	}
 
+	#[inline]
+	/// If this is a leap year in the Revised Julian calendar
+	///
+	/// See [`is_leap_revised_julian`].
+	///
+	/// ```rust
+	/// # use nichi::*;
+	/// assert!(Year(2024).is_leap_revised_julian());
+	/// assert!(!Year(2800).is_leap_revised_julian());
+	/// assert!(Year(2900).is_leap_revised_julian());
+	/// ```
+	pub const fn is_leap_revised_julian(self) -> bool {
+		crate::free::is_leap_revised_julian(self.0 as i128)
+	}
+
 	#[inline]
 	/// ```rust
 	/// # use nichi::*;